@@ -0,0 +1,127 @@
+//! Chromatic adaptation between white points.
+//!
+//! A chromatic adaptation transform (CAT) predicts how a color seen under one
+//! illuminant appears under another. The XYZ values are moved into a cone-like
+//! response space, scaled by the ratio of the two whites (the von Kries
+//! hypothesis) and moved back. The transforms only differ in the cone response
+//! matrix, selected with [`AdaptationMethod`].
+//!
+//! The crate itself uses Bradford wherever a white point changes, such as CIELAB
+//! relative to D50 or ProPhoto RGB.
+
+use crate::matrix::{Matrix3, diagonal, invert, multiply, multiply_matrices};
+use crate::xyz::WhitePoint;
+
+/// Bradford cone response matrix.
+const BRADFORD: Matrix3 = [
+    [0.8951, 0.2664, -0.1614],
+    [-0.7502, 1.7135, 0.0367],
+    [0.0389, -0.0685, 1.0296],
+];
+
+/// Hunt-Pointer-Estévez cone response matrix, normalized to D65.
+const VON_KRIES: Matrix3 = [
+    [0.40024, 0.70760, -0.08081],
+    [-0.22630, 1.16532, 0.04570],
+    [0.0, 0.0, 0.91822],
+];
+
+/// CIECAM02 cone response matrix.
+const CAT02: Matrix3 = [
+    [0.7328, 0.4296, -0.1624],
+    [-0.7036, 1.6975, 0.0061],
+    [0.0030, 0.0136, 0.9834],
+];
+
+/// CAM16 cone response matrix.
+const CAT16: Matrix3 = [
+    [0.401288, 0.650173, -0.051461],
+    [-0.250268, 1.204414, 0.045854],
+    [-0.002079, 0.048952, 0.953127],
+];
+
+/// Identity matrix, scaling the XYZ values directly.
+const XYZ_SCALING: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// A chromatic adaptation transform.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AdaptationMethod {
+    /// Bradford transform, used by ICC profiles and CSS Color Level 4
+    #[default]
+    Bradford,
+    /// Von Kries transform with the Hunt-Pointer-Estévez cone fundamentals
+    VonKries,
+    /// CAT02 transform from CIECAM02
+    Cat02,
+    /// CAT16 transform from CAM16
+    Cat16,
+    /// Scales the XYZ values directly; the least accurate transform
+    XyzScaling,
+}
+
+impl AdaptationMethod {
+    /// Returns the cone response matrix of the transform.
+    pub fn cone_response(&self) -> [[f64; 3]; 3] {
+        match self {
+            AdaptationMethod::Bradford => BRADFORD,
+            AdaptationMethod::VonKries => VON_KRIES,
+            AdaptationMethod::Cat02 => CAT02,
+            AdaptationMethod::Cat16 => CAT16,
+            AdaptationMethod::XyzScaling => XYZ_SCALING,
+        }
+    }
+}
+
+/// Builds the matrix that adapts XYZ values from one white point to another.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::WhitePoint;
+/// use color_parser::adaptation::{AdaptationMethod, adaptation_matrix};
+///
+/// let m = adaptation_matrix(WhitePoint::D65, WhitePoint::D50, AdaptationMethod::Bradford);
+/// assert!((m[0][0] - 1.0479).abs() < 1e-4);
+/// ```
+pub fn adaptation_matrix(
+    from: WhitePoint,
+    to: WhitePoint,
+    method: AdaptationMethod,
+) -> [[f64; 3]; 3] {
+    let cone = method.cone_response();
+
+    // Scale the cone responses by the ratio of the two whites
+    let source = multiply(&cone, from.xyz());
+    let destination = multiply(&cone, to.xyz());
+    let scale = diagonal([
+        destination[0] / source[0],
+        destination[1] / source[1],
+        destination[2] / source[2],
+    ]);
+
+    multiply_matrices(&invert(&cone), &multiply_matrices(&scale, &cone))
+}
+
+/// Adapts XYZ values from one white point to another.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::WhitePoint;
+/// use color_parser::adaptation::{AdaptationMethod, adapt};
+///
+/// // The source white maps exactly onto the destination white
+/// let white = adapt(WhitePoint::A.xyz(), WhitePoint::A, WhitePoint::D65, AdaptationMethod::Cat16);
+/// let d65 = WhitePoint::D65.xyz();
+/// assert!((0..3).all(|i| (white[i] - d65[i]).abs() < 1e-12));
+/// ```
+pub fn adapt(
+    xyz: [f64; 3],
+    from: WhitePoint,
+    to: WhitePoint,
+    method: AdaptationMethod,
+) -> [f64; 3] {
+    if from == to {
+        return xyz;
+    }
+
+    multiply(&adaptation_matrix(from, to, method), xyz)
+}
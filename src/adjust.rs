@@ -0,0 +1,176 @@
+//! Color adjustments in the style of Sass and LESS.
+//!
+//! `lighten`, `darken`, `saturate` and `desaturate` add or subtract percentage
+//! points, so `lighten(10.0)` turns 40% lightness into 50%, and `spin` rotates
+//! the hue by degrees. By default they work in HSL like Sass; with
+//! [`ColorModel::Oklch`] the same amounts apply to Oklch lightness and chroma
+//! (where 100% chroma is 0.4, as in CSS), which keeps the perceived lightness
+//! steady while the hue or chroma changes.
+
+use crate::gamut::GamutMapping;
+use crate::{Color, Hsl, Oklch, Rgba, unit_to_u8};
+
+/// Oklch chroma that corresponds to 100%, as in CSS `oklch()`
+const OKLCH_FULL_CHROMA: f64 = 0.4;
+
+/// The polar model an adjustment works in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ColorModel {
+    /// HSL, matching Sass and LESS
+    #[default]
+    Hsl,
+    /// Oklch; results outside sRGB are gamut mapped
+    Oklch,
+}
+
+/// Luma coefficients used to convert a color to gray.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Luma {
+    /// ITU-R BT.601 (0.299, 0.587, 0.114), used by most image tools
+    Rec601,
+    /// ITU-R BT.709 (0.2126, 0.7152, 0.0722), matching sRGB primaries
+    #[default]
+    Rec709,
+}
+
+impl Luma {
+    /// Returns the red, green and blue weights.
+    pub fn coefficients(&self) -> [f64; 3] {
+        match self {
+            Luma::Rec601 => [0.299, 0.587, 0.114],
+            Luma::Rec709 => [0.2126, 0.7152, 0.0722],
+        }
+    }
+}
+
+impl Rgba {
+    /// Increases lightness by `amount` percentage points.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::adjust::ColorModel;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// assert_eq!(red.lighten(20.0, ColorModel::Hsl), Rgba { red: 255, green: 102, blue: 102, alpha: 255 });
+    /// ```
+    pub fn lighten(&self, amount: f64, model: ColorModel) -> Rgba {
+        self.adjust(model, |h, s, l| (h, s, l + amount))
+    }
+
+    /// Decreases lightness by `amount` percentage points.
+    pub fn darken(&self, amount: f64, model: ColorModel) -> Rgba {
+        self.lighten(-amount, model)
+    }
+
+    /// Increases saturation (HSL) or chroma (Oklch) by `amount` percentage points.
+    pub fn saturate(&self, amount: f64, model: ColorModel) -> Rgba {
+        self.adjust(model, |h, s, l| (h, s + amount, l))
+    }
+
+    /// Decreases saturation (HSL) or chroma (Oklch) by `amount` percentage points.
+    pub fn desaturate(&self, amount: f64, model: ColorModel) -> Rgba {
+        self.saturate(-amount, model)
+    }
+
+    /// Rotates the hue by `degrees`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::adjust::ColorModel;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// assert_eq!(red.spin(120.0, ColorModel::Hsl), Rgba { red: 0, green: 255, blue: 0, alpha: 255 });
+    /// ```
+    pub fn spin(&self, degrees: f64, model: ColorModel) -> Rgba {
+        self.adjust(model, |h, s, l| (h + degrees, s, l))
+    }
+
+    /// Returns the color with the opposite hue.
+    pub fn complement(&self, model: ColorModel) -> Rgba {
+        self.spin(180.0, model)
+    }
+
+    /// Inverts each channel, keeping alpha.
+    pub fn invert(&self) -> Rgba {
+        Rgba {
+            red: 255 - self.red,
+            green: 255 - self.green,
+            blue: 255 - self.blue,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts the color to the gray with the same luma, keeping alpha.
+    ///
+    /// Luma is a weighted sum of the gamma-encoded channels, as in video and
+    /// most image editors; use [`ColorModel::Oklch`] with
+    /// [`Rgba::desaturate`] to keep the perceived lightness instead.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::adjust::Luma;
+    ///
+    /// let lime = Rgba { red: 0, green: 255, blue: 0, alpha: 255 };
+    /// assert_eq!(lime.grayscale(Luma::Rec601).red, 150);
+    /// assert_eq!(lime.grayscale(Luma::Rec709).red, 182);
+    /// ```
+    pub fn grayscale(&self, luma: Luma) -> Rgba {
+        let [r, g, b] = luma.coefficients();
+        let gray =
+            (r * self.red as f64 + g * self.green as f64 + b * self.blue as f64).round() as u8;
+
+        Rgba {
+            red: gray,
+            green: gray,
+            blue: gray,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Sets the opacity to `opacity` in [0, 1], like LESS `fade()`.
+    pub fn fade(&self, opacity: f64) -> Rgba {
+        Rgba {
+            alpha: unit_to_u8(opacity),
+            ..*self
+        }
+    }
+
+    /// Applies `adjust` to the hue (degrees), saturation or chroma (percent)
+    /// and lightness (percent) of the color in `model`.
+    fn adjust(
+        &self,
+        model: ColorModel,
+        adjust: impl FnOnce(f64, f64, f64) -> (f64, f64, f64),
+    ) -> Rgba {
+        match model {
+            ColorModel::Hsl => {
+                let hsl = Hsl::from(*self);
+                let (hue, saturation, lightness) = adjust(hsl.hue, hsl.saturation, hsl.lightness);
+                Rgba::from(Hsl {
+                    hue: hue.rem_euclid(360.0),
+                    saturation: saturation.clamp(0.0, 100.0),
+                    lightness: lightness.clamp(0.0, 100.0),
+                    alpha: hsl.alpha,
+                })
+            }
+            ColorModel::Oklch => {
+                let oklch = Oklch::from(*self);
+                let (hue, chroma, lightness) = adjust(
+                    oklch.hue,
+                    oklch.chroma / OKLCH_FULL_CHROMA * 100.0,
+                    oklch.lightness * 100.0,
+                );
+                Color::Oklch(Oklch {
+                    lightness: lightness.clamp(0.0, 100.0) / 100.0,
+                    chroma: chroma.clamp(0.0, 100.0) / 100.0 * OKLCH_FULL_CHROMA,
+                    hue: hue.rem_euclid(360.0),
+                    alpha: oklch.alpha,
+                })
+                .to_rgba_mapped(GamutMapping::ChromaReduction)
+            }
+        }
+    }
+}
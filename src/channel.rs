@@ -0,0 +1,135 @@
+//! Channel storage for RGBA colors: 8- and 16-bit integers, and floats.
+//!
+//! [`GenericRgba`] is generic over a [`Channel`] type, with an alias for each:
+//! [`Rgba`](crate::Rgba) (`u8`) matches CSS and most image formats,
+//! [`Rgba16`] holds the channels of 16-bit PNG and TIFF files, and
+//! [`RgbaF32`](crate::RgbaF32) and [`RgbaF64`](crate::RgbaF64) the values of
+//! EXR and other HDR sources. Floating-point channels are nominally in [0, 1]
+//! but are not clamped, so they can describe colors outside the sRGB gamut.
+//!
+//! `From` converts between any two of the aliases, like every other color
+//! type, and is the same as [`GenericRgba::to_depth`]: converting to a wider
+//! type (`u8` → `u16` → `f32` → `f64`, or either integer to either float) is
+//! lossless, and narrowing rounds to the nearest value, clamping when
+//! narrowing to an integer.
+//! 16-bit colors are written as hex with four digits per channel (see
+//! [`parse_hex_to_rgba16`](crate::parse_hex_to_rgba16)).
+//!
+//! # Examples
+//! ```rust
+//! use color_parser::{Rgba, Rgba16, RgbaF32};
+//!
+//! let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+//! let wide = Rgba16::from(orange);
+//! assert_eq!(wide.green, 136 * 257);
+//! assert_eq!(wide.to_depth::<u8>(), orange);
+//!
+//! let hdr = RgbaF32 { red: 1.5, green: 0.5, blue: 0.0, alpha: 1.0 };
+//! assert_eq!(hdr.to_depth::<u8>(), Rgba { red: 255, green: 128, blue: 0, alpha: 255 });
+//! ```
+
+use std::fmt;
+
+use crate::{ChannelOrder, GenericRgba, Rgba16, unit_to_u8};
+
+/// A storage type for the channels of a [`GenericRgba`] color.
+pub trait Channel: Copy + PartialEq + fmt::Debug {
+    /// The value of a full-intensity channel, and of an opaque alpha
+    const MAX: Self;
+
+    /// Converts the channel to a fraction of [`MAX`](Channel::MAX).
+    fn to_unit(self) -> f64;
+
+    /// Creates a channel from a fraction of [`MAX`](Channel::MAX), clamping
+    /// and rounding for integer types.
+    fn from_unit(value: f64) -> Self;
+}
+
+impl Channel for u8 {
+    const MAX: Self = u8::MAX;
+
+    fn to_unit(self) -> f64 {
+        self as f64 / 255.0
+    }
+
+    fn from_unit(value: f64) -> Self {
+        unit_to_u8(value)
+    }
+}
+
+impl Channel for u16 {
+    const MAX: Self = u16::MAX;
+
+    fn to_unit(self) -> f64 {
+        self as f64 / 65535.0
+    }
+
+    fn from_unit(value: f64) -> Self {
+        (value.clamp(0.0, 1.0) * 65535.0).round() as u16
+    }
+}
+
+impl Channel for f32 {
+    const MAX: Self = 1.0;
+
+    fn to_unit(self) -> f64 {
+        self as f64
+    }
+
+    /// Rounds to the nearest `f32`, without clamping.
+    fn from_unit(value: f64) -> Self {
+        value as f32
+    }
+}
+
+impl Channel for f64 {
+    const MAX: Self = 1.0;
+
+    fn to_unit(self) -> f64 {
+        self
+    }
+
+    fn from_unit(value: f64) -> Self {
+        value
+    }
+}
+
+impl<T: Channel> GenericRgba<T> {
+    /// Converts the channels to another storage type.
+    ///
+    /// Widening is exact. Narrowing rounds each channel to the nearest value
+    /// of the target type, and narrowing to an integer type also clamps to
+    /// its range, so 16-bit to 8-bit maps `x` to `round(x / 257)`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{Rgba, Rgba16};
+    ///
+    /// let color = Rgba16 { red: 0x8080, green: 0x8101, blue: 0x0080, alpha: 0xffff };
+    /// assert_eq!(color.to_depth::<u8>(), Rgba { red: 0x80, green: 0x81, blue: 0x00, alpha: 0xff });
+    /// ```
+    pub fn to_depth<U: Channel>(&self) -> GenericRgba<U> {
+        GenericRgba {
+            red: U::from_unit(self.red.to_unit()),
+            green: U::from_unit(self.green.to_unit()),
+            blue: U::from_unit(self.blue.to_unit()),
+            alpha: U::from_unit(self.alpha.to_unit()),
+        }
+    }
+}
+
+impl Rgba16 {
+    /// Formats the color as lowercase `#rrrrggggbbbb`, or `#rrrrggggbbbbaaaa`
+    /// if it is not fully opaque.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba16;
+    ///
+    /// let color = Rgba16 { red: 0xffff, green: 0x8800, blue: 0x0001, alpha: 0xffff };
+    /// assert_eq!(color.to_hex(), "#ffff88000001");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        self.to_hex_in(ChannelOrder::Rgba)
+    }
+}
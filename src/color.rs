@@ -0,0 +1,350 @@
+//! Conversions between every supported color space.
+//!
+//! Each color type implements [`ColorSpace`], which converts to and from the
+//! floating-point [`Srgb`] hub. `From`/`Into` implementations between every
+//! pair of types are generated from that, so `let hsl: Hsl = rgba.into()` works
+//! for any combination, including the 16-bit and floating-point `Rgba` aliases
+//! (see [`channel`](crate::channel)). Adding a new space only requires a `ColorSpace`
+//! implementation and an entry in the `impl_hub_conversions!` list below.
+//!
+//! The [`Color`] enum holds a color in any of the supported spaces, and is what
+//! [`parse_css_color`](crate::parse_css_color) returns.
+
+use crate::{
+    AdobeRgb, Channel, Cmyk, DisplayP3, GenericRgba, Hsl, Hsv, Lab, Lch, LinearRgb, Oklab, Oklch,
+    ProPhotoRgb, Rec2020, Rgba, Rgba16, RgbaF32, RgbaF64, Srgb, Xyz, hsl_to_rgb, hsv_to_rgb,
+};
+
+/// A color space that can be converted to and from the [`Srgb`] hub.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{ColorSpace, Hsv, Rgba};
+///
+/// let rgba = Rgba { red: 0, green: 255, blue: 0, alpha: 255 };
+/// let hsv: Hsv = rgba.convert();
+/// assert_eq!(hsv.hue, 120.0);
+/// ```
+pub trait ColorSpace: Copy {
+    /// Converts this color to the `Srgb` hub.
+    fn to_srgb(&self) -> Srgb;
+
+    /// Creates a color in this space from the `Srgb` hub.
+    fn from_srgb(color: Srgb) -> Self;
+
+    /// Converts this color to any other color space.
+    fn convert<T: ColorSpace>(&self) -> T {
+        T::from_srgb(self.to_srgb())
+    }
+}
+
+impl ColorSpace for Srgb {
+    fn to_srgb(&self) -> Srgb {
+        *self
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        color
+    }
+}
+
+impl<T: Channel> ColorSpace for GenericRgba<T> {
+    fn to_srgb(&self) -> Srgb {
+        // Convert r, g, b, a [0, MAX] range to [0, 1]
+        Srgb {
+            red: self.red.to_unit(),
+            green: self.green.to_unit(),
+            blue: self.blue.to_unit(),
+            alpha: self.alpha.to_unit(),
+        }
+    }
+
+    /// Integer channels are clamped to the sRGB gamut and rounded to the
+    /// nearest value; floating-point channels are kept as is.
+    fn from_srgb(color: Srgb) -> Self {
+        GenericRgba {
+            red: T::from_unit(color.red),
+            green: T::from_unit(color.green),
+            blue: T::from_unit(color.blue),
+            alpha: T::from_unit(color.alpha),
+        }
+    }
+}
+
+impl ColorSpace for Hsl {
+    fn to_srgb(&self) -> Srgb {
+        let (red, green, blue) =
+            hsl_to_rgb(self.hue, self.saturation / 100.0, self.lightness / 100.0);
+        Srgb {
+            red,
+            green,
+            blue,
+            alpha: self.alpha,
+        }
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        let (r, g, b) = (color.red, color.green, color.blue);
+
+        // Find min and max among the r, g, b values
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        // Calculate lightness
+        let lightness = (max + min) / 2.0;
+
+        // Calculate saturation, clamped since rounding can push it slightly above 1
+        let saturation = if delta == 0.0 {
+            0.0
+        } else {
+            (delta / (1.0 - (2.0 * lightness - 1.0).abs())).min(1.0)
+        };
+
+        Hsl {
+            hue: hue_from_rgb(r, g, b, max, delta),
+            saturation: saturation * 100.0,
+            lightness: lightness * 100.0,
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl ColorSpace for Hsv {
+    fn to_srgb(&self) -> Srgb {
+        let (red, green, blue) = hsv_to_rgb(self.hue, self.saturation / 100.0, self.value / 100.0);
+        Srgb {
+            red,
+            green,
+            blue,
+            alpha: self.alpha,
+        }
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        let (r, g, b) = (color.red, color.green, color.blue);
+
+        // Find min and max among the r, g, b values
+        let max = r.max(g).max(b);
+        let min = r.min(g).min(b);
+        let delta = max - min;
+
+        // Calculate the value
+        let value = max;
+
+        // Calculate the saturation
+        let saturation = if delta == 0.0 { 0.0 } else { delta / value };
+
+        Hsv {
+            hue: hue_from_rgb(r, g, b, max, delta),
+            saturation: saturation * 100.0,
+            value: value * 100.0,
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl ColorSpace for Cmyk {
+    fn to_srgb(&self) -> Srgb {
+        // Every channel is attenuated by its ink and by the black key
+        let k = 1.0 - self.black / 100.0;
+        Srgb {
+            red: (1.0 - self.cyan / 100.0) * k,
+            green: (1.0 - self.magenta / 100.0) * k,
+            blue: (1.0 - self.yellow / 100.0) * k,
+            alpha: self.alpha,
+        }
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        let (r, g, b) = (color.red, color.green, color.blue);
+
+        // Calculate the black key color
+        let k = 1.0 - r.max(g).max(b);
+
+        // If RGB is key (black), set CMY to 0
+        if k == 1.0 {
+            return Cmyk {
+                cyan: 0.0,
+                magenta: 0.0,
+                yellow: 0.0,
+                black: 100.0,
+                alpha: color.alpha,
+            };
+        }
+
+        // Calculate cyan, magenta and yellow color
+        let c = (1.0 - r - k) / (1.0 - k);
+        let m = (1.0 - g - k) / (1.0 - k);
+        let y = (1.0 - b - k) / (1.0 - k);
+
+        Cmyk {
+            cyan: c * 100.0,
+            magenta: m * 100.0,
+            yellow: y * 100.0,
+            black: k * 100.0,
+            alpha: color.alpha,
+        }
+    }
+}
+
+/// Computes the hue in degrees [0, 360) shared by HSL and HSV.
+fn hue_from_rgb(r: f64, g: f64, b: f64, max: f64, delta: f64) -> f64 {
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        (g - b) / delta + (if g < b { 6.0 } else { 0.0 })
+    } else if max == g {
+        (b - r) / delta + 2.0 // 120° on the color wheel
+    } else {
+        (r - g) / delta + 4.0 // 240° on the color wheel
+    };
+
+    (hue * 60.0) % 360.0
+}
+
+/// A color in any of the supported color spaces.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Color, Hsl, Rgba};
+///
+/// let color = Color::from(Rgba { red: 255, green: 0, blue: 0, alpha: 255 });
+/// let hsl: Hsl = color.into();
+/// assert_eq!(hsl.lightness, 50.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Color {
+    /// 8-bit sRGB color
+    Rgba(Rgba),
+    /// 16-bit sRGB color
+    Rgba16(Rgba16),
+    /// sRGB color with `f32` channels
+    RgbaF32(RgbaF32),
+    /// sRGB color with `f64` channels
+    RgbaF64(RgbaF64),
+    /// Floating-point sRGB color
+    Srgb(Srgb),
+    /// HSL color
+    Hsl(Hsl),
+    /// HSV color
+    Hsv(Hsv),
+    /// CMYK color
+    Cmyk(Cmyk),
+    /// Linear-light sRGB color
+    LinearRgb(LinearRgb),
+    /// CIE XYZ (D65) color
+    Xyz(Xyz),
+    /// CIELAB color
+    Lab(Lab),
+    /// CIELCh color
+    Lch(Lch),
+    /// Oklab color
+    Oklab(Oklab),
+    /// Oklch color
+    Oklch(Oklch),
+    /// Display P3 color
+    DisplayP3(DisplayP3),
+    /// Rec. 2020 color
+    Rec2020(Rec2020),
+    /// Adobe RGB (1998) color
+    AdobeRgb(AdobeRgb),
+    /// ProPhoto RGB color
+    ProPhotoRgb(ProPhotoRgb),
+}
+
+impl Color {
+    /// Converts the color to `Rgba`, clamping to the sRGB gamut and rounding
+    /// each channel to the nearest byte.
+    pub fn to_rgba(&self) -> Rgba {
+        self.convert()
+    }
+}
+
+impl ColorSpace for Color {
+    fn to_srgb(&self) -> Srgb {
+        match self {
+            Color::Rgba(color) => color.to_srgb(),
+            Color::Rgba16(color) => color.to_srgb(),
+            Color::RgbaF32(color) => color.to_srgb(),
+            Color::RgbaF64(color) => color.to_srgb(),
+            Color::Srgb(color) => color.to_srgb(),
+            Color::Hsl(color) => color.to_srgb(),
+            Color::Hsv(color) => color.to_srgb(),
+            Color::Cmyk(color) => color.to_srgb(),
+            Color::LinearRgb(color) => color.to_srgb(),
+            Color::Xyz(color) => color.to_srgb(),
+            Color::Lab(color) => color.to_srgb(),
+            Color::Lch(color) => color.to_srgb(),
+            Color::Oklab(color) => color.to_srgb(),
+            Color::Oklch(color) => color.to_srgb(),
+            Color::DisplayP3(color) => color.to_srgb(),
+            Color::Rec2020(color) => color.to_srgb(),
+            Color::AdobeRgb(color) => color.to_srgb(),
+            Color::ProPhotoRgb(color) => color.to_srgb(),
+        }
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        Color::Srgb(color)
+    }
+}
+
+/// Implements `From` between every pair of the given color spaces by going
+/// through the `Srgb` hub, plus `From` into and out of the `Color` enum.
+macro_rules! impl_hub_conversions {
+    ($($space:ident),+ $(,)?) => {
+        $(
+            impl From<$space> for Color {
+                fn from(color: $space) -> Self {
+                    Color::$space(color)
+                }
+            }
+
+            impl From<Color> for $space {
+                fn from(color: Color) -> Self {
+                    <$space>::from_srgb(color.to_srgb())
+                }
+            }
+        )+
+        impl_hub_conversions!(@pairs $($space),+);
+    };
+    (@pairs $first:ident $(, $rest:ident)*) => {
+        $(
+            impl From<$first> for $rest {
+                fn from(color: $first) -> Self {
+                    <$rest>::from_srgb(color.to_srgb())
+                }
+            }
+
+            impl From<$rest> for $first {
+                fn from(color: $rest) -> Self {
+                    <$first>::from_srgb(color.to_srgb())
+                }
+            }
+        )*
+        impl_hub_conversions!(@pairs $($rest),*);
+    };
+    (@pairs) => {};
+}
+
+impl_hub_conversions!(
+    Rgba,
+    Rgba16,
+    RgbaF32,
+    RgbaF64,
+    Srgb,
+    Hsl,
+    Hsv,
+    Cmyk,
+    LinearRgb,
+    Xyz,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+    DisplayP3,
+    Rec2020,
+    AdobeRgb,
+    ProPhotoRgb,
+);
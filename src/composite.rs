@@ -0,0 +1,362 @@
+//! Alpha compositing and blend modes, following the W3C Compositing and
+//! Blending Level 1 specification (Compositing 1).
+//!
+//! Compositing combines a source color drawn onto a backdrop using one of the
+//! Porter-Duff operators ([`CompositeOperator`]). Blending ([`BlendMode`])
+//! first mixes the source with the backdrop color, as CSS `mix-blend-mode`
+//! does, and then composites the result over the backdrop.
+//!
+//! All arithmetic happens on gamma-encoded sRGB channels with premultiplied
+//! alpha, as browsers do.
+
+use crate::{ColorSpace, Rgba, Srgb};
+
+/// A Porter-Duff compositing operator.
+///
+/// Each operator weights the source by `Fa` and the backdrop by `Fb`:
+/// `co = αs × Fa × Cs + αb × Fb × Cb` and `αo = αs × Fa + αb × Fb`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CompositeOperator {
+    /// Neither source nor backdrop is shown
+    Clear,
+    /// Only the source is shown
+    Copy,
+    /// Only the backdrop is shown
+    Destination,
+    /// The source is drawn over the backdrop (the default for painting)
+    #[default]
+    Over,
+    /// The backdrop is drawn over the source
+    DestinationOver,
+    /// The source where it overlaps the backdrop
+    In,
+    /// The backdrop where it overlaps the source
+    DestinationIn,
+    /// The source where it does not overlap the backdrop
+    Out,
+    /// The backdrop where it does not overlap the source
+    DestinationOut,
+    /// The source where it overlaps the backdrop, over the backdrop
+    Atop,
+    /// The backdrop where it overlaps the source, over the source
+    DestinationAtop,
+    /// The parts of source and backdrop that do not overlap
+    Xor,
+    /// The sum of source and backdrop, clamped
+    Lighter,
+}
+
+impl CompositeOperator {
+    /// Returns the source and backdrop weights `(Fa, Fb)` for the given alphas
+    /// (Compositing 1, §9.1 Porter-Duff compositing operators).
+    fn factors(&self, source_alpha: f64, backdrop_alpha: f64) -> (f64, f64) {
+        let (a, b) = (source_alpha, backdrop_alpha);
+        match self {
+            CompositeOperator::Clear => (0.0, 0.0),
+            CompositeOperator::Copy => (1.0, 0.0),
+            CompositeOperator::Destination => (0.0, 1.0),
+            CompositeOperator::Over => (1.0, 1.0 - a),
+            CompositeOperator::DestinationOver => (1.0 - b, 1.0),
+            CompositeOperator::In => (b, 0.0),
+            CompositeOperator::DestinationIn => (0.0, a),
+            CompositeOperator::Out => (1.0 - b, 0.0),
+            CompositeOperator::DestinationOut => (0.0, 1.0 - a),
+            CompositeOperator::Atop => (b, 1.0 - a),
+            CompositeOperator::DestinationAtop => (1.0 - b, a),
+            CompositeOperator::Xor => (1.0 - b, 1.0 - a),
+            CompositeOperator::Lighter => (1.0, 1.0),
+        }
+    }
+}
+
+/// A CSS `mix-blend-mode`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum BlendMode {
+    /// The source color
+    #[default]
+    Normal,
+    /// Multiplies the channels; the result is never lighter
+    Multiply,
+    /// Inverse of multiplying the inverses; the result is never darker
+    Screen,
+    /// Multiplies or screens depending on the backdrop
+    Overlay,
+    /// The darker of each channel
+    Darken,
+    /// The lighter of each channel
+    Lighten,
+    /// Brightens the backdrop to reflect the source
+    ColorDodge,
+    /// Darkens the backdrop to reflect the source
+    ColorBurn,
+    /// Multiplies or screens depending on the source
+    HardLight,
+    /// A softer version of hard light
+    SoftLight,
+    /// The absolute difference of each channel
+    Difference,
+    /// Like difference, with lower contrast
+    Exclusion,
+    /// The hue of the source with the saturation and luminosity of the backdrop
+    Hue,
+    /// The saturation of the source with the hue and luminosity of the backdrop
+    Saturation,
+    /// The hue and saturation of the source with the luminosity of the backdrop
+    Color,
+    /// The luminosity of the source with the hue and saturation of the backdrop
+    Luminosity,
+}
+
+/// Composites `source` onto `backdrop` with a Porter-Duff operator.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::Srgb;
+/// use color_parser::composite::{CompositeOperator, composite};
+///
+/// let red = Srgb { red: 1.0, green: 0.0, blue: 0.0, alpha: 0.5 };
+/// let blue = Srgb { red: 0.0, green: 0.0, blue: 1.0, alpha: 1.0 };
+///
+/// let result = composite(&red, &blue, CompositeOperator::Over);
+/// assert_eq!(result, Srgb { red: 0.5, green: 0.0, blue: 0.5, alpha: 1.0 });
+/// ```
+pub fn composite(source: &Srgb, backdrop: &Srgb, operator: CompositeOperator) -> Srgb {
+    let (fa, fb) = operator.factors(source.alpha, backdrop.alpha);
+    let (source_weight, backdrop_weight) = (source.alpha * fa, backdrop.alpha * fb);
+
+    let mut alpha = source_weight + backdrop_weight;
+    let mut premultiplied = [
+        source.red * source_weight + backdrop.red * backdrop_weight,
+        source.green * source_weight + backdrop.green * backdrop_weight,
+        source.blue * source_weight + backdrop.blue * backdrop_weight,
+    ];
+    if operator == CompositeOperator::Lighter {
+        alpha = alpha.min(1.0);
+        premultiplied = premultiplied.map(|c| c.min(1.0));
+    }
+
+    unpremultiply(premultiplied, alpha)
+}
+
+/// Blends `source` with `backdrop` and composites the result over it, as CSS
+/// `mix-blend-mode` does.
+///
+/// Where the backdrop is transparent, the source shows unblended:
+/// `Cs' = (1 - αb) × Cs + αb × B(Cb, Cs)`.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::Srgb;
+/// use color_parser::composite::{BlendMode, blend};
+///
+/// let gray = Srgb { red: 0.5, green: 0.5, blue: 0.5, alpha: 1.0 };
+/// let result = blend(&gray, &gray, BlendMode::Multiply);
+/// assert_eq!(result.red, 0.25);
+/// ```
+pub fn blend(source: &Srgb, backdrop: &Srgb, mode: BlendMode) -> Srgb {
+    let cs = [source.red, source.green, source.blue];
+    let cb = [backdrop.red, backdrop.green, backdrop.blue];
+    let blended = blend_channels(cs, cb, mode);
+
+    let mixed = [0, 1, 2].map(|i| (1.0 - backdrop.alpha) * cs[i] + backdrop.alpha * blended[i]);
+    let source = Srgb {
+        red: mixed[0],
+        green: mixed[1],
+        blue: mixed[2],
+        alpha: source.alpha,
+    };
+    composite(&source, backdrop, CompositeOperator::Over)
+}
+
+/// Applies the blend function `B(Cb, Cs)` of a mode to unpremultiplied channels
+/// (Compositing 1, §10.1 and §10.2).
+fn blend_channels(cs: [f64; 3], cb: [f64; 3], mode: BlendMode) -> [f64; 3] {
+    let separable = |f: fn(f64, f64) -> f64| [0, 1, 2].map(|i| f(cb[i], cs[i]));
+
+    match mode {
+        BlendMode::Normal => cs,
+        BlendMode::Multiply => separable(multiply),
+        BlendMode::Screen => separable(screen),
+        BlendMode::Overlay => separable(|cb, cs| hard_light(cs, cb)),
+        BlendMode::Darken => separable(f64::min),
+        BlendMode::Lighten => separable(f64::max),
+        BlendMode::ColorDodge => separable(color_dodge),
+        BlendMode::ColorBurn => separable(color_burn),
+        BlendMode::HardLight => separable(hard_light),
+        BlendMode::SoftLight => separable(soft_light),
+        BlendMode::Difference => separable(|cb, cs| (cb - cs).abs()),
+        BlendMode::Exclusion => separable(|cb, cs| cb + cs - 2.0 * cb * cs),
+        BlendMode::Hue => set_lum(set_sat(cs, sat(cb)), lum(cb)),
+        BlendMode::Saturation => set_lum(set_sat(cb, sat(cs)), lum(cb)),
+        BlendMode::Color => set_lum(cs, lum(cb)),
+        BlendMode::Luminosity => set_lum(cb, lum(cs)),
+    }
+}
+
+/// `B(Cb, Cs) = Cb × Cs` (Compositing 1, §10.1.2 multiply).
+fn multiply(cb: f64, cs: f64) -> f64 {
+    cb * cs
+}
+
+/// `B(Cb, Cs) = Cb + Cs - Cb × Cs` (Compositing 1, §10.1.3 screen).
+fn screen(cb: f64, cs: f64) -> f64 {
+    cb + cs - cb * cs
+}
+
+/// `Multiply(Cb, 2 × Cs)` for `Cs ≤ 0.5`, else `Screen(Cb, 2 × Cs - 1)`
+/// (Compositing 1, §10.1.9 hard-light).
+fn hard_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        multiply(cb, 2.0 * cs)
+    } else {
+        screen(cb, 2.0 * cs - 1.0)
+    }
+}
+
+/// `B(Cb, Cs) = min(1, Cb / (1 - Cs))` (Compositing 1, §10.1.7 color-dodge).
+fn color_dodge(cb: f64, cs: f64) -> f64 {
+    if cb == 0.0 {
+        0.0
+    } else if cs >= 1.0 {
+        1.0
+    } else {
+        (cb / (1.0 - cs)).min(1.0)
+    }
+}
+
+/// `B(Cb, Cs) = 1 - min(1, (1 - Cb) / Cs)` (Compositing 1, §10.1.8 color-burn).
+fn color_burn(cb: f64, cs: f64) -> f64 {
+    if cb == 1.0 {
+        1.0
+    } else if cs <= 0.0 {
+        0.0
+    } else {
+        1.0 - ((1.0 - cb) / cs).min(1.0)
+    }
+}
+
+/// `Cb - (1 - 2 × Cs) × Cb × (1 - Cb)` for `Cs ≤ 0.5`, else
+/// `Cb + (2 × Cs - 1) × (D(Cb) - Cb)` (Compositing 1, §10.1.10 soft-light).
+fn soft_light(cb: f64, cs: f64) -> f64 {
+    if cs <= 0.5 {
+        cb - (1.0 - 2.0 * cs) * cb * (1.0 - cb)
+    } else {
+        let d = if cb <= 0.25 {
+            ((16.0 * cb - 12.0) * cb + 4.0) * cb
+        } else {
+            cb.sqrt()
+        };
+        cb + (2.0 * cs - 1.0) * (d - cb)
+    }
+}
+
+/// `Lum(C) = 0.3 × R + 0.59 × G + 0.11 × B` (Compositing 1, §10.2).
+fn lum(c: [f64; 3]) -> f64 {
+    0.3 * c[0] + 0.59 * c[1] + 0.11 * c[2]
+}
+
+/// `ClipColor(C)`: moves out-of-range channels back into [0, 1], keeping the
+/// luminosity (Compositing 1, §10.2).
+fn clip_color(c: [f64; 3]) -> [f64; 3] {
+    let l = lum(c);
+    let n = c[0].min(c[1]).min(c[2]);
+    let x = c[0].max(c[1]).max(c[2]);
+
+    let mut c = c;
+    if n < 0.0 {
+        c = c.map(|channel| l + (channel - l) * l / (l - n));
+    }
+    if x > 1.0 {
+        c = c.map(|channel| l + (channel - l) * (1.0 - l) / (x - l));
+    }
+    c
+}
+
+/// `SetLum(C, l)`: shifts `C` to luminosity `l`, then clips (Compositing 1, §10.2).
+fn set_lum(c: [f64; 3], l: f64) -> [f64; 3] {
+    let d = l - lum(c);
+    clip_color(c.map(|channel| channel + d))
+}
+
+/// `Sat(C) = max(C) - min(C)` (Compositing 1, §10.2).
+fn sat(c: [f64; 3]) -> f64 {
+    c[0].max(c[1]).max(c[2]) - c[0].min(c[1]).min(c[2])
+}
+
+/// `SetSat(C, s)`: rescales `C` to saturation `s`, keeping its hue (Compositing 1, §10.2).
+fn set_sat(c: [f64; 3], s: f64) -> [f64; 3] {
+    // Indices of the minimum, middle and maximum channels
+    let mut order = [0, 1, 2];
+    order.sort_by(|&a, &b| c[a].total_cmp(&c[b]));
+    let [min, mid, max] = order;
+
+    let mut result = [0.0; 3];
+    if c[max] > c[min] {
+        result[mid] = (c[mid] - c[min]) * s / (c[max] - c[min]);
+        result[max] = s;
+    }
+    result
+}
+
+/// Divides premultiplied channels by alpha, leaving transparent black at zero alpha.
+fn unpremultiply(premultiplied: [f64; 3], alpha: f64) -> Srgb {
+    let [red, green, blue] = if alpha == 0.0 {
+        [0.0; 3]
+    } else {
+        premultiplied.map(|c| c / alpha)
+    };
+
+    Srgb {
+        red,
+        green,
+        blue,
+        alpha,
+    }
+}
+
+impl Rgba {
+    /// Composites this color onto `backdrop` with a Porter-Duff operator.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::composite::CompositeOperator;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// let transparent = Rgba { red: 0, green: 0, blue: 0, alpha: 0 };
+    /// assert_eq!(red.composite(&transparent, CompositeOperator::In), transparent);
+    /// ```
+    pub fn composite(&self, backdrop: &Rgba, operator: CompositeOperator) -> Rgba {
+        Rgba::from_srgb(composite(&self.to_srgb(), &backdrop.to_srgb(), operator))
+    }
+
+    /// Composites this color over `backdrop`, the usual way of painting.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 128 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// assert_eq!(red.over(&white), Rgba { red: 255, green: 127, blue: 127, alpha: 255 });
+    /// ```
+    pub fn over(&self, backdrop: &Rgba) -> Rgba {
+        self.composite(backdrop, CompositeOperator::Over)
+    }
+
+    /// Blends this color with `backdrop` using a CSS blend mode, then
+    /// composites it over the backdrop.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::composite::BlendMode;
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+    /// let gray = Rgba { red: 128, green: 128, blue: 128, alpha: 255 };
+    /// assert_eq!(orange.blend(&gray, BlendMode::Multiply), Rgba { red: 128, green: 68, blue: 0, alpha: 255 });
+    /// ```
+    pub fn blend(&self, backdrop: &Rgba, mode: BlendMode) -> Rgba {
+        Rgba::from_srgb(blend(&self.to_srgb(), &backdrop.to_srgb(), mode))
+    }
+}
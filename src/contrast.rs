@@ -0,0 +1,357 @@
+//! Text contrast and accessibility checks.
+//!
+//! Implements the WCAG 2.x relative luminance and contrast ratio, and the APCA
+//! lightness contrast (Lc) proposed for WCAG 3. Translucent colors are
+//! composited before measuring: the background over white, then the foreground
+//! over the result, which is what a reader sees on screen.
+
+use crate::composite::{CompositeOperator, composite};
+use crate::xyz::srgb_to_linear;
+use crate::{ColorSpace, Rgba, Srgb};
+
+/// A WCAG conformance level.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum WcagLevel {
+    /// Level AA, the usual legal and organizational target
+    Aa,
+    /// Level AAA, the enhanced contrast level
+    Aaa,
+}
+
+/// The text size category used by the WCAG contrast thresholds.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TextSize {
+    /// Text below the large-scale threshold
+    Normal,
+    /// Large-scale text: at least 18pt, or 14pt bold
+    Large,
+}
+
+impl WcagLevel {
+    /// Returns the minimum contrast ratio required for text of the given size.
+    pub fn required_ratio(&self, size: TextSize) -> f64 {
+        match (self, size) {
+            (WcagLevel::Aa, TextSize::Normal) => 4.5,
+            (WcagLevel::Aa, TextSize::Large) => 3.0,
+            (WcagLevel::Aaa, TextSize::Normal) => 7.0,
+            (WcagLevel::Aaa, TextSize::Large) => 4.5,
+        }
+    }
+}
+
+/// The WCAG 2.x contrast between a text color and its background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WcagContrast {
+    /// Contrast ratio [1–21]
+    pub ratio: f64,
+    /// Opaque text color after compositing
+    pub foreground: Rgba,
+    /// Opaque background color after compositing
+    pub background: Rgba,
+}
+
+impl WcagContrast {
+    /// Returns whether the contrast meets `level` for text of the given size.
+    ///
+    /// The ratio is not rounded, so 4.499:1 fails a 4.5:1 requirement.
+    pub fn passes(&self, level: WcagLevel, size: TextSize) -> bool {
+        self.ratio >= level.required_ratio(size)
+    }
+}
+
+/// The APCA-W3 (0.0.98G-4g) lightness contrast between a text color and its
+/// background.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ApcaContrast {
+    /// Lightness contrast Lc, roughly [-108–106]; positive for dark text on a
+    /// light background and negative for light text on a dark background
+    pub lc: f64,
+}
+
+/// Which of the text and background is lighter.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Polarity {
+    /// Dark text on a light background (positive Lc)
+    DarkOnLight,
+    /// Light text on a dark background (negative Lc)
+    LightOnDark,
+}
+
+/// APCA minimum font sizes in px, by Lc (rows) and font weight 100–900
+/// (columns), from the `apca-w3` font lookup table. `NOT_TEXT` marks
+/// contrast only suitable for non-text elements, `UNUSABLE` contrast that is
+/// not usable at all.
+const FONT_SIZES: [(f64, [f64; 9]); 25] = [
+    (0.0, [UNUSABLE; 9]),
+    (10.0, [UNUSABLE; 9]),
+    (15.0, [NOT_TEXT; 9]),
+    (20.0, [NOT_TEXT; 9]),
+    (
+        25.0,
+        [
+            NOT_TEXT, NOT_TEXT, NOT_TEXT, 120.0, 120.0, 108.0, 96.0, 96.0, 96.0,
+        ],
+    ),
+    (
+        30.0,
+        [
+            NOT_TEXT, NOT_TEXT, 120.0, 108.0, 108.0, 96.0, 72.0, 72.0, 72.0,
+        ],
+    ),
+    (
+        35.0,
+        [NOT_TEXT, 120.0, 108.0, 96.0, 72.0, 60.0, 48.0, 48.0, 48.0],
+    ),
+    (
+        40.0,
+        [120.0, 108.0, 96.0, 60.0, 48.0, 42.0, 32.0, 32.0, 32.0],
+    ),
+    (
+        45.0,
+        [108.0, 96.0, 72.0, 42.0, 32.0, 28.0, 24.0, 24.0, 24.0],
+    ),
+    (50.0, [96.0, 72.0, 60.0, 32.0, 28.0, 24.0, 21.0, 21.0, 21.0]),
+    (55.0, [80.0, 60.0, 48.0, 28.0, 24.0, 21.0, 18.0, 18.0, 18.0]),
+    (60.0, [72.0, 48.0, 42.0, 24.0, 21.0, 18.0, 16.0, 16.0, 18.0]),
+    (
+        65.0,
+        [68.0, 46.0, 32.0, 21.75, 19.0, 17.0, 15.0, 16.0, 18.0],
+    ),
+    (70.0, [64.0, 44.0, 28.0, 19.5, 18.0, 16.0, 14.5, 16.0, 18.0]),
+    (75.0, [60.0, 42.0, 24.0, 18.0, 16.0, 15.0, 14.0, 16.0, 18.0]),
+    (
+        80.0,
+        [56.0, 38.25, 23.0, 17.25, 15.81, 14.81, 14.0, 16.0, 18.0],
+    ),
+    (
+        85.0,
+        [52.0, 34.5, 22.0, 16.5, 15.625, 14.625, 14.0, 16.0, 18.0],
+    ),
+    (90.0, [48.0, 32.0, 21.0, 16.0, 15.5, 14.5, 14.0, 16.0, 18.0]),
+    (95.0, [45.0, 28.0, 19.5, 15.5, 15.0, 14.0, 13.5, 16.0, 18.0]),
+    (
+        100.0,
+        [42.0, 26.5, 18.5, 15.0, 14.5, 13.5, 13.0, 16.0, 18.0],
+    ),
+    (
+        105.0,
+        [39.0, 25.0, 18.0, 14.5, 14.0, 13.0, 12.0, 16.0, 18.0],
+    ),
+    (
+        110.0,
+        [36.0, 24.0, 18.0, 14.0, 13.0, 12.0, 11.0, 16.0, 18.0],
+    ),
+    (
+        115.0,
+        [34.5, 22.5, 17.25, 12.5, 11.875, 11.25, 10.625, 14.5, 16.5],
+    ),
+    (
+        120.0,
+        [33.0, 21.0, 16.5, 11.0, 11.0, 11.0, 10.0, 13.0, 15.0],
+    ),
+    (
+        125.0,
+        [32.0, 20.0, 16.0, 10.0, 10.0, 10.0, 10.0, 12.0, 14.0],
+    ),
+];
+
+/// Font lookup marker: usable for non-text elements only
+const NOT_TEXT: f64 = 777.0;
+/// Font lookup marker: not usable
+const UNUSABLE: f64 = 999.0;
+
+/// APCA display gamma
+const APCA_TRC: f64 = 2.4;
+/// Luminance below which APCA soft-clamps toward black
+const APCA_BLACK_THRESHOLD: f64 = 0.022;
+/// Exponent of the black soft clamp
+const APCA_BLACK_CLAMP: f64 = 1.414;
+/// Luminance difference below which the contrast is zero
+const APCA_DELTA_Y_MIN: f64 = 0.0005;
+/// Output scale shared by both polarities
+const APCA_SCALE: f64 = 1.14;
+/// Output offset shared by both polarities
+const APCA_OFFSET: f64 = 0.027;
+/// Scaled contrast below which the output is clipped to zero
+const APCA_LOW_CLIP: f64 = 0.1;
+
+impl ApcaContrast {
+    /// Returns which of the text and background is lighter.
+    ///
+    /// A contrast of zero is reported as [`Polarity::DarkOnLight`].
+    pub fn polarity(&self) -> Polarity {
+        if self.lc < 0.0 {
+            Polarity::LightOnDark
+        } else {
+            Polarity::DarkOnLight
+        }
+    }
+
+    /// Returns the minimum font size in px for body text of the given weight, or
+    /// `None` if the contrast is too low for text at any size.
+    ///
+    /// Weights are rounded to the nearest hundred within 100–900, and the Lc
+    /// magnitude is rounded down to the table row below it, so the result errs
+    /// on the side of larger text.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let gray = Rgba { red: 136, green: 136, blue: 136, alpha: 255 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// let contrast = gray.apca_contrast(&white);
+    /// assert_eq!(contrast.min_font_size(400), Some(24.0));
+    /// assert_eq!(contrast.min_font_size(700), Some(16.0));
+    /// ```
+    pub fn min_font_size(&self, weight: u16) -> Option<f64> {
+        let column = ((weight.clamp(100, 900) + 50) / 100 - 1) as usize;
+        let lc = self.lc.abs();
+        let (_, sizes) = FONT_SIZES
+            .iter()
+            .rev()
+            .find(|(row, _)| *row <= lc)
+            .unwrap_or(&FONT_SIZES[0]);
+
+        let size = sizes[column];
+        (size < NOT_TEXT).then_some(size)
+    }
+}
+
+impl Rgba {
+    /// Returns the WCAG relative luminance of this color, ignoring alpha.
+    ///
+    /// Uses the IEC 61966-2-1 transfer function; WCAG 2.x quotes a 0.03928
+    /// threshold from an older draft, which makes no difference at 8 bits.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// assert_eq!(white.relative_luminance(), 1.0);
+    /// ```
+    pub fn relative_luminance(&self) -> f64 {
+        luminance(&self.to_srgb())
+    }
+
+    /// Returns the WCAG contrast ratio between this text color and `background`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let black = Rgba { red: 0, green: 0, blue: 0, alpha: 255 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// assert_eq!(black.contrast_ratio(&white), 21.0);
+    /// ```
+    pub fn contrast_ratio(&self, background: &Rgba) -> f64 {
+        self.wcag_contrast(background).ratio
+    }
+
+    /// Measures the WCAG contrast between this text color and `background`,
+    /// compositing translucent colors first.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::contrast::{TextSize, WcagLevel};
+    ///
+    /// let gray = Rgba { red: 118, green: 118, blue: 118, alpha: 255 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// let contrast = gray.wcag_contrast(&white);
+    /// assert!(contrast.passes(WcagLevel::Aa, TextSize::Normal));
+    /// assert!(!contrast.passes(WcagLevel::Aaa, TextSize::Normal));
+    /// ```
+    pub fn wcag_contrast(&self, background: &Rgba) -> WcagContrast {
+        let (foreground, background) = flatten(self, background);
+
+        // Lighter color over darker, each offset by the 0.05 viewing flare
+        let (l1, l2) = (luminance(&foreground), luminance(&background));
+        let ratio = (l1.max(l2) + 0.05) / (l1.min(l2) + 0.05);
+
+        WcagContrast {
+            ratio,
+            foreground: Rgba::from_srgb(foreground),
+            background: Rgba::from_srgb(background),
+        }
+    }
+
+    /// Measures the APCA lightness contrast of this text color on `background`,
+    /// compositing translucent colors first.
+    ///
+    /// Unlike the WCAG ratio, the result depends on which color is the text:
+    /// swapping the colors changes the magnitude as well as the sign.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::contrast::Polarity;
+    ///
+    /// let black = Rgba { red: 0, green: 0, blue: 0, alpha: 255 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// assert_eq!(black.apca_contrast(&white).lc.round(), 106.0);
+    /// assert_eq!(white.apca_contrast(&black).polarity(), Polarity::LightOnDark);
+    /// ```
+    pub fn apca_contrast(&self, background: &Rgba) -> ApcaContrast {
+        let (foreground, background) = flatten(self, background);
+
+        let text = apca_luminance(&foreground);
+        let back = apca_luminance(&background);
+        if (back - text).abs() < APCA_DELTA_Y_MIN {
+            return ApcaContrast { lc: 0.0 };
+        }
+
+        // Each polarity uses its own exponents
+        let lc = if back > text {
+            let sapc = (back.powf(0.56) - text.powf(0.57)) * APCA_SCALE;
+            if sapc < APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc - APCA_OFFSET
+            }
+        } else {
+            let sapc = (back.powf(0.65) - text.powf(0.62)) * APCA_SCALE;
+            if sapc > -APCA_LOW_CLIP {
+                0.0
+            } else {
+                sapc + APCA_OFFSET
+            }
+        };
+
+        ApcaContrast { lc: lc * 100.0 }
+    }
+}
+
+/// Computes the APCA screen luminance, soft-clamped near black.
+fn apca_luminance(color: &Srgb) -> f64 {
+    let y = 0.2126729 * color.red.max(0.0).powf(APCA_TRC)
+        + 0.7151522 * color.green.max(0.0).powf(APCA_TRC)
+        + 0.0721750 * color.blue.max(0.0).powf(APCA_TRC);
+
+    if y > APCA_BLACK_THRESHOLD {
+        y
+    } else {
+        y + (APCA_BLACK_THRESHOLD - y).powf(APCA_BLACK_CLAMP)
+    }
+}
+
+/// Computes the relative luminance of a gamma-encoded sRGB color.
+fn luminance(color: &Srgb) -> f64 {
+    0.2126 * srgb_to_linear(color.red)
+        + 0.7152 * srgb_to_linear(color.green)
+        + 0.0722 * srgb_to_linear(color.blue)
+}
+
+/// Composites the background over white and the foreground over the result.
+fn flatten(foreground: &Rgba, background: &Rgba) -> (Srgb, Srgb) {
+    let white = Srgb {
+        red: 1.0,
+        green: 1.0,
+        blue: 1.0,
+        alpha: 1.0,
+    };
+    let background = composite(&background.to_srgb(), &white, CompositeOperator::Over);
+    let foreground = composite(&foreground.to_srgb(), &background, CompositeOperator::Over);
+    (foreground, background)
+}
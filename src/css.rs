@@ -0,0 +1,713 @@
+//! Parser for CSS Color Level 4 color strings.
+//!
+//! Supports:
+//! - Hex colors prefixed with `#` (delegated to [`parse_hex_to_rgba`])
+//! - Named colors such as `rebeccapurple` or `transparent` (see [`named_color`])
+//! - `rgb()` / `rgba()`
+//! - `hsl()` / `hsla()`
+//! - `hwb()`
+//! - `lab()` / `lch()` (D50, see [`Lab`] and [`Lch`])
+//! - `oklab()` / `oklch()` (see [`Oklab`] and [`Oklch`])
+//! - `color()` with the `srgb`, `srgb-linear`, `display-p3`, `rec2020`, `a98-rgb`,
+//!   `prophoto-rgb`, `xyz-d50` and `xyz-d65` (or `xyz`) color spaces
+//! - `device-cmyk()` (see [`Cmyk`], naive conversion without a color profile)
+//! - `color-mix()` (see [`mix`](crate::mix::mix))
+//!
+//! Both the legacy comma-separated syntax (`rgb(255, 0, 0, 0.5)`) and the
+//! modern space-separated syntax (`rgb(255 0 0 / 50%)`) are accepted, along
+//! with percentages, `deg`/`rad`/`grad`/`turn` hue units and the `none` keyword.
+
+use std::ops::Range;
+
+use crate::adaptation::AdaptationMethod;
+use crate::error::offset_of;
+use crate::mix::parse_color_mix;
+use crate::wide_gamut::{AdobeRgb, DisplayP3, ProPhotoRgb, Rec2020, RgbSpace};
+use crate::xyz::WhitePoint;
+use crate::{
+    Cmyk, Color, ColorParserError, ErrorKind, Hsl, Lab, Lch, LinearRgb, Oklab, Oklch, Rgba, Srgb,
+    Xyz, hsl_to_rgb, named_color, parse_hex_to_rgba, unit_to_u8,
+};
+
+/// Parses a CSS color string into a [`Color`].
+///
+/// Function names, units and keywords are matched case-insensitively and
+/// surrounding whitespace is ignored. Out-of-range channel values are clamped.
+///
+/// # Errors
+/// - `UnknownColorName` if a bare keyword is not a known color name.
+/// - `UnknownFunction` if the function name is not supported.
+/// - `InvalidSyntax` if the arguments, separators or units are malformed.
+/// - Any error returned by [`parse_hex_to_rgba`] for `#` colors.
+///
+/// The error span points into `input`, surrounding whitespace included.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Color, Rgba, parse_css_color};
+///
+/// let color = parse_css_color("rgb(255 0 0 / 50%)").unwrap();
+/// assert_eq!(color, Color::Rgba(Rgba { red: 255, green: 0, blue: 0, alpha: 128 }));
+///
+/// let color = parse_css_color("hsl(0.5turn, 100%, 50%)").unwrap();
+/// assert_eq!(color.to_rgba(), Rgba { red: 0, green: 255, blue: 255, alpha: 255 });
+///
+/// let error = parse_css_color("hsl(120 50% 50q)").unwrap_err();
+/// assert_eq!(error.span, 12..15);
+/// ```
+pub fn parse_css_color(input: &str) -> Result<Color, ColorParserError> {
+    let trimmed = input.trim();
+    parse_trimmed(trimmed).map_err(|e| e.shifted(offset_of(input, trimmed)).within(input))
+}
+
+/// Parses a color without surrounding whitespace; error spans are relative to `input`.
+fn parse_trimmed(input: &str) -> Result<Color, ColorParserError> {
+    if input.starts_with('#') {
+        return parse_hex_to_rgba(input).map(Color::Rgba);
+    }
+
+    // A bare identifier is a named color keyword
+    if !input.is_empty() && input.chars().all(|c| c.is_ascii_alphanumeric()) {
+        return named_color(input).map(Color::Rgba).ok_or_else(|| {
+            ColorParserError::at(ErrorKind::UnknownColorName, 0..input.len())
+                .expecting(&["a CSS color name"])
+        });
+    }
+
+    let (name, args) = split_function(input)?;
+    let args_start = offset_of(input, args);
+
+    // color-mix() nests whole colors, which the tokenizer does not handle
+    if name.eq_ignore_ascii_case("color-mix") {
+        return parse_color_mix(args).map_err(|e| e.shifted(args_start));
+    }
+    let args = parse_arguments(args, args_start)?;
+
+    match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => parse_rgb(&args).map(Color::Rgba),
+        "hsl" | "hsla" => parse_hsl(&args).map(Color::Hsl),
+        "hwb" => parse_hwb(&args).map(Color::Rgba),
+        "lab" => parse_lab(&args).map(Color::Lab),
+        "lch" => parse_lch(&args).map(Color::Lch),
+        "oklab" => parse_oklab(&args).map(Color::Oklab),
+        "oklch" => parse_oklch(&args).map(Color::Oklch),
+        "color" => parse_color_function(&args),
+        "device-cmyk" => parse_device_cmyk(&args).map(Color::Cmyk),
+        _ => Err(
+            ColorParserError::at(ErrorKind::UnknownFunction, 0..name.len()).expecting(&[
+                "rgb()",
+                "hsl()",
+                "hwb()",
+                "lab()",
+                "lch()",
+                "oklab()",
+                "oklch()",
+                "color()",
+                "color-mix()",
+                "device-cmyk()",
+            ]),
+        ),
+    }
+}
+
+/// A single token inside the parentheses of a color function.
+#[derive(Debug, Clone, PartialEq)]
+enum Token {
+    Number(f64),
+    Percentage(f64),
+    Dimension(f64, String),
+    Ident(String),
+    Comma,
+    Slash,
+}
+
+/// A token with its byte range in the input.
+#[derive(Debug, Clone, PartialEq)]
+struct Spanned {
+    token: Token,
+    span: Range<usize>,
+}
+
+impl Spanned {
+    /// Returns an `InvalidSyntax` error pointing at this token.
+    fn error(&self, expected: &[&str]) -> ColorParserError {
+        ColorParserError::at(ErrorKind::InvalidSyntax, self.span.clone()).expecting(expected)
+    }
+}
+
+/// A color function argument list, split into channels and an optional alpha.
+#[derive(Debug)]
+struct Arguments {
+    /// Channel components, in order
+    channels: Vec<Spanned>,
+    /// Alpha component, if one was given
+    alpha: Option<Spanned>,
+    /// Whether the legacy comma-separated syntax was used
+    legacy: bool,
+    /// Byte range of the whole argument string in the input
+    span: Range<usize>,
+}
+
+impl Arguments {
+    /// Returns an `InvalidSyntax` error for the wrong number of channels,
+    /// pointing at the extra channels or at the end of the arguments.
+    fn count_error(&self, count: usize, expected: &str) -> ColorParserError {
+        let span = match (self.channels.get(count), self.channels.last()) {
+            (Some(extra), Some(last)) => extra.span.start..last.span.end,
+            _ => self.span.end..self.span.end,
+        };
+        ColorParserError::at(ErrorKind::InvalidSyntax, span).expecting(&[expected])
+    }
+
+    /// Returns an `InvalidSyntax` error for the legacy syntax in a function
+    /// that only supports the modern one.
+    fn legacy_error(&self) -> ColorParserError {
+        ColorParserError::at(ErrorKind::InvalidSyntax, self.span.clone())
+            .expecting(&["space-separated channels"])
+    }
+}
+
+/// Splits `name(args)` into its function name and raw argument string.
+fn split_function(input: &str) -> Result<(&str, &str), ColorParserError> {
+    let open = input.find('(').ok_or_else(|| {
+        ColorParserError::at(ErrorKind::InvalidSyntax, 0..input.len())
+            .expecting(&["a hex color, color name or color function"])
+    })?;
+    let name = &input[..open];
+    let args = input[open + 1..].strip_suffix(')').ok_or_else(|| {
+        ColorParserError::at(ErrorKind::InvalidSyntax, input.len()..input.len()).expecting(&["`)`"])
+    })?;
+
+    if name.is_empty() || !name.chars().all(|c| c.is_ascii_alphanumeric() || c == '-') {
+        return Err(
+            ColorParserError::at(ErrorKind::InvalidSyntax, 0..open).expecting(&["a function name"])
+        );
+    }
+
+    Ok((name, args))
+}
+
+/// Tokenizes the argument string and validates its separators.
+///
+/// The legacy syntax is `a, b, c[, alpha]`; the modern syntax is `a b c [/ alpha]`.
+/// The two may not be mixed. `start` is the offset of `args` in the input.
+fn parse_arguments(args: &str, start: usize) -> Result<Arguments, ColorParserError> {
+    let tokens = tokenize(args, start)?;
+    let span = start..start + args.len();
+
+    if tokens.iter().any(|t| t.token == Token::Comma) {
+        // Legacy syntax: values must alternate with commas, and no slash is allowed
+        let mut values = Vec::new();
+        for (i, spanned) in tokens.iter().enumerate() {
+            let expect_value = i % 2 == 0;
+            match (expect_value, &spanned.token) {
+                (true, Token::Comma | Token::Slash) => {
+                    return Err(spanned.error(&["a number or percentage"]));
+                }
+                (true, _) => values.push(spanned.clone()),
+                (false, Token::Comma) => {}
+                (false, _) => return Err(spanned.error(&["`,`"])),
+            }
+        }
+        if let Some(comma) = tokens.last().filter(|_| tokens.len() % 2 == 0) {
+            // Trailing comma
+            return Err(comma.error(&["a number or percentage"]));
+        }
+        if let Some(ident) = values.iter().find(|v| matches!(v.token, Token::Ident(_))) {
+            // `none` is not allowed in the legacy syntax
+            return Err(ident.error(&["a number or percentage"]));
+        }
+
+        let alpha = if values.len() == 4 {
+            values.pop()
+        } else {
+            None
+        };
+        return Ok(Arguments {
+            channels: values,
+            alpha,
+            legacy: true,
+            span,
+        });
+    }
+
+    // Modern syntax: channels, then optionally a slash followed by exactly one alpha value
+    let mut parts = tokens.split(|t| t.token == Token::Slash);
+    let channels = parts.next().unwrap_or_default().to_vec();
+    let alpha = match parts.next() {
+        Some([alpha]) => Some(alpha.clone()),
+        Some([]) => {
+            // Point just past the slash
+            let end = tokens
+                .iter()
+                .find(|t| t.token == Token::Slash)
+                .map(|t| t.span.end);
+            let end = end.unwrap_or(span.end);
+            return Err(ColorParserError::at(ErrorKind::InvalidSyntax, end..end)
+                .expecting(&["an alpha value"]));
+        }
+        Some([_, extra, ..]) => return Err(extra.error(&["a single alpha value"])),
+        None => None,
+    };
+    if let Some(slash) = tokens.iter().filter(|t| t.token == Token::Slash).nth(1) {
+        return Err(slash.error(&["a single alpha value"]));
+    }
+
+    Ok(Arguments {
+        channels,
+        alpha,
+        legacy: false,
+        span,
+    })
+}
+
+/// Splits an argument string into number, percentage, dimension, identifier
+/// and separator tokens, with spans offset by `start`.
+fn tokenize(args: &str, start: usize) -> Result<Vec<Spanned>, ColorParserError> {
+    let bytes = args.as_bytes();
+    let mut tokens = Vec::new();
+    let mut i = 0;
+
+    while i < bytes.len() {
+        let c = bytes[i];
+        let begin = i;
+        let token = if c.is_ascii_whitespace() {
+            i += 1;
+            continue;
+        } else if c == b',' {
+            i += 1;
+            Token::Comma
+        } else if c == b'/' {
+            i += 1;
+            Token::Slash
+        } else if c.is_ascii_digit() || matches!(c, b'+' | b'-' | b'.') {
+            let number_error = || {
+                ColorParserError::at(ErrorKind::InvalidSyntax, start + begin..start + begin + 1)
+                    .expecting(&["a number"])
+            };
+            let end = scan_number(bytes, i).ok_or_else(number_error)?;
+            let value: f64 = args[i..end].parse().map_err(|_| number_error())?;
+            i = end;
+
+            // A number may be followed directly by `%` or a unit
+            if bytes.get(i) == Some(&b'%') {
+                i += 1;
+                Token::Percentage(value)
+            } else {
+                let unit_end = scan_unit(bytes, i);
+                if unit_end > i {
+                    let unit = args[i..unit_end].to_ascii_lowercase();
+                    i = unit_end;
+                    Token::Dimension(value, unit)
+                } else {
+                    Token::Number(value)
+                }
+            }
+        } else if c.is_ascii_alphabetic() {
+            i = scan_ident(bytes, i);
+            Token::Ident(args[begin..i].to_ascii_lowercase())
+        } else {
+            let width = args[i..].chars().next().map_or(1, char::len_utf8);
+            return Err(ColorParserError::at(
+                ErrorKind::InvalidSyntax,
+                start + i..start + i + width,
+            )
+            .expecting(&["a number, percentage, angle or keyword"]));
+        };
+
+        tokens.push(Spanned {
+            token,
+            span: start + begin..start + i,
+        });
+    }
+
+    Ok(tokens)
+}
+
+/// Returns the end index of a CSS number starting at `start`, if there is one.
+///
+/// Matches `[+-]? (\d+ | \d* \. \d+) ([eE] [+-]? \d+)?`.
+fn scan_number(bytes: &[u8], start: usize) -> Option<usize> {
+    let digits = |mut i: usize| {
+        while i < bytes.len() && bytes[i].is_ascii_digit() {
+            i += 1;
+        }
+        i
+    };
+
+    let mut i = start;
+    if matches!(bytes.get(i), Some(b'+' | b'-')) {
+        i += 1;
+    }
+
+    let int_end = digits(i);
+    let mut end = int_end;
+    if bytes.get(int_end) == Some(&b'.') {
+        let frac_end = digits(int_end + 1);
+        if frac_end > int_end + 1 {
+            end = frac_end;
+        }
+    }
+    if end == i {
+        // No digits at all
+        return None;
+    }
+
+    // Optional exponent, only consumed when followed by digits
+    if matches!(bytes.get(end), Some(b'e' | b'E')) {
+        let mut j = end + 1;
+        if matches!(bytes.get(j), Some(b'+' | b'-')) {
+            j += 1;
+        }
+        let exp_end = digits(j);
+        if exp_end > j {
+            end = exp_end;
+        }
+    }
+
+    Some(end)
+}
+
+/// Returns the end index of a unit (letters only) starting at `start`.
+fn scan_unit(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && bytes[i].is_ascii_alphabetic() {
+        i += 1;
+    }
+    i
+}
+
+/// Returns the end index of an identifier (a letter followed by letters,
+/// digits or hyphens, as in `display-p3`) starting at `start`.
+fn scan_ident(bytes: &[u8], start: usize) -> usize {
+    let mut i = start;
+    while i < bytes.len() && (bytes[i].is_ascii_alphanumeric() || bytes[i] == b'-') {
+        i += 1;
+    }
+    i
+}
+
+/// Returns `true` if the token is the `none` keyword.
+fn is_none(token: &Token) -> bool {
+    matches!(token, Token::Ident(ident) if ident == "none")
+}
+
+/// Resolves a hue component (number or angle) to degrees, or `None` for the `none` keyword.
+fn hue_component(spanned: &Spanned) -> Result<Option<f64>, ColorParserError> {
+    match &spanned.token {
+        Token::Number(value) => Ok(Some(*value)),
+        Token::Dimension(value, unit) => {
+            let degrees = match unit.as_str() {
+                "deg" => *value,
+                "rad" => value.to_degrees(),
+                "grad" => value * 0.9,
+                "turn" => value * 360.0,
+                _ => return Err(spanned.error(&["deg", "rad", "grad", "turn"])),
+            };
+            Ok(Some(degrees))
+        }
+        token if is_none(token) => Ok(None),
+        _ => Err(spanned.error(&["a number, angle or none"])),
+    }
+}
+
+/// Resolves a number or percentage component, where `100%` maps to `percent_ref`.
+///
+/// Returns `None` for the `none` keyword.
+fn number_component(spanned: &Spanned, percent_ref: f64) -> Result<Option<f64>, ColorParserError> {
+    match &spanned.token {
+        Token::Number(value) => Ok(Some(*value)),
+        Token::Percentage(value) => Ok(Some(value / 100.0 * percent_ref)),
+        token if is_none(token) => Ok(None),
+        _ => Err(spanned.error(&["a number, percentage or none"])),
+    }
+}
+
+/// Resolves the optional alpha component to a fraction in [0, 1].
+///
+/// A missing alpha is fully opaque; `none` is treated as zero.
+fn alpha_component(alpha: Option<&Spanned>) -> Result<f64, ColorParserError> {
+    match alpha {
+        Some(token) => Ok(number_component(token, 1.0)?.unwrap_or(0.0).clamp(0.0, 1.0)),
+        None => Ok(1.0),
+    }
+}
+
+/// Parses the arguments of `rgb()` / `rgba()`.
+fn parse_rgb(args: &Arguments) -> Result<Rgba, ColorParserError> {
+    if args.channels.len() != 3 {
+        return Err(args.count_error(3, "3 channels"));
+    }
+
+    // The legacy syntax requires either all numbers or all percentages
+    if args.legacy {
+        let is_percentage = |t: &Spanned| matches!(t.token, Token::Percentage(_));
+        let percentages = is_percentage(&args.channels[0]);
+        if let Some(mismatch) = args
+            .channels
+            .iter()
+            .find(|t| is_percentage(t) != percentages)
+        {
+            return Err(mismatch.error(&[if percentages {
+                "a percentage"
+            } else {
+                "a number"
+            }]));
+        }
+    }
+
+    let mut channels = [0u8; 3];
+    for (channel, token) in channels.iter_mut().zip(&args.channels) {
+        let value = number_component(token, 255.0)?.unwrap_or(0.0);
+        *channel = value.clamp(0.0, 255.0).round() as u8;
+    }
+
+    Ok(Rgba {
+        red: channels[0],
+        green: channels[1],
+        blue: channels[2],
+        alpha: unit_to_u8(alpha_component(args.alpha.as_ref())?),
+    })
+}
+
+/// Parses the arguments of `hsl()` / `hsla()`.
+fn parse_hsl(args: &Arguments) -> Result<Hsl, ColorParserError> {
+    let [hue, saturation, lightness] = args.channels.as_slice() else {
+        return Err(args.count_error(3, "3 channels"));
+    };
+
+    // The legacy syntax requires percentages for saturation and lightness
+    if args.legacy {
+        for token in [saturation, lightness] {
+            if !matches!(token.token, Token::Percentage(_)) {
+                return Err(token.error(&["a percentage"]));
+            }
+        }
+    }
+
+    Ok(Hsl {
+        hue: hue_component(hue)?.unwrap_or(0.0).rem_euclid(360.0),
+        saturation: number_component(saturation, 100.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0),
+        lightness: number_component(lightness, 100.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0),
+        alpha: alpha_component(args.alpha.as_ref())?,
+    })
+}
+
+/// Parses the arguments of `hwb()`, which only supports the modern syntax.
+fn parse_hwb(args: &Arguments) -> Result<Rgba, ColorParserError> {
+    let [hue, whiteness, blackness] = modern_channels(args)?;
+
+    let hue = hue_component(hue)?.unwrap_or(0.0);
+    // Whiteness and blackness are kept as percentages so that common inputs
+    // such as `30%` and `50%` add up exactly
+    let mut white = number_component(whiteness, 100.0)?
+        .unwrap_or(0.0)
+        .clamp(0.0, 100.0);
+    let mut black = number_component(blackness, 100.0)?
+        .unwrap_or(0.0)
+        .clamp(0.0, 100.0);
+
+    // Whiteness and blackness that add up to more than 100% produce a gray
+    if white + black >= 100.0 {
+        let sum = white + black;
+        white = white / sum * 100.0;
+        black = black / sum * 100.0;
+    }
+
+    let (r, g, b) = hsl_to_rgb(hue, 1.0, 0.5);
+    let scale = 100.0 - white - black;
+
+    Ok(Rgba {
+        red: unit_to_u8((r * scale + white) / 100.0),
+        green: unit_to_u8((g * scale + white) / 100.0),
+        blue: unit_to_u8((b * scale + white) / 100.0),
+        alpha: unit_to_u8(alpha_component(args.alpha.as_ref())?),
+    })
+}
+
+/// Parses the arguments of `lab()`, which only supports the modern syntax.
+///
+/// `100%` lightness is `100` and `100%` for `a`/`b` is `125`.
+fn parse_lab(args: &Arguments) -> Result<Lab, ColorParserError> {
+    let [lightness, a, b] = modern_channels(args)?;
+
+    Ok(Lab {
+        lightness: number_component(lightness, 100.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0),
+        a: number_component(a, 125.0)?.unwrap_or(0.0),
+        b: number_component(b, 125.0)?.unwrap_or(0.0),
+        alpha: alpha_component(args.alpha.as_ref())?,
+        white_point: WhitePoint::D50,
+    })
+}
+
+/// Parses the arguments of `lch()`, which only supports the modern syntax.
+///
+/// `100%` lightness is `100` and `100%` chroma is `150`.
+fn parse_lch(args: &Arguments) -> Result<Lch, ColorParserError> {
+    let [lightness, chroma, hue] = modern_channels(args)?;
+
+    Ok(Lch {
+        lightness: number_component(lightness, 100.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 100.0),
+        chroma: number_component(chroma, 150.0)?.unwrap_or(0.0).max(0.0),
+        hue: hue_component(hue)?.unwrap_or(0.0).rem_euclid(360.0),
+        alpha: alpha_component(args.alpha.as_ref())?,
+        white_point: WhitePoint::D50,
+    })
+}
+
+/// Parses the arguments of `oklab()`, which only supports the modern syntax.
+///
+/// `100%` lightness is `1` and `100%` for `a`/`b` is `0.4`.
+fn parse_oklab(args: &Arguments) -> Result<Oklab, ColorParserError> {
+    let [lightness, a, b] = modern_channels(args)?;
+
+    Ok(Oklab {
+        lightness: number_component(lightness, 1.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0),
+        a: number_component(a, 0.4)?.unwrap_or(0.0),
+        b: number_component(b, 0.4)?.unwrap_or(0.0),
+        alpha: alpha_component(args.alpha.as_ref())?,
+    })
+}
+
+/// Parses the arguments of `oklch()`, which only supports the modern syntax.
+///
+/// `100%` lightness is `1` and `100%` chroma is `0.4`.
+fn parse_oklch(args: &Arguments) -> Result<Oklch, ColorParserError> {
+    let [lightness, chroma, hue] = modern_channels(args)?;
+
+    Ok(Oklch {
+        lightness: number_component(lightness, 1.0)?
+            .unwrap_or(0.0)
+            .clamp(0.0, 1.0),
+        chroma: number_component(chroma, 0.4)?.unwrap_or(0.0).max(0.0),
+        hue: hue_component(hue)?.unwrap_or(0.0).rem_euclid(360.0),
+        alpha: alpha_component(args.alpha.as_ref())?,
+    })
+}
+
+/// Parses the arguments of `color()`: a color space identifier followed by
+/// three channels, where `100%` is `1`.
+fn parse_color_function(args: &Arguments) -> Result<Color, ColorParserError> {
+    if args.legacy {
+        return Err(args.legacy_error());
+    }
+    let Some((space, channels)) = args.channels.split_first() else {
+        return Err(args.count_error(4, "a color space and 3 channels"));
+    };
+    let Token::Ident(name) = &space.token else {
+        return Err(space.error(&["a color space"]));
+    };
+    if channels.len() != 3 {
+        return Err(args.count_error(4, "a color space and 3 channels"));
+    }
+
+    let mut values = [0.0; 3];
+    for (value, token) in values.iter_mut().zip(channels) {
+        *value = number_component(token, 1.0)?.unwrap_or(0.0);
+    }
+    let alpha = alpha_component(args.alpha.as_ref())?;
+
+    let color = match name.as_str() {
+        "srgb" => Color::Srgb(Srgb::from_channels(values, alpha)),
+        "srgb-linear" => Color::LinearRgb(LinearRgb::from_channels(values, alpha)),
+        "display-p3" => Color::DisplayP3(DisplayP3::from_channels(values, alpha)),
+        "rec2020" => Color::Rec2020(Rec2020::from_channels(values, alpha)),
+        "a98-rgb" => Color::AdobeRgb(AdobeRgb::from_channels(values, alpha)),
+        "prophoto-rgb" => Color::ProPhotoRgb(ProPhotoRgb::from_channels(values, alpha)),
+        "xyz" | "xyz-d65" => Color::Xyz(xyz(values, alpha)),
+        "xyz-d50" => Color::Xyz(xyz(values, alpha).adapt(
+            WhitePoint::D50,
+            WhitePoint::D65,
+            AdaptationMethod::Bradford,
+        )),
+        _ => {
+            return Err(
+                ColorParserError::at(ErrorKind::UnknownFunction, space.span.clone()).expecting(&[
+                    "srgb",
+                    "srgb-linear",
+                    "display-p3",
+                    "rec2020",
+                    "a98-rgb",
+                    "prophoto-rgb",
+                    "xyz-d50",
+                    "xyz-d65",
+                ]),
+            );
+        }
+    };
+
+    Ok(color)
+}
+
+/// Builds an `Xyz` color from `color()` channels.
+fn xyz([x, y, z]: [f64; 3], alpha: f64) -> Xyz {
+    Xyz { x, y, z, alpha }
+}
+
+/// Parses the arguments of `device-cmyk()`, which only supports the modern
+/// syntax: four channels where `100%` is `1`.
+fn parse_device_cmyk(args: &Arguments) -> Result<Cmyk, ColorParserError> {
+    if args.legacy {
+        return Err(args.legacy_error());
+    }
+    let [cyan, magenta, yellow, black] = args.channels.as_slice() else {
+        return Err(args.count_error(4, "4 channels"));
+    };
+
+    let mut values = [0.0; 4];
+    for (value, token) in values.iter_mut().zip([cyan, magenta, yellow, black]) {
+        *value = number_component(token, 1.0)?.unwrap_or(0.0).clamp(0.0, 1.0) * 100.0;
+    }
+
+    Ok(Cmyk {
+        cyan: values[0],
+        magenta: values[1],
+        yellow: values[2],
+        black: values[3],
+        alpha: alpha_component(args.alpha.as_ref())?,
+    })
+}
+
+/// Returns the three channels of a function that only supports the modern syntax.
+fn modern_channels(args: &Arguments) -> Result<&[Spanned; 3], ColorParserError> {
+    if args.legacy {
+        return Err(args.legacy_error());
+    }
+    args.channels
+        .as_slice()
+        .try_into()
+        .map_err(|_| args.count_error(3, "3 channels"))
+}
+
+/// Formats a number for CSS output with at most four decimal places.
+pub(crate) fn format_number(value: f64) -> String {
+    let rounded = (value * 10_000.0).round() / 10_000.0;
+    // Avoid printing `-0`
+    let rounded = if rounded == 0.0 { 0.0 } else { rounded };
+    format!("{rounded}")
+}
+
+/// Formats `c0 c1 c2[ / alpha]`, omitting the alpha when fully opaque.
+pub(crate) fn css_channels(channels: [f64; 3], alpha: f64) -> String {
+    let [c0, c1, c2] = channels.map(format_number);
+    if alpha < 1.0 {
+        format!("{c0} {c1} {c2} / {}", format_number(alpha))
+    } else {
+        format!("{c0} {c1} {c2}")
+    }
+}
+
+/// Formats `name(c0 c1 c2[ / alpha])`, omitting the alpha when fully opaque.
+pub(crate) fn css_function(name: &str, channels: [f64; 3], alpha: f64) -> String {
+    format!("{name}({})", css_channels(channels, alpha))
+}
@@ -0,0 +1,432 @@
+//! Color vision deficiency (CVD) simulation and daltonization.
+//!
+//! Simulations work on linear-light sRGB with precomputed matrices:
+//! - [`CvdMethod::Brettel`]: Brettel, Viénot and Mollon (1997), projecting onto
+//!   two half-planes; the most accurate for full dichromacy, including tritanopia
+//! - [`CvdMethod::Vienot`]: Viénot, Brettel and Mollon (1999), a single-plane
+//!   simplification that is only reliable for protanopia and deuteranopia
+//! - [`CvdMethod::Machado`]: Machado, Oliveira and Fernandes (2009), which models
+//!   anomalous trichromacy with a matrix per severity step
+//!
+//! Brettel and Viénot model dichromacy; lower severities blend linearly between
+//! the original and the dichromat simulation. The Brettel and Viénot matrices
+//! are the ones published with DaltonLens.
+
+use crate::matrix::{Matrix3, multiply};
+use crate::{ColorSpace, LinearRgb, Rgba};
+
+/// A type of color vision deficiency, named after the affected cone.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Deficiency {
+    /// Missing or anomalous long-wavelength (red) cones
+    Protan,
+    /// Missing or anomalous medium-wavelength (green) cones
+    Deutan,
+    /// Missing or anomalous short-wavelength (blue) cones
+    Tritan,
+}
+
+/// The model used to simulate a color vision deficiency.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CvdMethod {
+    /// Brettel et al. (1997) dichromacy simulation
+    Brettel,
+    /// Viénot et al. (1999) dichromacy simulation
+    Vienot,
+    /// Machado et al. (2009) severity-based simulation
+    #[default]
+    Machado,
+}
+
+/// Brettel simulation of one deficiency: a matrix for each side of the
+/// separation plane, and the plane's normal.
+struct Brettel {
+    /// Matrix for colors on the positive side of the plane
+    positive: Matrix3,
+    /// Matrix for colors on the negative side of the plane
+    negative: Matrix3,
+    /// Normal of the separation plane
+    normal: [f64; 3],
+}
+
+/// Brettel protanopia simulation.
+const BRETTEL_PROTAN: Brettel = Brettel {
+    positive: [
+        [0.14510, 1.20165, -0.34675],
+        [0.10447, 0.85316, 0.04237],
+        [0.00429, -0.00603, 1.00174],
+    ],
+    negative: [
+        [0.14115, 1.16782, -0.30897],
+        [0.10495, 0.85730, 0.03776],
+        [0.00431, -0.00586, 1.00155],
+    ],
+    normal: [0.00048, 0.00416, -0.00464],
+};
+
+/// Brettel deuteranopia simulation.
+const BRETTEL_DEUTAN: Brettel = Brettel {
+    positive: [
+        [0.36198, 0.86755, -0.22953],
+        [0.26099, 0.64512, 0.09389],
+        [-0.01975, 0.02686, 0.99289],
+    ],
+    negative: [
+        [0.37009, 0.88540, -0.25549],
+        [0.25767, 0.63782, 0.10451],
+        [-0.01950, 0.02741, 0.99209],
+    ],
+    normal: [-0.00293, -0.00645, 0.00938],
+};
+
+/// Brettel tritanopia simulation.
+const BRETTEL_TRITAN: Brettel = Brettel {
+    positive: [
+        [1.01354, 0.14268, -0.15622],
+        [-0.01181, 0.87561, 0.13619],
+        [0.07707, 0.81208, 0.11085],
+    ],
+    negative: [
+        [0.93337, 0.19999, -0.13336],
+        [0.05809, 0.82565, 0.11626],
+        [-0.37923, 1.13825, 0.24098],
+    ],
+    normal: [0.03960, -0.02831, -0.01129],
+};
+
+/// Viénot protanopia simulation.
+const VIENOT_PROTAN: Matrix3 = [
+    [0.11238, 0.88762, 0.0],
+    [0.11238, 0.88762, 0.0],
+    [0.00401, -0.00401, 1.0],
+];
+
+/// Viénot deuteranopia simulation.
+const VIENOT_DEUTAN: Matrix3 = [
+    [0.29275, 0.70725, 0.0],
+    [0.29275, 0.70725, 0.0],
+    [-0.02234, 0.02234, 1.0],
+];
+
+/// Viénot tritanopia simulation (inaccurate, see [`CvdMethod::Vienot`]).
+const VIENOT_TRITAN: Matrix3 = [
+    [1.0, 0.14461, -0.14461],
+    [0.0, 0.85924, 0.14076],
+    [0.0, 0.85924, 0.14076],
+];
+
+/// Machado protanomaly matrices for severities 0.1 to 1.0.
+const MACHADO_PROTAN: [Matrix3; 10] = [
+    [
+        [0.856167, 0.182038, -0.038205],
+        [0.029342, 0.955115, 0.015544],
+        [-0.002880, -0.001563, 1.004443],
+    ],
+    [
+        [0.734766, 0.334872, -0.069637],
+        [0.051840, 0.919198, 0.028963],
+        [-0.004928, -0.004209, 1.009137],
+    ],
+    [
+        [0.630323, 0.465641, -0.095964],
+        [0.069181, 0.890046, 0.040773],
+        [-0.006308, -0.007724, 1.014032],
+    ],
+    [
+        [0.539009, 0.579343, -0.118352],
+        [0.082546, 0.866121, 0.051332],
+        [-0.007136, -0.011959, 1.019095],
+    ],
+    [
+        [0.458064, 0.679578, -0.137642],
+        [0.092785, 0.846313, 0.060902],
+        [-0.007494, -0.016807, 1.024301],
+    ],
+    [
+        [0.385450, 0.769005, -0.154455],
+        [0.100526, 0.829802, 0.069673],
+        [-0.007442, -0.022190, 1.029632],
+    ],
+    [
+        [0.319627, 0.849633, -0.169261],
+        [0.106241, 0.815969, 0.077790],
+        [-0.007025, -0.028051, 1.035076],
+    ],
+    [
+        [0.259411, 0.923008, -0.182420],
+        [0.110296, 0.804340, 0.085364],
+        [-0.006276, -0.034346, 1.040622],
+    ],
+    [
+        [0.203876, 0.990338, -0.194214],
+        [0.112975, 0.794542, 0.092483],
+        [-0.005222, -0.041043, 1.046265],
+    ],
+    [
+        [0.152286, 1.052583, -0.204868],
+        [0.114503, 0.786281, 0.099216],
+        [-0.003882, -0.048116, 1.051998],
+    ],
+];
+
+/// Machado deuteranomaly matrices for severities 0.1 to 1.0.
+const MACHADO_DEUTAN: [Matrix3; 10] = [
+    [
+        [0.866435, 0.177704, -0.044139],
+        [0.049567, 0.939063, 0.011370],
+        [-0.003453, 0.007233, 0.996220],
+    ],
+    [
+        [0.760729, 0.319078, -0.079807],
+        [0.090568, 0.889315, 0.020117],
+        [-0.006027, 0.013325, 0.992702],
+    ],
+    [
+        [0.675425, 0.433850, -0.109275],
+        [0.125303, 0.847755, 0.026942],
+        [-0.007950, 0.018572, 0.989378],
+    ],
+    [
+        [0.605511, 0.528560, -0.134071],
+        [0.155318, 0.812366, 0.032316],
+        [-0.009376, 0.023176, 0.986200],
+    ],
+    [
+        [0.547494, 0.607765, -0.155259],
+        [0.181692, 0.781742, 0.036566],
+        [-0.010410, 0.027275, 0.983136],
+    ],
+    [
+        [0.498864, 0.674741, -0.173604],
+        [0.205199, 0.754872, 0.039929],
+        [-0.011131, 0.030969, 0.980162],
+    ],
+    [
+        [0.457771, 0.731899, -0.189670],
+        [0.226409, 0.731012, 0.042579],
+        [-0.011595, 0.034333, 0.977261],
+    ],
+    [
+        [0.422823, 0.781057, -0.203881],
+        [0.245752, 0.709602, 0.044646],
+        [-0.011843, 0.037423, 0.974421],
+    ],
+    [
+        [0.392952, 0.823610, -0.216562],
+        [0.263559, 0.690210, 0.046232],
+        [-0.011910, 0.040281, 0.971630],
+    ],
+    [
+        [0.367322, 0.860646, -0.227968],
+        [0.280085, 0.672501, 0.047413],
+        [-0.011820, 0.042940, 0.968881],
+    ],
+];
+
+/// Machado tritanomaly matrices for severities 0.1 to 1.0.
+const MACHADO_TRITAN: [Matrix3; 10] = [
+    [
+        [0.926670, 0.092514, -0.019184],
+        [0.021191, 0.964503, 0.014306],
+        [0.008437, 0.054813, 0.936750],
+    ],
+    [
+        [0.895720, 0.133330, -0.029050],
+        [0.029997, 0.945400, 0.024603],
+        [0.013027, 0.104707, 0.882266],
+    ],
+    [
+        [0.905871, 0.127791, -0.033662],
+        [0.026856, 0.941251, 0.031893],
+        [0.013410, 0.148296, 0.838294],
+    ],
+    [
+        [0.948035, 0.089490, -0.037526],
+        [0.014364, 0.946792, 0.038844],
+        [0.010853, 0.193991, 0.795156],
+    ],
+    [
+        [1.017277, 0.027029, -0.044306],
+        [-0.006113, 0.958479, 0.047634],
+        [0.006379, 0.248708, 0.744913],
+    ],
+    [
+        [1.104996, -0.046633, -0.058363],
+        [-0.032137, 0.971635, 0.060503],
+        [0.001336, 0.317922, 0.680742],
+    ],
+    [
+        [1.193214, -0.109812, -0.083402],
+        [-0.058496, 0.979410, 0.079086],
+        [-0.002346, 0.403492, 0.598854],
+    ],
+    [
+        [1.257728, -0.139648, -0.118081],
+        [-0.078003, 0.975409, 0.102594],
+        [-0.003316, 0.501214, 0.502102],
+    ],
+    [
+        [1.278864, -0.125333, -0.153531],
+        [-0.084748, 0.957674, 0.127074],
+        [-0.000989, 0.601151, 0.399838],
+    ],
+    [
+        [1.255528, -0.076749, -0.178779],
+        [-0.078411, 0.930809, 0.147602],
+        [0.004733, 0.691367, 0.303900],
+    ],
+];
+
+/// Identity matrix, the Machado simulation at severity 0.
+const IDENTITY: Matrix3 = [[1.0, 0.0, 0.0], [0.0, 1.0, 0.0], [0.0, 0.0, 1.0]];
+
+/// Fidaner, Lin and Ozguven (2005) error-modification matrix, which moves the
+/// red-green error onto green and blue.
+const FIDANER_ERROR_SHIFT: Matrix3 = [[0.0, 0.0, 0.0], [0.7, 1.0, 0.0], [0.7, 0.0, 1.0]];
+
+impl Rgba {
+    /// Simulates how this color appears with a color vision deficiency.
+    ///
+    /// `severity` ranges from 0 (normal vision) to 1 (dichromacy: protanopia,
+    /// deuteranopia or tritanopia) and is clamped to that range. Values in
+    /// between model anomalous trichromacy. Alpha is preserved.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::cvd::{CvdMethod, Deficiency};
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// let seen = red.simulate_cvd(Deficiency::Protan, 1.0, CvdMethod::Brettel);
+    /// assert!(seen.red < 128 && seen.green < 128);
+    /// assert_eq!(red.simulate_cvd(Deficiency::Protan, 0.0, CvdMethod::Brettel), red);
+    /// ```
+    pub fn simulate_cvd(&self, deficiency: Deficiency, severity: f64, method: CvdMethod) -> Rgba {
+        let linear = LinearRgb::from(*self);
+        let simulated = simulate(
+            [linear.red, linear.green, linear.blue],
+            deficiency,
+            severity.clamp(0.0, 1.0),
+            method,
+        );
+        to_rgba(simulated, linear.alpha)
+    }
+
+    /// Adjusts this color so that it stays distinguishable with a color vision
+    /// deficiency.
+    ///
+    /// Follows Fidaner, Lin and Ozguven (2005): the information lost by the
+    /// Machado simulation is shifted onto the channels the viewer can still
+    /// tell apart. Alpha is preserved.
+    ///
+    /// The method only defines a correction for protan and deutan
+    /// deficiencies, and there is no published tritan equivalent, so
+    /// [`Deficiency::Tritan`] returns the color unchanged.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::cvd::Deficiency;
+    ///
+    /// let gray = Rgba { red: 128, green: 128, blue: 128, alpha: 255 };
+    /// assert_eq!(gray.daltonize(Deficiency::Deutan, 1.0), gray);
+    /// assert_eq!(gray.daltonize(Deficiency::Tritan, 1.0), gray);
+    /// ```
+    pub fn daltonize(&self, deficiency: Deficiency, severity: f64) -> Rgba {
+        if deficiency == Deficiency::Tritan {
+            return *self;
+        }
+
+        let linear = LinearRgb::from(*self);
+        let original = [linear.red, linear.green, linear.blue];
+        let simulated = simulate(
+            original,
+            deficiency,
+            severity.clamp(0.0, 1.0),
+            CvdMethod::Machado,
+        );
+        let error = [
+            original[0] - simulated[0],
+            original[1] - simulated[1],
+            original[2] - simulated[2],
+        ];
+
+        // Redistribute the lost contrast onto the visible channels
+        let [dr, dg, db] = multiply(&FIDANER_ERROR_SHIFT, error);
+
+        to_rgba(
+            [original[0] + dr, original[1] + dg, original[2] + db],
+            linear.alpha,
+        )
+    }
+}
+
+/// Simulates a deficiency on linear sRGB channels.
+fn simulate(rgb: [f64; 3], deficiency: Deficiency, severity: f64, method: CvdMethod) -> [f64; 3] {
+    let dichromat = match method {
+        CvdMethod::Machado => return multiply(&machado_matrix(deficiency, severity), rgb),
+        CvdMethod::Vienot => {
+            let matrix = match deficiency {
+                Deficiency::Protan => &VIENOT_PROTAN,
+                Deficiency::Deutan => &VIENOT_DEUTAN,
+                Deficiency::Tritan => &VIENOT_TRITAN,
+            };
+            multiply(matrix, rgb)
+        }
+        CvdMethod::Brettel => {
+            let brettel = match deficiency {
+                Deficiency::Protan => &BRETTEL_PROTAN,
+                Deficiency::Deutan => &BRETTEL_DEUTAN,
+                Deficiency::Tritan => &BRETTEL_TRITAN,
+            };
+            let side: f64 = (0..3).map(|i| rgb[i] * brettel.normal[i]).sum();
+            let matrix = if side >= 0.0 {
+                &brettel.positive
+            } else {
+                &brettel.negative
+            };
+            multiply(matrix, rgb)
+        }
+    };
+
+    // Anomalous trichromacy as a blend toward the dichromat
+    [0, 1, 2].map(|i| rgb[i] + (dichromat[i] - rgb[i]) * severity)
+}
+
+/// Returns the Machado matrix for a severity, interpolating between the
+/// tabulated 0.1 steps.
+fn machado_matrix(deficiency: Deficiency, severity: f64) -> Matrix3 {
+    let table = match deficiency {
+        Deficiency::Protan => &MACHADO_PROTAN,
+        Deficiency::Deutan => &MACHADO_DEUTAN,
+        Deficiency::Tritan => &MACHADO_TRITAN,
+    };
+
+    // Index 0 is severity 0 (identity), index n is table[n - 1]
+    let step = |index: usize| {
+        if index == 0 {
+            &IDENTITY
+        } else {
+            &table[index - 1]
+        }
+    };
+    let position = severity * 10.0;
+    let lower = (position.floor() as usize).min(9);
+    let t = position - lower as f64;
+    let (a, b) = (step(lower), step(lower + 1));
+
+    [0, 1, 2].map(|row| [0, 1, 2].map(|col| a[row][col] + (b[row][col] - a[row][col]) * t))
+}
+
+/// Converts linear sRGB channels back to `Rgba`, clamping to the gamut.
+fn to_rgba([red, green, blue]: [f64; 3], alpha: f64) -> Rgba {
+    Rgba::from_srgb(
+        LinearRgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+        .to_srgb(),
+    )
+}
@@ -0,0 +1,258 @@
+//! Perceptual color difference metrics.
+//!
+//! Comparing `Rgba` channels says little about how different two colors look.
+//! These formulas measure the difference in perceptual spaces instead, where a
+//! ΔE around 1 is the smallest difference most observers notice:
+//! - [`delta_e76`]: Euclidean distance in CIELAB (CIE 1976)
+//! - [`delta_e94`]: CIE 1994, weighting chroma and hue by chroma
+//! - [`delta_e2000`]: CIEDE2000, the current CIE recommendation
+//! - [`delta_e_cmc`]: CMC l:c, common in the textile industry
+//! - [`delta_e_ok`]: Euclidean distance in Oklab
+//!
+//! All CIELAB formulas except ΔE76 are asymmetric in principle and treat the
+//! first color as the reference. If the two colors use different white points,
+//! the sample is re-expressed relative to the reference's white point first.
+
+use crate::{ColorSpace, Lab, Oklab};
+
+/// Weighting factors for the CIE 1994 formula.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Cie94Application {
+    /// kL = 1, K1 = 0.045, K2 = 0.015
+    #[default]
+    GraphicArts,
+    /// kL = 2, K1 = 0.048, K2 = 0.014
+    Textiles,
+}
+
+/// A color difference formula, for comparing colors in any color space.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::Rgba;
+/// use color_parser::delta_e::DeltaE;
+///
+/// let brand = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+/// let swatch = Rgba { red: 255, green: 137, blue: 0, alpha: 255 };
+/// assert!(DeltaE::Cie2000.difference(&brand, &swatch) < 1.0);
+/// ```
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum DeltaE {
+    /// CIE 1976, see [`delta_e76`]
+    Cie76,
+    /// CIE 1994, see [`delta_e94`]
+    Cie94(Cie94Application),
+    /// CIEDE2000, see [`delta_e2000`]
+    Cie2000,
+    /// CMC l:c, see [`delta_e_cmc`]
+    Cmc {
+        /// Lightness weight `l`, usually 2 for acceptability or 1 for perceptibility
+        lightness: f64,
+        /// Chroma weight `c`, usually 1
+        chroma: f64,
+    },
+    /// Euclidean distance in Oklab, see [`delta_e_ok`]
+    Ok,
+}
+
+impl DeltaE {
+    /// Computes the difference between two colors, converting them to CIELAB
+    /// (D50) or Oklab as the formula requires.
+    pub fn difference(&self, reference: &impl ColorSpace, sample: &impl ColorSpace) -> f64 {
+        let lab: (Lab, Lab) = (reference.convert(), sample.convert());
+        match *self {
+            DeltaE::Cie76 => delta_e76(&lab.0, &lab.1),
+            DeltaE::Cie94(application) => delta_e94(&lab.0, &lab.1, application),
+            DeltaE::Cie2000 => delta_e2000(&lab.0, &lab.1),
+            DeltaE::Cmc { lightness, chroma } => delta_e_cmc(&lab.0, &lab.1, lightness, chroma),
+            DeltaE::Ok => delta_e_ok(&reference.convert(), &sample.convert()),
+        }
+    }
+}
+
+/// Computes the CIE 1976 color difference: the Euclidean distance in CIELAB.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Lab, WhitePoint};
+/// use color_parser::delta_e::delta_e76;
+///
+/// let lab = |lightness, a, b| Lab { lightness, a, b, alpha: 1.0, white_point: WhitePoint::D50 };
+/// assert_eq!(delta_e76(&lab(50.0, 0.0, 0.0), &lab(53.0, 4.0, 0.0)), 5.0);
+/// ```
+pub fn delta_e76(reference: &Lab, sample: &Lab) -> f64 {
+    let sample = aligned(reference, sample);
+    let dl = reference.lightness - sample.lightness;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Computes the CIE 1994 color difference.
+pub fn delta_e94(reference: &Lab, sample: &Lab, application: Cie94Application) -> f64 {
+    let sample = aligned(reference, sample);
+    let (kl, k1, k2) = match application {
+        Cie94Application::GraphicArts => (1.0, 0.045, 0.015),
+        Cie94Application::Textiles => (2.0, 0.048, 0.014),
+    };
+
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let dl = reference.lightness - sample.lightness;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+
+    // ΔH is whatever remains of Δa and Δb after removing the chroma difference
+    let dh_squared = (da * da + db * db - dc * dc).max(0.0);
+
+    let sc = 1.0 + k1 * c1;
+    let sh = 1.0 + k2 * c1;
+
+    ((dl / kl).powi(2) + (dc / sc).powi(2) + dh_squared / (sh * sh)).sqrt()
+}
+
+/// Computes the CIEDE2000 color difference with unit weighting factors.
+///
+/// Follows Sharma, Wu and Dalal, "The CIEDE2000 Color-Difference Formula:
+/// Implementation Notes, Supplementary Test Data, and Mathematical Observations"
+/// (2005).
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Lab, WhitePoint};
+/// use color_parser::delta_e::delta_e2000;
+///
+/// let lab = |lightness, a, b| Lab { lightness, a, b, alpha: 1.0, white_point: WhitePoint::D50 };
+/// let difference = delta_e2000(&lab(50.0, 2.6772, -79.7751), &lab(50.0, 0.0, -82.7485));
+/// assert!((difference - 2.0425).abs() < 1e-4);
+/// ```
+pub fn delta_e2000(reference: &Lab, sample: &Lab) -> f64 {
+    let sample = aligned(reference, sample);
+    let (l1, a1, b1) = (reference.lightness, reference.a, reference.b);
+    let (l2, a2, b2) = (sample.lightness, sample.a, sample.b);
+
+    // Stretch the a axis for low-chroma colors
+    let c_mean = (a1.hypot(b1) + a2.hypot(b2)) / 2.0;
+    let g = 0.5 * (1.0 - chroma_weight(c_mean));
+    let a1 = (1.0 + g) * a1;
+    let a2 = (1.0 + g) * a2;
+
+    let c1 = a1.hypot(b1);
+    let c2 = a2.hypot(b2);
+    let h1 = hue_angle(a1, b1);
+    let h2 = hue_angle(a2, b2);
+
+    // Differences in lightness, chroma and hue
+    let dl = l2 - l1;
+    let dc = c2 - c1;
+    let dh = if c1 * c2 == 0.0 {
+        0.0
+    } else if (h2 - h1).abs() <= 180.0 {
+        h2 - h1
+    } else if h2 - h1 > 180.0 {
+        h2 - h1 - 360.0
+    } else {
+        h2 - h1 + 360.0
+    };
+    let dh = 2.0 * (c1 * c2).sqrt() * (dh / 2.0).to_radians().sin();
+
+    // Means of lightness, chroma and hue
+    let l_mean = (l1 + l2) / 2.0;
+    let c_mean = (c1 + c2) / 2.0;
+    let h_mean = if c1 * c2 == 0.0 {
+        h1 + h2
+    } else if (h1 - h2).abs() <= 180.0 {
+        (h1 + h2) / 2.0
+    } else if h1 + h2 < 360.0 {
+        (h1 + h2 + 360.0) / 2.0
+    } else {
+        (h1 + h2 - 360.0) / 2.0
+    };
+
+    let cos = |degrees: f64| degrees.to_radians().cos();
+    let t =
+        1.0 - 0.17 * cos(h_mean - 30.0) + 0.24 * cos(2.0 * h_mean) + 0.32 * cos(3.0 * h_mean + 6.0)
+            - 0.20 * cos(4.0 * h_mean - 63.0);
+
+    // Weighting functions and the rotation term for blues
+    let sl = 1.0 + 0.015 * (l_mean - 50.0).powi(2) / (20.0 + (l_mean - 50.0).powi(2)).sqrt();
+    let sc = 1.0 + 0.045 * c_mean;
+    let sh = 1.0 + 0.015 * c_mean * t;
+    let rotation = 30.0 * (-((h_mean - 275.0) / 25.0).powi(2)).exp();
+    let rt = -(2.0 * rotation).to_radians().sin() * 2.0 * chroma_weight(c_mean);
+
+    let (l, c, h) = (dl / sl, dc / sc, dh / sh);
+    (l * l + c * c + h * h + rt * c * h).sqrt()
+}
+
+/// Computes the CMC l:c color difference.
+///
+/// Use `l = 2, c = 1` for acceptability and `l = 1, c = 1` for perceptibility.
+pub fn delta_e_cmc(reference: &Lab, sample: &Lab, lightness: f64, chroma: f64) -> f64 {
+    let sample = aligned(reference, sample);
+    let l1 = reference.lightness;
+    let c1 = reference.a.hypot(reference.b);
+    let c2 = sample.a.hypot(sample.b);
+    let h1 = hue_angle(reference.a, reference.b);
+
+    let dl = l1 - sample.lightness;
+    let dc = c1 - c2;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+    let dh_squared = (da * da + db * db - dc * dc).max(0.0);
+
+    // Weighting functions, all based on the reference color
+    let sl = if l1 < 16.0 {
+        0.511
+    } else {
+        0.040975 * l1 / (1.0 + 0.01765 * l1)
+    };
+    let sc = 0.0638 * c1 / (1.0 + 0.0131 * c1) + 0.638;
+    let f = (c1.powi(4) / (c1.powi(4) + 1900.0)).sqrt();
+    let t = if (164.0..=345.0).contains(&h1) {
+        0.56 + (0.2 * (h1 + 168.0).to_radians().cos()).abs()
+    } else {
+        0.36 + (0.4 * (h1 + 35.0).to_radians().cos()).abs()
+    };
+    let sh = sc * (f * t + 1.0 - f);
+
+    ((dl / (lightness * sl)).powi(2) + (dc / (chroma * sc)).powi(2) + dh_squared / (sh * sh)).sqrt()
+}
+
+/// Computes the Euclidean distance in Oklab.
+///
+/// Oklab lightness spans [0, 1], so a just noticeable difference is roughly
+/// 0.02 rather than 1.
+pub fn delta_e_ok(reference: &Oklab, sample: &Oklab) -> f64 {
+    let dl = reference.lightness - sample.lightness;
+    let da = reference.a - sample.a;
+    let db = reference.b - sample.b;
+
+    (dl * dl + da * da + db * db).sqrt()
+}
+
+/// Re-expresses `sample` relative to the white point of `reference`.
+fn aligned(reference: &Lab, sample: &Lab) -> Lab {
+    if sample.white_point == reference.white_point {
+        *sample
+    } else {
+        sample.with_white_point(reference.white_point)
+    }
+}
+
+/// Returns the hue angle in degrees [0, 360), or 0 for a neutral color.
+fn hue_angle(a: f64, b: f64) -> f64 {
+    if a == 0.0 && b == 0.0 {
+        0.0
+    } else {
+        b.atan2(a).to_degrees().rem_euclid(360.0)
+    }
+}
+
+/// Computes `sqrt(C^7 / (C^7 + 25^7))`, used by CIEDE2000.
+fn chroma_weight(chroma: f64) -> f64 {
+    let c7 = chroma.powi(7);
+    (c7 / (c7 + 25f64.powi(7))).sqrt()
+}
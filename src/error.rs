@@ -0,0 +1,199 @@
+//! Errors returned when parsing or converting colors.
+//!
+//! A [`ColorParserError`] records what went wrong as an [`ErrorKind`], the
+//! input that failed, the byte span of the offending part and the forms that
+//! would have been accepted there. [`ColorParserError::render`] formats all of
+//! this with the bad region underlined, for command-line output:
+//!
+//! ```text
+//! error: Invalid character in hex color
+//!   |
+//!   | #ff8g00
+//!   |     ^ expected a hex digit (0-9, a-f)
+//! ```
+
+use std::fmt;
+use std::ops::Range;
+
+/// The category of a [`ColorParserError`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ErrorKind {
+    /// Invalid hex string length (3, 4, 6, or 8 digits, depending on the options)
+    InvalidLength,
+    /// Invalid character in hex string
+    InvalidCharacter,
+    /// RGB values must be in the 0–255 range
+    InvalidRgbValue,
+    /// Malformed CSS color syntax (bad arguments, separators or units)
+    InvalidSyntax,
+    /// Unsupported or unknown CSS color function
+    UnknownFunction,
+    /// Unknown named color keyword
+    UnknownColorName,
+    /// HSL, HSV or CMYK component outside its valid range
+    InvalidComponentValue,
+}
+
+impl ErrorKind {
+    /// Returns a stable, machine-readable identifier such as `invalid-length`.
+    pub fn code(&self) -> &'static str {
+        match self {
+            ErrorKind::InvalidLength => "invalid-length",
+            ErrorKind::InvalidCharacter => "invalid-character",
+            ErrorKind::InvalidRgbValue => "invalid-rgb-value",
+            ErrorKind::InvalidSyntax => "invalid-syntax",
+            ErrorKind::UnknownFunction => "unknown-function",
+            ErrorKind::UnknownColorName => "unknown-color-name",
+            ErrorKind::InvalidComponentValue => "invalid-component-value",
+        }
+    }
+}
+
+impl fmt::Display for ErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            ErrorKind::InvalidLength => write!(f, "Hex color has the wrong number of digits"),
+            ErrorKind::InvalidCharacter => write!(f, "Invalid character in hex color"),
+            ErrorKind::InvalidRgbValue => write!(f, "RGB value must be between 0 and 255"),
+            ErrorKind::InvalidSyntax => write!(f, "Invalid CSS color syntax"),
+            ErrorKind::UnknownFunction => write!(f, "Unknown CSS color function"),
+            ErrorKind::UnknownColorName => write!(f, "Unknown color name"),
+            ErrorKind::InvalidComponentValue => {
+                write!(f, "Color component is outside its valid range")
+            }
+        }
+    }
+}
+
+/// An error that occurred during color parsing or conversion.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{ErrorKind, parse_css_color};
+///
+/// let error = parse_css_color("rgb(255 0 0 / 50% 1)").unwrap_err();
+/// assert_eq!(error.kind, ErrorKind::InvalidSyntax);
+/// assert_eq!(&error.input[error.span.clone()], "1");
+/// assert_eq!(error.expected, ["a single alpha value"]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColorParserError {
+    /// What went wrong
+    pub kind: ErrorKind,
+    /// The input that failed, or an empty string for conversion errors
+    pub input: String,
+    /// Byte range of the offending part of `input`; empty when something is missing
+    pub span: Range<usize>,
+    /// Forms that would have been accepted at `span`, e.g. `a number or percentage`
+    pub expected: Vec<String>,
+}
+
+impl ColorParserError {
+    /// Creates an error of `kind` pointing at `span` in `input`.
+    pub fn new(kind: ErrorKind, input: &str, span: Range<usize>) -> Self {
+        Self {
+            kind,
+            input: input.to_string(),
+            span,
+            expected: Vec::new(),
+        }
+    }
+
+    /// Sets the forms that would have been accepted at the error span.
+    pub fn expecting(mut self, forms: &[&str]) -> Self {
+        self.expected = forms.iter().map(|form| form.to_string()).collect();
+        self
+    }
+
+    /// Formats the error with the offending part of the input underlined.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::parse_hex_to_rgba;
+    ///
+    /// let error = parse_hex_to_rgba("#ff8g00").unwrap_err();
+    /// assert_eq!(
+    ///     error.render(),
+    ///     "error: Invalid character in hex color\n  |\n  | #ff8g00\n  |     ^ expected a hex digit (0-9, a-f)\n"
+    /// );
+    /// ```
+    pub fn render(&self) -> String {
+        let mut output = format!("error: {}\n", self.kind);
+        if self.input.is_empty() {
+            // Nothing to underline, so the expected forms go in a note
+            if let Some(expected) = self.expected_list() {
+                output.push_str(&format!("  = expected {expected}\n"));
+            }
+            return output;
+        }
+
+        // Columns are counted in characters so multi-byte input lines up
+        let before = self.input.get(..self.span.start).unwrap_or_default();
+        let region = self.input.get(self.span.clone()).unwrap_or_default();
+        let column = before.chars().count();
+        let width = region.chars().count().max(1);
+        let mut marker = format!("{}{}", " ".repeat(column), "^".repeat(width));
+        if let Some(expected) = self.expected_list() {
+            marker.push_str(&format!(" expected {expected}"));
+        }
+
+        output.push_str("  |\n");
+        output.push_str(&format!("  | {}\n", self.input));
+        output.push_str(&format!("  | {marker}\n"));
+        output
+    }
+
+    /// Creates an error at `span` whose input is filled in by the caller.
+    pub(crate) fn at(kind: ErrorKind, span: Range<usize>) -> Self {
+        Self::new(kind, "", span)
+    }
+
+    /// Moves the span `offset` bytes to the right, for errors found in a
+    /// slice of a larger input.
+    pub(crate) fn shifted(mut self, offset: usize) -> Self {
+        self.span = self.span.start + offset..self.span.end + offset;
+        self
+    }
+
+    /// Sets the input the span refers to.
+    pub(crate) fn within(mut self, input: &str) -> Self {
+        self.input = input.to_string();
+        self
+    }
+
+    /// Joins the expected forms as `a`, `a or b` or `one of a, b or c`.
+    fn expected_list(&self) -> Option<String> {
+        match self.expected.as_slice() {
+            [] => None,
+            [form] => Some(form.clone()),
+            [first, second] => Some(format!("{first} or {second}")),
+            [rest @ .., last] => Some(format!("one of {} or {last}", rest.join(", "))),
+        }
+    }
+}
+
+impl From<ErrorKind> for ColorParserError {
+    fn from(kind: ErrorKind) -> Self {
+        Self::new(kind, "", 0..0)
+    }
+}
+
+impl fmt::Display for ColorParserError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.kind)?;
+        if !self.input.is_empty() {
+            write!(f, " at byte {} of `{}`", self.span.start, self.input)?;
+        }
+        if let Some(expected) = self.expected_list() {
+            write!(f, "; expected {expected}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for ColorParserError {}
+
+/// Returns the byte offset of `inner` within `outer`, which it must be a slice of.
+pub(crate) fn offset_of(outer: &str, inner: &str) -> usize {
+    inner.as_ptr() as usize - outer.as_ptr() as usize
+}
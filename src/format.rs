@@ -0,0 +1,726 @@
+//! `Display`, `FromStr` and hex formatting for every color type.
+//!
+//! `{}` prints a color in its own notation with comma-separated components,
+//! such as `hsl(30, 100%, 50%)`, `cmyk(0%, 47%, 100%, 0%)` or
+//! `oklch(0.628, 0.2577, 29.23)`; `Rgba` prints as lowercase hex. `{:#}` prints
+//! the CSS Color 4 function instead, such as `rgb(255 136 0 / 0.5)`,
+//! `hsl(30 100% 50%)` or `color(display-p3 1 0 0)`. CSS has no HSV or CMYK
+//! function, so `Hsv` prints as the equivalent `hwb()` and `Cmyk` as
+//! `device-cmyk()`. `Lab` and `Lch` are written relative to D50 in both
+//! notations, whatever their white point, and parse back as D50.
+//!
+//! A precision sets a fixed number of decimals for the components (`{:.2}`,
+//! `{:#.2}`); without one, up to four decimals are printed with trailing zeros
+//! trimmed. Alpha always uses up to four decimals and is omitted when the
+//! color is fully opaque.
+//!
+//! `{:x}` and `{:X}` print any color as lowercase or uppercase hex after
+//! converting it to [`Rgba`]. The alpha digits are omitted when the color is
+//! fully opaque, unless the alternate flag is set (`{:#x}`).
+//!
+//! `Rgba16` prints as 16-bit hex (`#rrrrggggbbbb`), and `RgbaF32` and
+//! `RgbaF64` in the notation of `Srgb`; with the alternate flag all three
+//! print as `color(srgb r g b)`.
+//!
+//! `FromStr` accepts the `{}` notation of the type as well as anything
+//! [`parse_css_color`] accepts, converting to the target type. Components of
+//! the plain notation must be finite, with percentages in [0, 100] and alpha in
+//! [0, 1]; anything else is an `InvalidComponentValue` error.
+//!
+//! # Examples
+//! ```rust
+//! use color_parser::{Hsl, Rgba};
+//!
+//! let orange: Rgba = "#ff8800".parse().unwrap();
+//! assert_eq!(format!("{orange}"), "#ff8800");
+//! assert_eq!(format!("{orange:#}"), "rgb(255 136 0)");
+//! assert_eq!(format!("{orange:X}"), "#FF8800");
+//! assert_eq!(format!("{orange:#x}"), "#ff8800ff");
+//!
+//! let hsl = Hsl::from(orange);
+//! assert_eq!(format!("{hsl:.1}"), "hsl(32.0, 100.0%, 50.0%)");
+//! assert_eq!(format!("{hsl:#.0}"), "hsl(32 100% 50%)");
+//! assert_eq!("hsl(32, 100%, 50%)".parse::<Hsl>().unwrap().hue, 32.0);
+//! ```
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::css::{css_function, format_number};
+use crate::error::offset_of;
+use crate::{
+    AdobeRgb, Cmyk, Color, ColorParserError, ColorSpace, DisplayP3, ErrorKind, Hsl, Hsv, Lab, Lch,
+    LinearRgb, Oklab, Oklch, ParseOptions, ProPhotoRgb, Rec2020, RgbSpace, Rgba, Rgba16, RgbaF32,
+    RgbaF64, Srgb, WhitePoint, Xyz, component_expectation, parse_css_color,
+};
+
+/// A color written out as a function name and its components.
+struct Notation {
+    /// Function name, such as `hsl` or `color`
+    name: &'static str,
+    /// Color space identifier before the components, as in `color(srgb 1 0 0)`
+    space: Option<&'static str>,
+    /// Component values and their unit suffixes
+    components: Vec<(f64, &'static str)>,
+    /// Alpha as a fraction [0–1]
+    alpha: f64,
+}
+
+impl Notation {
+    /// Writes `name(c0, c1, c2[, alpha])`.
+    fn write_plain(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = self.formatted_components(f.precision());
+        if self.alpha < 1.0 {
+            parts.push(format_number(self.alpha));
+        }
+        write!(f, "{}({})", self.name, parts.join(", "))
+    }
+
+    /// Writes `name([space ]c0 c1 c2[ / alpha])`.
+    fn write_css(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let mut parts = self.formatted_components(f.precision());
+        if let Some(space) = self.space {
+            parts.insert(0, space.to_string());
+        }
+        if self.alpha < 1.0 {
+            parts.push(format!("/ {}", format_number(self.alpha)));
+        }
+        write!(f, "{}({})", self.name, parts.join(" "))
+    }
+
+    /// Returns the same notation under another function name.
+    fn renamed(self, name: &'static str) -> Notation {
+        Notation { name, ..self }
+    }
+
+    /// Formats each component with its unit.
+    fn formatted_components(&self, precision: Option<usize>) -> Vec<String> {
+        self.components
+            .iter()
+            .map(|&(value, unit)| format!("{}{unit}", number(value, precision)))
+            .collect()
+    }
+}
+
+/// Formats a number with a fixed number of decimals, or with up to four
+/// decimals and trailing zeros trimmed.
+fn number(value: f64, precision: Option<usize>) -> String {
+    let Some(precision) = precision else {
+        return format_number(value);
+    };
+    let formatted = format!("{value:.precision$}");
+    // Avoid printing `-0.00`
+    match formatted.strip_prefix('-') {
+        Some(unsigned) if unsigned.chars().all(|c| c == '0' || c == '.') => unsigned.to_string(),
+        _ => formatted,
+    }
+}
+
+/// Writes a color as `#rrggbb[aa]`, including the alpha digits when the color
+/// is translucent or `always_alpha` is set.
+fn write_hex(f: &mut fmt::Formatter, color: Rgba, upper: bool, always_alpha: bool) -> fmt::Result {
+    let mut hex = color.to_hex();
+    if always_alpha && color.alpha == 255 {
+        hex.push_str("ff");
+    }
+    if upper {
+        hex.make_ascii_uppercase();
+    }
+    f.write_str(&hex)
+}
+
+/// A color type with its own comma-separated notation and a CSS function form.
+trait Notate: ColorSpace {
+    /// Function name of the plain notation
+    const NAME: &'static str;
+    /// Unit suffix of each component in the plain notation
+    const UNITS: &'static [&'static str];
+    /// Upper bound of each component, which then must be at least 0; `None`
+    /// for components that only need to be finite
+    const MAXIMA: &'static [Option<f64>] = &[None, None, None];
+
+    /// Returns the components of the plain notation, and the alpha.
+    fn components(&self) -> (Vec<f64>, f64);
+
+    /// Builds a color from the components of the plain notation.
+    fn from_components(components: &[f64], alpha: f64) -> Self;
+
+    /// Returns the CSS function form of the color.
+    fn css(&self) -> Notation;
+
+    /// Converts a parsed color, keeping it as is if it is already of this type.
+    fn from_color(color: Color) -> Self;
+
+    /// Returns the plain notation of the color.
+    fn plain(&self) -> Notation {
+        let (components, alpha) = self.components();
+        Notation {
+            name: Self::NAME,
+            space: None,
+            components: components
+                .into_iter()
+                .zip(Self::UNITS.iter().copied())
+                .collect(),
+            alpha,
+        }
+    }
+}
+
+/// Parses the plain notation of `T`, such as `hsl(30, 100%, 50%, 0.5)`, or
+/// returns `None` if the input is not in that notation.
+///
+/// Units are optional, and the alpha may be given as a fourth (or fifth) value.
+/// Components must be finite and within the bounds of `T::MAXIMA`, and alpha
+/// within [0, 1].
+fn parse_plain<T: Notate>(input: &str) -> Option<Result<T, ColorParserError>> {
+    let trimmed = input.trim();
+    let (name, args) = trimmed.split_once('(')?;
+    if !name.eq_ignore_ascii_case(T::NAME) {
+        return None;
+    }
+    let args: Vec<&str> = args.strip_suffix(')')?.split(',').map(str::trim).collect();
+    if args.len() != T::UNITS.len() && args.len() != T::UNITS.len() + 1 {
+        return None;
+    }
+
+    let units = T::UNITS.iter().chain(std::iter::once(&""));
+    let values = args
+        .iter()
+        .zip(units)
+        .map(|(arg, unit)| arg.strip_suffix(unit).unwrap_or(arg).parse().ok())
+        .collect::<Option<Vec<f64>>>()?;
+
+    let maxima = T::MAXIMA.iter().chain(std::iter::once(&Some(1.0)));
+    for ((&value, &max), arg) in values.iter().zip(maxima).zip(&args) {
+        if let Some(expected) = component_expectation(value, max) {
+            let start = offset_of(input, arg);
+            let error = ColorParserError::new(
+                ErrorKind::InvalidComponentValue,
+                input,
+                start..start + arg.len(),
+            );
+            return Some(Err(error.expecting(&[&expected])));
+        }
+    }
+
+    let alpha = values.get(T::UNITS.len()).copied().unwrap_or(1.0);
+    Some(Ok(T::from_components(&values[..T::UNITS.len()], alpha)))
+}
+
+/// Parses the plain notation of `T`, falling back to any CSS color.
+fn parse<T: Notate>(input: &str) -> Result<T, ColorParserError> {
+    match parse_plain(input) {
+        Some(result) => result,
+        None => parse_css_color(input).map(T::from_color),
+    }
+}
+
+/// Implements `Display`, `LowerHex`, `UpperHex` and `FromStr` for types that
+/// implement `Notate`.
+macro_rules! impl_formatting {
+    ($($space:ident),+ $(,)?) => {
+        $(
+            impl fmt::Display for $space {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    if f.alternate() {
+                        self.css().write_css(f)
+                    } else {
+                        self.plain().write_plain(f)
+                    }
+                }
+            }
+
+            impl fmt::LowerHex for $space {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write_hex(f, self.convert(), false, f.alternate())
+                }
+            }
+
+            impl fmt::UpperHex for $space {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write_hex(f, self.convert(), true, f.alternate())
+                }
+            }
+
+            impl FromStr for $space {
+                type Err = ColorParserError;
+
+                fn from_str(input: &str) -> Result<Self, Self::Err> {
+                    parse(input)
+                }
+            }
+        )+
+    };
+}
+
+/// Implements `Notate` for RGB spaces, written as `color(<space> r g b)` in CSS.
+macro_rules! impl_rgb_notation {
+    ($($space:ident),+ $(,)?) => {
+        $(
+            impl Notate for $space {
+                const NAME: &'static str = <$space as RgbSpace>::CSS_NAME;
+                const UNITS: &'static [&'static str] = &["", "", ""];
+
+                fn components(&self) -> (Vec<f64>, f64) {
+                    let (channels, alpha) = self.channels();
+                    (channels.to_vec(), alpha)
+                }
+
+                fn from_components(components: &[f64], alpha: f64) -> Self {
+                    Self::from_channels([components[0], components[1], components[2]], alpha)
+                }
+
+                fn css(&self) -> Notation {
+                    Notation {
+                        space: Some(Self::NAME),
+                        ..self.plain().renamed("color")
+                    }
+                }
+
+                fn from_color(color: Color) -> Self {
+                    match color {
+                        Color::$space(color) => color,
+                        color => color.into(),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_rgb_notation!(Srgb, LinearRgb, DisplayP3, Rec2020, AdobeRgb, ProPhotoRgb);
+
+impl Notate for Hsl {
+    const NAME: &'static str = "hsl";
+    const UNITS: &'static [&'static str] = &["", "%", "%"];
+    const MAXIMA: &'static [Option<f64>] = &[None, Some(100.0), Some(100.0)];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (vec![self.hue, self.saturation, self.lightness], self.alpha)
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Hsl {
+            hue: components[0],
+            saturation: components[1],
+            lightness: components[2],
+            alpha,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain()
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Hsl(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Hsv {
+    const NAME: &'static str = "hsv";
+    const UNITS: &'static [&'static str] = &["", "%", "%"];
+    const MAXIMA: &'static [Option<f64>] = &[None, Some(100.0), Some(100.0)];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (vec![self.hue, self.saturation, self.value], self.alpha)
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Hsv {
+            hue: components[0],
+            saturation: components[1],
+            value: components[2],
+            alpha,
+        }
+    }
+
+    /// HWB is HSV with whiteness `(1 - s) * v` and blackness `1 - v`.
+    fn css(&self) -> Notation {
+        let whiteness = (100.0 - self.saturation) * self.value / 100.0;
+        let blackness = 100.0 - self.value;
+        Notation {
+            name: "hwb",
+            space: None,
+            components: vec![(self.hue, ""), (whiteness, "%"), (blackness, "%")],
+            alpha: self.alpha,
+        }
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Hsv(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Cmyk {
+    const NAME: &'static str = "cmyk";
+    const UNITS: &'static [&'static str] = &["%", "%", "%", "%"];
+    const MAXIMA: &'static [Option<f64>] = &[Some(100.0); 4];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (
+            vec![self.cyan, self.magenta, self.yellow, self.black],
+            self.alpha,
+        )
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Cmyk {
+            cyan: components[0],
+            magenta: components[1],
+            yellow: components[2],
+            black: components[3],
+            alpha,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain().renamed("device-cmyk")
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Cmyk(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Xyz {
+    const NAME: &'static str = "xyz";
+    const UNITS: &'static [&'static str] = &["", "", ""];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (vec![self.x, self.y, self.z], self.alpha)
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Xyz {
+            x: components[0],
+            y: components[1],
+            z: components[2],
+            alpha,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        Notation {
+            space: Some("xyz-d65"),
+            ..self.plain().renamed("color")
+        }
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Xyz(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Lab {
+    const NAME: &'static str = "lab";
+    const UNITS: &'static [&'static str] = &["", "", ""];
+
+    /// Components are relative to D50, as in CSS.
+    fn components(&self) -> (Vec<f64>, f64) {
+        let lab = match self.white_point {
+            WhitePoint::D50 => *self,
+            _ => self.with_white_point(WhitePoint::D50),
+        };
+        (vec![lab.lightness, lab.a, lab.b], lab.alpha)
+    }
+
+    /// Parsed values are relative to D50, as in CSS.
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Lab {
+            lightness: components[0],
+            a: components[1],
+            b: components[2],
+            alpha,
+            white_point: WhitePoint::D50,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain()
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Lab(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Lch {
+    const NAME: &'static str = "lch";
+    const UNITS: &'static [&'static str] = &["", "", ""];
+
+    /// Components are relative to D50, as in CSS.
+    fn components(&self) -> (Vec<f64>, f64) {
+        let lch = match self.white_point {
+            WhitePoint::D50 => *self,
+            _ => self.with_white_point(WhitePoint::D50),
+        };
+        (vec![lch.lightness, lch.chroma, lch.hue], lch.alpha)
+    }
+
+    /// Parsed values are relative to D50, as in CSS.
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Lch {
+            lightness: components[0],
+            chroma: components[1],
+            hue: components[2],
+            alpha,
+            white_point: WhitePoint::D50,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain()
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Lch(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Oklab {
+    const NAME: &'static str = "oklab";
+    const UNITS: &'static [&'static str] = &["", "", ""];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (vec![self.lightness, self.a, self.b], self.alpha)
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Oklab {
+            lightness: components[0],
+            a: components[1],
+            b: components[2],
+            alpha,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain()
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Oklab(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl Notate for Oklch {
+    const NAME: &'static str = "oklch";
+    const UNITS: &'static [&'static str] = &["", "", ""];
+
+    fn components(&self) -> (Vec<f64>, f64) {
+        (vec![self.lightness, self.chroma, self.hue], self.alpha)
+    }
+
+    fn from_components(components: &[f64], alpha: f64) -> Self {
+        Oklch {
+            lightness: components[0],
+            chroma: components[1],
+            hue: components[2],
+            alpha,
+        }
+    }
+
+    fn css(&self) -> Notation {
+        self.plain()
+    }
+
+    fn from_color(color: Color) -> Self {
+        match color {
+            Color::Oklch(color) => color,
+            color => color.into(),
+        }
+    }
+}
+
+impl_formatting!(
+    Srgb,
+    LinearRgb,
+    DisplayP3,
+    Rec2020,
+    AdobeRgb,
+    ProPhotoRgb,
+    Hsl,
+    Hsv,
+    Cmyk,
+    Xyz,
+    Lab,
+    Lch,
+    Oklab,
+    Oklch,
+);
+
+impl fmt::Display for Rgba {
+    /// Formats the color as hex, or as `rgb()` with the alternate flag.
+    /// Channels are whole numbers, so the precision is ignored.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !f.alternate() {
+            return write_hex(f, *self, false, false);
+        }
+        let channels = [self.red, self.green, self.blue].map(f64::from);
+        f.write_str(&css_function("rgb", channels, self.alpha as f64 / 255.0))
+    }
+}
+
+impl fmt::LowerHex for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, *self, false, f.alternate())
+    }
+}
+
+impl fmt::UpperHex for Rgba {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, *self, true, f.alternate())
+    }
+}
+
+impl FromStr for Rgba {
+    type Err = ColorParserError;
+
+    /// Parses any CSS color and converts it to `Rgba`, clamping to the sRGB gamut.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_css_color(input).map(|color| color.to_rgba())
+    }
+}
+
+impl fmt::Display for Rgba16 {
+    /// Formats the color as 16-bit hex, or as `color(srgb r g b)` with the
+    /// alternate flag.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if f.alternate() {
+            fmt::Display::fmt(&Srgb::from_srgb(self.to_srgb()), f)
+        } else {
+            f.write_str(&self.to_hex())
+        }
+    }
+}
+
+impl fmt::LowerHex for Rgba16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, self.to_depth(), false, f.alternate())
+    }
+}
+
+impl fmt::UpperHex for Rgba16 {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, self.to_depth(), true, f.alternate())
+    }
+}
+
+impl FromStr for Rgba16 {
+    type Err = ColorParserError;
+
+    /// Parses 16-bit hex, or any CSS color converted to `Rgba16`.
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        if input.trim_start().starts_with('#') {
+            ParseOptions::new()
+                .allow_whitespace(true)
+                .parse_hex16(input)
+        } else {
+            parse_css_color(input).map(Rgba16::from)
+        }
+    }
+}
+
+/// Implements `Display`, `LowerHex`, `UpperHex` and `FromStr` for
+/// floating-point `Rgba` types through the notation of `Srgb`.
+macro_rules! impl_float_formatting {
+    ($($rgba:ident),+ $(,)?) => {
+        $(
+            impl fmt::Display for $rgba {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    fmt::Display::fmt(&Srgb::from_srgb(self.to_srgb()), f)
+                }
+            }
+
+            impl fmt::LowerHex for $rgba {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write_hex(f, self.to_depth(), false, f.alternate())
+                }
+            }
+
+            impl fmt::UpperHex for $rgba {
+                fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+                    write_hex(f, self.to_depth(), true, f.alternate())
+                }
+            }
+
+            impl FromStr for $rgba {
+                type Err = ColorParserError;
+
+                fn from_str(input: &str) -> Result<Self, Self::Err> {
+                    input.parse::<Srgb>().map(|color| color.convert())
+                }
+            }
+        )+
+    };
+}
+
+impl_float_formatting!(RgbaF32, RgbaF64);
+
+impl fmt::Display for Color {
+    /// Formats the color in the notation of the space it is held in.
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Color::Rgba(color) => fmt::Display::fmt(color, f),
+            Color::Rgba16(color) => fmt::Display::fmt(color, f),
+            Color::RgbaF32(color) => fmt::Display::fmt(color, f),
+            Color::RgbaF64(color) => fmt::Display::fmt(color, f),
+            Color::Srgb(color) => fmt::Display::fmt(color, f),
+            Color::Hsl(color) => fmt::Display::fmt(color, f),
+            Color::Hsv(color) => fmt::Display::fmt(color, f),
+            Color::Cmyk(color) => fmt::Display::fmt(color, f),
+            Color::LinearRgb(color) => fmt::Display::fmt(color, f),
+            Color::Xyz(color) => fmt::Display::fmt(color, f),
+            Color::Lab(color) => fmt::Display::fmt(color, f),
+            Color::Lch(color) => fmt::Display::fmt(color, f),
+            Color::Oklab(color) => fmt::Display::fmt(color, f),
+            Color::Oklch(color) => fmt::Display::fmt(color, f),
+            Color::DisplayP3(color) => fmt::Display::fmt(color, f),
+            Color::Rec2020(color) => fmt::Display::fmt(color, f),
+            Color::AdobeRgb(color) => fmt::Display::fmt(color, f),
+            Color::ProPhotoRgb(color) => fmt::Display::fmt(color, f),
+        }
+    }
+}
+
+impl fmt::LowerHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, self.to_rgba(), false, f.alternate())
+    }
+}
+
+impl fmt::UpperHex for Color {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write_hex(f, self.to_rgba(), true, f.alternate())
+    }
+}
+
+impl FromStr for Color {
+    type Err = ColorParserError;
+
+    fn from_str(input: &str) -> Result<Self, Self::Err> {
+        parse_css_color(input)
+    }
+}
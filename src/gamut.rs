@@ -0,0 +1,145 @@
+//! Gamut mapping for colors outside an RGB space.
+//!
+//! Wide-gamut and Oklch colors often have no exact equivalent in a smaller space
+//! such as sRGB. Clamping each channel is fast but shifts hue and lightness, so
+//! [`GamutMapping::ChromaReduction`] implements the CSS Color Level 4 algorithm:
+//! lightness and hue are kept in Oklch while chroma is reduced until the color
+//! fits, stopping once clipping would no longer be noticeable.
+
+use crate::delta_e::delta_e_ok;
+use crate::{Color, ColorSpace, Oklch, RgbSpace, Rgba, Srgb};
+
+/// Largest ΔEOK between a color and its clipped form that is accepted as
+/// unnoticeable (just noticeable difference)
+const JND: f64 = 0.02;
+/// Chroma precision of the binary search
+const CHROMA_EPSILON: f64 = 0.0001;
+
+/// A strategy for bringing a color into an RGB space's gamut.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum GamutMapping {
+    /// Clamps each channel to [0, 1]
+    Clip,
+    /// Moves the color toward gray in linear light until it fits, keeping the
+    /// hue of the linear channels
+    Scale,
+    /// CSS Color Level 4 chroma reduction in Oklch
+    #[default]
+    ChromaReduction,
+}
+
+/// Converts a color into the RGB space `S`, mapping it into the gamut of `S`
+/// if needed. Colors already in gamut are converted unchanged.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::gamut::{GamutMapping, map_to_gamut};
+/// use color_parser::{DisplayP3, RgbSpace, Srgb};
+///
+/// let p3_red = DisplayP3 { red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0 };
+/// let srgb: Srgb = map_to_gamut(&p3_red, GamutMapping::ChromaReduction);
+/// assert!(srgb.in_gamut());
+/// ```
+pub fn map_to_gamut<S: RgbSpace>(color: &impl ColorSpace, mapping: GamutMapping) -> S {
+    let converted: S = color.convert();
+    if converted.in_gamut() {
+        return converted;
+    }
+
+    match mapping {
+        GamutMapping::Clip => converted.clip(),
+        GamutMapping::Scale => scale(&converted),
+        GamutMapping::ChromaReduction => reduce_chroma(color.convert()),
+    }
+}
+
+/// Scales the linear channels toward their mean, which keeps their ratios to
+/// each other.
+fn scale<S: RgbSpace>(color: &S) -> S {
+    let (channels, alpha) = color.channels();
+    let linear = channels.map(S::decode);
+    let gray = (linear.iter().sum::<f64>() / 3.0).clamp(0.0, 1.0);
+
+    // Largest fraction of the distance from gray that stays within [0, 1]
+    let t = linear.iter().fold(1.0_f64, |t, &c| {
+        if c > 1.0 {
+            t.min((1.0 - gray) / (c - gray))
+        } else if c < 0.0 {
+            t.min(gray / (gray - c))
+        } else {
+            t
+        }
+    });
+
+    let scaled = linear.map(|c| (gray + (c - gray) * t).clamp(0.0, 1.0));
+    S::from_channels(scaled.map(S::encode), alpha)
+}
+
+/// Implements the CSS Color Level 4 gamut mapping algorithm.
+fn reduce_chroma<S: RgbSpace>(origin: Oklch) -> S {
+    // Lightness outside (0, 1) maps to white or black, which every space shares
+    if origin.lightness >= 1.0 {
+        return S::from_channels([1.0; 3], origin.alpha);
+    }
+    if origin.lightness <= 0.0 {
+        return S::from_channels([0.0; 3], origin.alpha);
+    }
+
+    let mut current = origin;
+    let mut clipped = current.convert::<S>().clip();
+    if difference(&clipped, &current) < JND {
+        return clipped;
+    }
+
+    // Binary search for the largest chroma whose clipped form is unnoticeable
+    let (mut min, mut max) = (0.0, origin.chroma);
+    let mut min_in_gamut = true;
+    while max - min > CHROMA_EPSILON {
+        let chroma = (min + max) / 2.0;
+        current.chroma = chroma;
+
+        let candidate: S = current.convert();
+        if min_in_gamut && candidate.in_gamut() {
+            min = chroma;
+            continue;
+        }
+
+        clipped = candidate.clip();
+        let e = difference(&clipped, &current);
+        if e < JND {
+            if JND - e < CHROMA_EPSILON {
+                return clipped;
+            }
+            min_in_gamut = false;
+            min = chroma;
+        } else {
+            max = chroma;
+        }
+    }
+
+    clipped
+}
+
+/// Computes the ΔEOK between a clipped color and the Oklch color it came from.
+fn difference<S: RgbSpace>(clipped: &S, current: &Oklch) -> f64 {
+    delta_e_ok(&clipped.convert(), &current.to_oklab())
+}
+
+impl Color {
+    /// Converts the color to `Rgba`, mapping it into the sRGB gamut with the
+    /// given strategy instead of clamping each channel.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::gamut::GamutMapping;
+    /// use color_parser::parse_css_color;
+    ///
+    /// let color = parse_css_color("oklch(0.7 0.3 150)").unwrap();
+    /// let clipped = color.to_rgba_mapped(GamutMapping::Clip);
+    /// let mapped = color.to_rgba_mapped(GamutMapping::ChromaReduction);
+    /// assert_ne!(clipped, mapped);
+    /// ```
+    pub fn to_rgba_mapped(&self, mapping: GamutMapping) -> Rgba {
+        Rgba::from_srgb(map_to_gamut::<Srgb>(self, mapping))
+    }
+}
@@ -0,0 +1,126 @@
+//! Color harmonies: palettes built by rotating the hue of a base color.
+//!
+//! Each [`Harmony`] is a set of hue offsets from the base color. The rotation
+//! happens either in HSL, which matches most design tools, or in Oklch, which
+//! keeps the perceived lightness and chroma of the base color across the
+//! palette.
+
+use crate::gamut::{GamutMapping, map_to_gamut};
+use crate::{Hsl, Oklch, Rgba, Srgb};
+
+/// A color harmony scheme.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Harmony {
+    /// The base color and the opposite hue
+    Complementary,
+    /// Three hues evenly spaced 120° apart
+    Triadic,
+    /// Four hues evenly spaced 90° apart (a square)
+    Tetradic,
+    /// The base color and its neighbors 30° to either side
+    Analogous,
+    /// The base color and the two neighbors of its complement
+    SplitComplementary,
+}
+
+impl Harmony {
+    /// Every scheme, in the order listed above.
+    pub const ALL: [Harmony; 5] = [
+        Harmony::Complementary,
+        Harmony::Triadic,
+        Harmony::Tetradic,
+        Harmony::Analogous,
+        Harmony::SplitComplementary,
+    ];
+
+    /// Returns the hue offsets in degrees, starting with the base color at 0.
+    pub fn offsets(&self) -> &'static [f64] {
+        match self {
+            Harmony::Complementary => &[0.0, 180.0],
+            Harmony::Triadic => &[0.0, 120.0, 240.0],
+            Harmony::Tetradic => &[0.0, 90.0, 180.0, 270.0],
+            Harmony::Analogous => &[0.0, -30.0, 30.0],
+            Harmony::SplitComplementary => &[0.0, 150.0, 210.0],
+        }
+    }
+
+    /// Returns the lowercase name of the scheme, such as `split-complementary`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            Harmony::Complementary => "complementary",
+            Harmony::Triadic => "triadic",
+            Harmony::Tetradic => "tetradic",
+            Harmony::Analogous => "analogous",
+            Harmony::SplitComplementary => "split-complementary",
+        }
+    }
+
+    /// Looks up a scheme by name (case-insensitive). `split` is accepted as a
+    /// short form of `split-complementary`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::harmony::Harmony;
+    ///
+    /// assert_eq!(Harmony::from_name("Triadic"), Some(Harmony::Triadic));
+    /// assert_eq!(Harmony::from_name("split"), Some(Harmony::SplitComplementary));
+    /// assert_eq!(Harmony::from_name("pentadic"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<Harmony> {
+        let name = name.to_ascii_lowercase();
+        match name.as_str() {
+            "split" => Some(Harmony::SplitComplementary),
+            _ => Harmony::ALL
+                .into_iter()
+                .find(|harmony| harmony.name() == name),
+        }
+    }
+}
+
+impl Hsl {
+    /// Generates a harmony by rotating the hue in HSL, starting with this color.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::harmony::Harmony;
+    /// use color_parser::{Hsl, Rgba};
+    ///
+    /// let orange = Hsl::from(Rgba { red: 255, green: 136, blue: 0, alpha: 255 });
+    /// let triad = orange.harmony(Harmony::Triadic);
+    /// assert_eq!(triad[1], Rgba { red: 0, green: 255, blue: 136, alpha: 255 });
+    /// assert_eq!(triad[2], Rgba { red: 136, green: 0, blue: 255, alpha: 255 });
+    /// ```
+    pub fn harmony(&self, scheme: Harmony) -> Vec<Rgba> {
+        scheme
+            .offsets()
+            .iter()
+            .map(|offset| {
+                Rgba::from(Hsl {
+                    hue: (self.hue + offset).rem_euclid(360.0),
+                    ..*self
+                })
+            })
+            .collect()
+    }
+}
+
+impl Oklch {
+    /// Generates a harmony by rotating the hue in Oklch, starting with this
+    /// color. Rotated colors outside the sRGB gamut are mapped into it.
+    pub fn harmony(&self, scheme: Harmony) -> Vec<Rgba> {
+        scheme
+            .offsets()
+            .iter()
+            .map(|offset| {
+                let rotated = Oklch {
+                    hue: (self.hue + offset).rem_euclid(360.0),
+                    ..*self
+                };
+                Rgba::from(map_to_gamut::<Srgb>(
+                    &rotated,
+                    GamutMapping::ChromaReduction,
+                ))
+            })
+            .collect()
+    }
+}
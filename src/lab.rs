@@ -0,0 +1,221 @@
+//! CIELAB and CIELCh color spaces.
+//!
+//! Both are derived from CIE XYZ relative to a selectable [`WhitePoint`]. CSS
+//! `lab()` and `lch()` use D50, which is also the default when converting from
+//! other color spaces; D65 or any other white point is available for tooling
+//! that expects it. XYZ values are adapted between white points with the
+//! Bradford transform unless another [`AdaptationMethod`] is given.
+
+use crate::adaptation::{AdaptationMethod, adapt};
+use crate::css::css_function;
+use crate::xyz::WhitePoint;
+use crate::{ColorSpace, Srgb, Xyz};
+
+/// CIE ε: the luminance ratio below which the linear segment is used
+const EPSILON: f64 = 216.0 / 24389.0;
+/// CIE κ: the slope of the linear segment
+const KAPPA: f64 = 24389.0 / 27.0;
+
+/// Represents a color in the CIELAB (L\*a\*b\*) color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lab {
+    /// Perceptual lightness [0–100]
+    pub lightness: f64,
+    /// Green–red axis, roughly [-125–125]
+    pub a: f64,
+    /// Blue–yellow axis, roughly [-125–125]
+    pub b: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+    /// Reference white the values are relative to
+    pub white_point: WhitePoint,
+}
+
+/// Represents a color in the CIELCh color space, the polar form of CIELAB.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Lch {
+    /// Perceptual lightness [0–100]
+    pub lightness: f64,
+    /// Chroma, roughly [0–150]
+    pub chroma: f64,
+    /// Hue in degrees [0–360)
+    pub hue: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+    /// Reference white the values are relative to
+    pub white_point: WhitePoint,
+}
+
+impl Lab {
+    /// Converts a D65-relative `Xyz` color to CIELAB relative to `white_point`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{Lab, Rgba, Xyz};
+    /// use color_parser::xyz::WhitePoint;
+    ///
+    /// let xyz: Xyz = Rgba { red: 255, green: 0, blue: 0, alpha: 255 }.into();
+    /// let lab = Lab::from_xyz(&xyz, WhitePoint::D65);
+    /// assert_eq!(lab.lightness.round(), 53.0);
+    /// ```
+    pub fn from_xyz(xyz: &Xyz, white_point: WhitePoint) -> Lab {
+        Lab::from_xyz_with(xyz, white_point, AdaptationMethod::Bradford)
+    }
+
+    /// Converts a D65-relative `Xyz` color to CIELAB relative to `white_point`,
+    /// adapting with the given transform.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::adaptation::AdaptationMethod;
+    /// use color_parser::{Lab, Rgba, WhitePoint};
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// let lab = Lab::from_xyz_with(&red.into(), WhitePoint::D50, AdaptationMethod::Cat16);
+    /// assert_eq!(Rgba::from(lab.to_xyz_with(AdaptationMethod::Cat16)), red);
+    /// ```
+    pub fn from_xyz_with(xyz: &Xyz, white_point: WhitePoint, method: AdaptationMethod) -> Lab {
+        let [x, y, z] = adapt([xyz.x, xyz.y, xyz.z], WhitePoint::D65, white_point, method);
+        let [wx, wy, wz] = white_point.xyz();
+
+        // Compress each ratio to the white with a cube root (linear near black)
+        let f = |t: f64| {
+            if t > EPSILON {
+                t.cbrt()
+            } else {
+                (KAPPA * t + 16.0) / 116.0
+            }
+        };
+        let (fx, fy, fz) = (f(x / wx), f(y / wy), f(z / wz));
+
+        Lab {
+            lightness: 116.0 * fy - 16.0,
+            a: 500.0 * (fx - fy),
+            b: 200.0 * (fy - fz),
+            alpha: xyz.alpha,
+            white_point,
+        }
+    }
+
+    /// Converts this color to D65-relative `Xyz`.
+    pub fn to_xyz(&self) -> Xyz {
+        self.to_xyz_with(AdaptationMethod::Bradford)
+    }
+
+    /// Converts this color to D65-relative `Xyz`, adapting with the given
+    /// transform.
+    pub fn to_xyz_with(&self, method: AdaptationMethod) -> Xyz {
+        let fy = (self.lightness + 16.0) / 116.0;
+        let fx = fy + self.a / 500.0;
+        let fz = fy - self.b / 200.0;
+
+        // Undo the cube-root compression
+        let x = if fx.powi(3) > EPSILON {
+            fx.powi(3)
+        } else {
+            (116.0 * fx - 16.0) / KAPPA
+        };
+        let y = if self.lightness > KAPPA * EPSILON {
+            fy.powi(3)
+        } else {
+            self.lightness / KAPPA
+        };
+        let z = if fz.powi(3) > EPSILON {
+            fz.powi(3)
+        } else {
+            (116.0 * fz - 16.0) / KAPPA
+        };
+
+        let [wx, wy, wz] = self.white_point.xyz();
+        let [x, y, z] = adapt(
+            [x * wx, y * wy, z * wz],
+            self.white_point,
+            WhitePoint::D65,
+            method,
+        );
+
+        Xyz {
+            x,
+            y,
+            z,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Re-expresses this color relative to another white point.
+    pub fn with_white_point(&self, white_point: WhitePoint) -> Lab {
+        Lab::from_xyz(&self.to_xyz(), white_point)
+    }
+
+    /// Converts this color to its polar form.
+    pub fn to_lch(&self) -> Lch {
+        Lch {
+            lightness: self.lightness,
+            chroma: self.a.hypot(self.b),
+            hue: self.b.atan2(self.a).to_degrees().rem_euclid(360.0),
+            alpha: self.alpha,
+            white_point: self.white_point,
+        }
+    }
+
+    /// Formats this color as a CSS `lab()` function, converting to D50 if needed.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{Lab, Rgba};
+    ///
+    /// let lab = Lab::from(Rgba { red: 255, green: 255, blue: 255, alpha: 255 });
+    /// assert_eq!(lab.to_css_string(), "lab(100 0 0)");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        let lab = self.with_white_point(WhitePoint::D50);
+        css_function("lab", [lab.lightness, lab.a, lab.b], lab.alpha)
+    }
+}
+
+impl Lch {
+    /// Converts this color to its rectangular form.
+    pub fn to_lab(&self) -> Lab {
+        let hue = self.hue.to_radians();
+        Lab {
+            lightness: self.lightness,
+            a: self.chroma * hue.cos(),
+            b: self.chroma * hue.sin(),
+            alpha: self.alpha,
+            white_point: self.white_point,
+        }
+    }
+
+    /// Re-expresses this color relative to another white point.
+    pub fn with_white_point(&self, white_point: WhitePoint) -> Lch {
+        self.to_lab().with_white_point(white_point).to_lch()
+    }
+
+    /// Formats this color as a CSS `lch()` function, converting to D50 if needed.
+    pub fn to_css_string(&self) -> String {
+        let lch = self.with_white_point(WhitePoint::D50);
+        css_function("lch", [lch.lightness, lch.chroma, lch.hue], lch.alpha)
+    }
+}
+
+/// CIELAB derived from sRGB uses the CSS default D50 white point.
+impl ColorSpace for Lab {
+    fn to_srgb(&self) -> Srgb {
+        self.to_xyz().to_srgb()
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        Lab::from_xyz(&Xyz::from_srgb(color), WhitePoint::D50)
+    }
+}
+
+/// CIELCh derived from sRGB uses the CSS default D50 white point.
+impl ColorSpace for Lch {
+    fn to_srgb(&self) -> Srgb {
+        self.to_lab().to_srgb()
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        Lab::from_srgb(color).to_lch()
+    }
+}
@@ -2,7 +2,33 @@
 //!
 //! Supports conversion between:
 //! - Hexadecimal (`#RRGGBB`, `#RRGGBBAA`, `#RGB`, `#RGBA`) and `Rgba`
-//! - `Rgba` to `Hsl`, `Hsv`, and `Cmyk`
+//! - `Rgba` to `Hsl`, `Hsv`, and `Cmyk`, and back
+//! - `Hsl` to `Hsv`, and back
+//! - CSS Color Level 4 functional notations (`rgb()`, `hsl()`, `hwb()`) to `Rgba` and `Hsl`
+//! - CSS named colors (and X11 names with the `x11` feature) to `Rgba`
+//! - Linear-light sRGB (`LinearRgb`) and CIE XYZ (`Xyz`, D65)
+//! - CIELAB (`Lab`) and CIELCh (`Lch`) relative to any white point
+//! - Chromatic adaptation (Bradford, von Kries, CAT02, CAT16) between white points
+//! - Oklab (`Oklab`) and Oklch (`Oklch`)
+//! - Wide-gamut RGB: Display P3, Rec. 2020, Adobe RGB and ProPhoto RGB
+//! - Color mixing in any space with CSS hue interpolation, and `color-mix()`
+//! - Color scales with stops, domains, classes, Bezier interpolation and lightness correction
+//! - Color harmonies (complementary, triadic, tetradic, analogous, split) in HSL or Oklch
+//! - Tints, shades, tones and Tailwind-style 50–950 ramps in Oklch
+//! - Sass-style adjustments (lighten, darken, saturate, spin, invert, grayscale, fade) in HSL or Oklch
+//! - Porter-Duff compositing and CSS blend modes with premultiplied alpha
+//! - `Display`, `FromStr` and hex formatting for every color type, with CSS and precision options
+//! - Strict and lenient hex parsing with `ParseOptions` (`#`, `0x`, whitespace, case, alpha, shorthand)
+//! - ARGB, BGRA and ABGR channel orders for hex strings and packed `u32` colors
+//! - 16-bit and floating-point channels (`Rgba16`, `RgbaF32`, `RgbaF64`) and 12/16-digit hex
+//! - Parse errors with byte spans, expected forms and rustc-style rendering
+//! - Gamut checks and CSS Color 4 gamut mapping (chroma reduction, clip, scale)
+//! - Color differences: ΔE76, ΔE94, ΔE2000, CMC l:c and ΔEOK
+//! - WCAG 2.x contrast ratio with AA/AAA checks, and APCA lightness contrast
+//! - Color vision deficiency simulation and daltonization
+//!
+//! Every color type implements [`ColorSpace`] and `From`/`Into` for every other,
+//! and the [`Color`] enum can hold a color in any of them.
 //!
 //! # Example
 //! ```rust
@@ -12,22 +38,98 @@
 //! let hsl = parse_rgb_to_hsl(&rgba).unwrap();
 //! let hsv = parse_rgb_to_hsv(&rgba).unwrap();
 //! let cmyk = parse_rgb_to_cmyk(&rgba).unwrap();
+//! let back = parse_hsl_to_rgb(&hsl).unwrap();
+//! let css = parse_css_color("rgb(255 136 0 / 50%)").unwrap();
+//!
+//! let hsl: Hsl = rgba.into();
+//! let cmyk = Cmyk::from(hsl);
 //! ```
 
-/// Represents a color in the RGBA color space.
-#[derive(Debug, PartialEq, Eq)]
-pub struct Rgba {
-    /// Red channel (0–255)
-    pub red: u8,
-    /// Green channel (0–255)
-    pub green: u8,
-    /// Blue channel (0–255)
-    pub blue: u8,
-    /// Alpha channel (0–255), where 255 is fully opaque
-    pub alpha: u8,
+pub mod adaptation;
+pub mod adjust;
+pub mod channel;
+pub mod color;
+pub mod composite;
+pub mod contrast;
+pub mod css;
+pub mod cvd;
+pub mod delta_e;
+pub mod error;
+pub mod format;
+pub mod gamut;
+pub mod harmony;
+pub mod lab;
+pub mod mix;
+pub mod named;
+pub mod oklab;
+pub mod options;
+pub mod order;
+pub mod ramp;
+pub mod scale;
+pub mod wide_gamut;
+pub mod xyz;
+
+mod matrix;
+
+pub use channel::Channel;
+pub use color::{Color, ColorSpace};
+pub use css::parse_css_color;
+pub use error::{ColorParserError, ErrorKind};
+pub use lab::{Lab, Lch};
+pub use named::named_color;
+pub use oklab::{Oklab, Oklch};
+pub use options::ParseOptions;
+pub use order::ChannelOrder;
+pub use wide_gamut::{AdobeRgb, DisplayP3, ProPhotoRgb, Rec2020, RgbSpace};
+pub use xyz::{Chromaticity, LinearRgb, WhitePoint, Xyz};
+
+/// Represents a color in the RGBA color space, with channels stored as `T`.
+///
+/// Use the [`Rgba`], [`Rgba16`], [`RgbaF32`] and [`RgbaF64`] aliases rather
+/// than naming this type; see [`Channel`] for the storage types.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct GenericRgba<T> {
+    /// Red channel, from 0 to [`Channel::MAX`]
+    pub red: T,
+    /// Green channel, from 0 to [`Channel::MAX`]
+    pub green: T,
+    /// Blue channel, from 0 to [`Channel::MAX`]
+    pub blue: T,
+    /// Alpha channel, where [`Channel::MAX`] is fully opaque
+    pub alpha: T,
+}
+
+/// Represents a color in the RGBA color space with 8-bit channels (0–255).
+pub type Rgba = GenericRgba<u8>;
+
+/// An RGBA color with 16-bit channels (0–65535), as in 16-bit PNG files.
+pub type Rgba16 = GenericRgba<u16>;
+
+/// An RGBA color with unclamped `f32` channels, nominally [0–1].
+pub type RgbaF32 = GenericRgba<f32>;
+
+/// An RGBA color with unclamped `f64` channels, nominally [0–1].
+pub type RgbaF64 = GenericRgba<f64>;
+
+/// Represents a color in the sRGB color space with floating-point channels.
+///
+/// This is the hub every other color space converts through (see [`ColorSpace`]).
+/// Channels are gamma-encoded and not clamped, so values outside [0, 1]
+/// describe colors outside the sRGB gamut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Srgb {
+    /// Red channel, nominally [0–1]
+    pub red: f64,
+    /// Green channel, nominally [0–1]
+    pub green: f64,
+    /// Blue channel, nominally [0–1]
+    pub blue: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
 }
 
 /// Represents a color in the HSL color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Hsl {
     /// Hue in degrees [0–360)
     pub hue: f64,
@@ -35,9 +137,12 @@ pub struct Hsl {
     pub saturation: f64,
     /// Lightness as percentage [0–100]
     pub lightness: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
 }
 
 /// Represents a color in the HSV color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Hsv {
     /// Hue in degrees [0–360)
     pub hue: f64,
@@ -45,9 +150,12 @@ pub struct Hsv {
     pub saturation: f64,
     /// Value (brightness) as percentage [0–100]
     pub value: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
 }
 
 /// Represents a color in the CMYK color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub struct Cmyk {
     /// Cyan channel as percentage [0–100]
     pub cyan: f64,
@@ -57,41 +165,46 @@ pub struct Cmyk {
     pub yellow: f64,
     /// Black (Key) channel as percentage [0–100]
     pub black: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
 }
 
-/// Errors that can occur during color parsing or conversion.
-#[derive(Debug)]
-pub enum ColorParserError {
-    /// Invalid hex string length (must be 3, 4, 6, or 8 characters)
-    InvalidLength,
-    /// Invalid character in hex string
-    InvalidCharacter,
-    /// RGB values must be in the 0–255 range
-    InvalidRgbValue,
-}
-
-impl std::fmt::Display for ColorParserError {
-    fn fmt(&self, f: &mut std::fmt::Formatter) -> std::fmt::Result {
-        match self {
-            ColorParserError::InvalidLength => write!(f, "Hex color must be 6 character long"),
-            ColorParserError::InvalidCharacter => write!(f, "Invalid character in hex color"),
-            ColorParserError::InvalidRgbValue => write!(f, "RGB value must be between 0 and 255"),
+impl Rgba {
+    /// Formats the color as lowercase `#rrggbb`, or `#rrggbbaa` if it is not
+    /// fully opaque.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// assert_eq!(Rgba { red: 255, green: 136, blue: 0, alpha: 255 }.to_hex(), "#ff8800");
+    /// assert_eq!(Rgba { red: 255, green: 136, blue: 0, alpha: 128 }.to_hex(), "#ff880080");
+    /// ```
+    pub fn to_hex(&self) -> String {
+        let hex = format!("#{:02x}{:02x}{:02x}", self.red, self.green, self.blue);
+        if self.alpha == 255 {
+            hex
+        } else {
+            format!("{hex}{:02x}", self.alpha)
         }
     }
 }
 
-impl std::error::Error for ColorParserError {}
-
 /// Parses a hexadecimal color string into an `Rgba` struct.
 ///
-/// Accepts the following formats:
+/// Accepts the following formats, with or without the `#`:
 /// - `#RRGGBB`
 /// - `#RRGGBBAA`
 /// - `#RGB`
 /// - `#RGBA`
 ///
+/// This uses the default [`ParseOptions`]; use them directly to require the
+/// `#`, accept `0x` or whitespace, or reject uppercase or shorthand colors.
+///
 /// # Errors
-/// Returns `ColorParserError` if the format or characters are invalid.
+/// - `InvalidSyntax`, pointing at it, if there is surrounding whitespace.
+/// - `InvalidLength`, spanning the digits, if there are not 3, 4, 6 or 8 of them.
+/// - `InvalidCharacter`, pointing at the first character that is not a hex digit.
 ///
 /// # Examples
 /// ```rust
@@ -101,58 +214,68 @@ impl std::error::Error for ColorParserError {}
 /// assert_eq!(color.red, 255);
 /// ```
 pub fn parse_hex_to_rgba(hex: &str) -> Result<Rgba, ColorParserError> {
-    let hex = hex.trim_start_matches('#');
+    ParseOptions::default().parse_hex(hex)
+}
 
-    // Handle different hex color formats and ensure valid length
-    let expanded = match hex.len() {
-        8 => hex.to_string(),      // Full RGBA
-        6 => format!("{}FF", hex), // default alpha = 255
+/// Parses a hexadecimal color string into an `Rgba16` struct, with 16 bits
+/// per channel.
+///
+/// Accepts the formats of [`parse_hex_to_rgba`], which are widened losslessly,
+/// as well as four digits per channel:
+/// - `#RRRRGGGGBBBB`
+/// - `#RRRRGGGGBBBBAAAA`
+///
+/// # Errors
+/// The same as [`parse_hex_to_rgba`], with 12 and 16 digits also allowed.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Rgba16, parse_hex_to_rgba16};
+///
+/// let color = parse_hex_to_rgba16("#ffff88000001").unwrap();
+/// assert_eq!(color.green, 0x8800);
+/// assert_eq!(parse_hex_to_rgba16("#ff8800").unwrap().green, 0x8888);
+/// ```
+pub fn parse_hex_to_rgba16(hex: &str) -> Result<Rgba16, ColorParserError> {
+    ParseOptions::default().parse_hex16(hex)
+}
+
+/// Builds an `Rgba` from 3, 4, 6 or 8 hex digits, which must already be validated.
+pub(crate) fn expand_hex(digits: &str) -> Rgba {
+    // Handle different hex color formats
+    let expanded = match digits.len() {
+        8 => digits.to_string(),      // Full RGBA
+        6 => format!("{}FF", digits), // default alpha = 255
         4 => {
             // Expands #RGBA => #RRGGBBAA
             let mut s = String::with_capacity(8);
-            for ch in hex.chars() {
+            for ch in digits.chars() {
                 s.push(ch);
                 s.push(ch);
             }
             s
         }
-        3 => {
+        _ => {
             // Expands #RGB => #RRGGBB + FF
             let mut s = String::with_capacity(8);
-            for ch in hex.chars() {
+            for ch in digits.chars() {
                 s.push(ch);
                 s.push(ch);
             }
             s.push_str("FF"); // Default alpha
             s
         }
-        _ => return Err(ColorParserError::InvalidLength),
     };
 
-    let red =
-        u8::from_str_radix(&expanded[0..2], 16).map_err(|_| ColorParserError::InvalidCharacter)?;
-    let green =
-        u8::from_str_radix(&expanded[2..4], 16).map_err(|_| ColorParserError::InvalidCharacter)?;
-    let blue =
-        u8::from_str_radix(&expanded[4..6], 16).map_err(|_| ColorParserError::InvalidCharacter)?;
-    let alpha =
-        u8::from_str_radix(&expanded[6..8], 16).map_err(|_| ColorParserError::InvalidCharacter)?;
+    // Every character is a hex digit, so each pair fits in a u8
+    let channel = |i: usize| u8::from_str_radix(&expanded[i..i + 2], 16).unwrap_or_default();
 
-    // Check that RGBA values are within valid range
-    if !(0..=255).contains(&red)
-        || !(0..=255).contains(&green)
-        || !(0..=255).contains(&blue)
-        || !(0..=255).contains(&alpha)
-    {
-        return Err(ColorParserError::InvalidRgbValue);
+    Rgba {
+        red: channel(0),
+        green: channel(2),
+        blue: channel(4),
+        alpha: channel(6),
     }
-
-    Ok(Rgba {
-        red,
-        green,
-        blue,
-        alpha,
-    })
 }
 
 /// Converts an `Rgba` color to the HSL color space.
@@ -175,45 +298,10 @@ pub fn parse_rgb_to_hsl(color: &Rgba) -> Result<Hsl, ColorParserError> {
         || !(0..=255).contains(&color.green)
         || !(0..=255).contains(&color.blue)
     {
-        return Err(ColorParserError::InvalidRgbValue);
+        return Err(ErrorKind::InvalidRgbValue.into());
     }
 
-    // Convert r, g, b [0, 255] range to [0, 1]
-    let r = color.red as f64 / 255.0;
-    let g = color.green as f64 / 255.0;
-    let b = color.blue as f64 / 255.0;
-
-    // Find min and max among the r, g, b values
-    let max = r.max(g).max(b);
-    let min = r.min(g).min(b);
-    let delta = max - min;
-
-    // Calculate lightness
-    let lightness = (max + min) / 2.0;
-
-    // Calculate saturation
-    let saturation = if delta == 0.0 {
-        0.0
-    } else {
-        delta / (1.0 - (2.0 * lightness - 1.0).abs())
-    };
-
-    // Calculate hue
-    let hue = if delta == 0.0 {
-        0.0
-    } else if max == r {
-        (g - b) / delta + (if g < b { 6.0 } else { 0.0 })
-    } else if max == g {
-        (b - r) / delta + 2.0 // 120° on the color wheel
-    } else {
-        (r - g) / delta + 4.0 // 240° on the color wheel
-    };
-
-    Ok(Hsl {
-        hue: (hue * 60.0) % 360.0, // Normalize the hue to [0°, 350°]
-        saturation: saturation * 100.0,
-        lightness: lightness * 100.0,
-    })
+    Ok(Hsl::from_srgb(color.to_srgb()))
 }
 
 /// Converts an `Rgba` color to the HSV color space.
@@ -235,41 +323,10 @@ pub fn parse_rgb_to_hsv(color: &Rgba) -> Result<Hsv, ColorParserError> {
         || !(0..=255).contains(&color.green)
         || !(0..=255).contains(&color.blue)
     {
-        return Err(ColorParserError::InvalidRgbValue);
+        return Err(ErrorKind::InvalidRgbValue.into());
     }
 
-    // Convert r, g, b [0, 255] range to [0, 1]
-    let r = color.red as f64 / 255.0;
-    let g = color.green as f64 / 255.0;
-    let b = color.blue as f64 / 255.0;
-
-    // Find min and max among the r, g, b values
-    let max = r.max(g).max(b);
-    let min = r.min(g).min(b);
-    let delta = max - min;
-
-    // Calculate the value
-    let value = max;
-
-    // Calculate the saturation
-    let saturation = if delta == 0.0 { 0.0 } else { delta / value };
-
-    // Calculate the hue
-    let hue = if delta == 0.0 {
-        0.0
-    } else if max == r {
-        (g - b) / delta + (if g < b { 6.0 } else { 0.0 })
-    } else if max == g {
-        (b - r) / delta + 2.0
-    } else {
-        (r - g) / delta + 4.0
-    };
-
-    Ok(Hsv {
-        hue: (hue * 60.0) % 360.0,
-        saturation: saturation * 100.0,
-        value: value * 100.0,
-    })
+    Ok(Hsv::from_srgb(color.to_srgb()))
 }
 
 /// Converts an `Rgba` color to the CMYK color space.
@@ -291,35 +348,232 @@ pub fn parse_rgb_to_cmyk(color: &Rgba) -> Result<Cmyk, ColorParserError> {
         || !(0..=255).contains(&color.green)
         || !(0..=255).contains(&color.blue)
     {
-        return Err(ColorParserError::InvalidRgbValue);
+        return Err(ErrorKind::InvalidRgbValue.into());
     }
 
-    // Convert r, g, b [0, 255] range to [0, 1]
-    let r = color.red as f64 / 255.0;
-    let g = color.green as f64 / 255.0;
-    let b = color.blue as f64 / 255.0;
-
-    // Calculate the black key color
-    let k = 1.0 - r.max(g).max(b);
-
-    // If RGB is key (black), set CMY to 0
-    if k == 1.0 {
-        return Ok(Cmyk {
-            cyan: 0.0 * 100.0,
-            magenta: 0.0 * 100.0,
-            yellow: 0.0 * 100.0,
-            black: 100.0,
-        });
-    }
-    // Calculate cyan, magenta and yellow color
-    let c = (1.0 - r - k) / (1.0 - k);
-    let m = (1.0 - g - k) / (1.0 - k);
-    let y = (1.0 - b - k) / (1.0 - k);
-
-    Ok(Cmyk {
-        cyan: c * 100.0,
-        magenta: m * 100.0,
-        yellow: y * 100.0,
-        black: k * 100.0,
+    Ok(Cmyk::from_srgb(color.to_srgb()))
+}
+
+/// Converts an `Hsl` color back to the RGBA color space.
+///
+/// The hue wraps around, so any finite angle is accepted.
+///
+/// # Errors
+/// Returns `InvalidComponentValue` if saturation or lightness are outside
+/// the 0–100 range, or alpha is outside the 0–1 range.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Hsl, Rgba, parse_hsl_to_rgb};
+///
+/// let hsl = Hsl { hue: 120.0, saturation: 100.0, lightness: 50.0, alpha: 1.0 };
+/// let rgba = parse_hsl_to_rgb(&hsl).unwrap();
+/// assert_eq!(rgba, Rgba { red: 0, green: 255, blue: 0, alpha: 255 });
+/// ```
+pub fn parse_hsl_to_rgb(color: &Hsl) -> Result<Rgba, ColorParserError> {
+    // Check that HSL values are within valid range
+    check_hue(color.hue)?;
+    check_range(color.saturation, 100.0)?;
+    check_range(color.lightness, 100.0)?;
+    check_range(color.alpha, 1.0)?;
+
+    Ok(Rgba::from_srgb(color.to_srgb()))
+}
+
+/// Converts an `Hsv` color back to the RGBA color space.
+///
+/// The hue wraps around, so any finite angle is accepted.
+///
+/// # Errors
+/// Returns `InvalidComponentValue` if saturation or value are outside
+/// the 0–100 range, or alpha is outside the 0–1 range.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Hsv, Rgba, parse_hsv_to_rgb};
+///
+/// let hsv = Hsv { hue: 240.0, saturation: 100.0, value: 100.0, alpha: 1.0 };
+/// let rgba = parse_hsv_to_rgb(&hsv).unwrap();
+/// assert_eq!(rgba, Rgba { red: 0, green: 0, blue: 255, alpha: 255 });
+/// ```
+pub fn parse_hsv_to_rgb(color: &Hsv) -> Result<Rgba, ColorParserError> {
+    // Check that HSV values are within valid range
+    check_hue(color.hue)?;
+    check_range(color.saturation, 100.0)?;
+    check_range(color.value, 100.0)?;
+    check_range(color.alpha, 1.0)?;
+
+    Ok(Rgba::from_srgb(color.to_srgb()))
+}
+
+/// Converts a `Cmyk` color back to the RGBA color space.
+///
+/// # Errors
+/// Returns `InvalidComponentValue` if any channel is outside the 0–100 range,
+/// or alpha is outside the 0–1 range.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Cmyk, Rgba, parse_cmyk_to_rgb};
+///
+/// let cmyk = Cmyk { cyan: 0.0, magenta: 100.0, yellow: 100.0, black: 0.0, alpha: 1.0 };
+/// let rgba = parse_cmyk_to_rgb(&cmyk).unwrap();
+/// assert_eq!(rgba, Rgba { red: 255, green: 0, blue: 0, alpha: 255 });
+/// ```
+pub fn parse_cmyk_to_rgb(color: &Cmyk) -> Result<Rgba, ColorParserError> {
+    // Check that CMYK values are within valid range
+    check_range(color.cyan, 100.0)?;
+    check_range(color.magenta, 100.0)?;
+    check_range(color.yellow, 100.0)?;
+    check_range(color.black, 100.0)?;
+    check_range(color.alpha, 1.0)?;
+
+    Ok(Rgba::from_srgb(color.to_srgb()))
+}
+
+/// Converts an `Hsl` color directly to the HSV color space.
+///
+/// # Errors
+/// Returns `InvalidComponentValue` if saturation or lightness are outside
+/// the 0–100 range.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Hsl, parse_hsl_to_hsv};
+///
+/// let hsl = Hsl { hue: 30.0, saturation: 100.0, lightness: 50.0, alpha: 1.0 };
+/// let hsv = parse_hsl_to_hsv(&hsl).unwrap();
+/// assert_eq!(hsv.saturation, 100.0);
+/// assert_eq!(hsv.value, 100.0);
+/// ```
+pub fn parse_hsl_to_hsv(color: &Hsl) -> Result<Hsv, ColorParserError> {
+    // Check that HSL values are within valid range
+    check_hue(color.hue)?;
+    check_range(color.saturation, 100.0)?;
+    check_range(color.lightness, 100.0)?;
+
+    let s = color.saturation / 100.0;
+    let l = color.lightness / 100.0;
+
+    // Value is the lightness plus the chroma contribution above it
+    let value = l + s * l.min(1.0 - l);
+    let saturation = if value == 0.0 {
+        0.0
+    } else {
+        2.0 * (1.0 - l / value)
+    };
+
+    Ok(Hsv {
+        hue: color.hue.rem_euclid(360.0),
+        saturation: saturation * 100.0,
+        value: value * 100.0,
+        alpha: color.alpha,
     })
 }
+
+/// Converts an `Hsv` color directly to the HSL color space.
+///
+/// # Errors
+/// Returns `InvalidComponentValue` if saturation or value are outside
+/// the 0–100 range.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::{Hsv, parse_hsv_to_hsl};
+///
+/// let hsv = Hsv { hue: 30.0, saturation: 100.0, value: 100.0, alpha: 1.0 };
+/// let hsl = parse_hsv_to_hsl(&hsv).unwrap();
+/// assert_eq!(hsl.saturation, 100.0);
+/// assert_eq!(hsl.lightness, 50.0);
+/// ```
+pub fn parse_hsv_to_hsl(color: &Hsv) -> Result<Hsl, ColorParserError> {
+    // Check that HSV values are within valid range
+    check_hue(color.hue)?;
+    check_range(color.saturation, 100.0)?;
+    check_range(color.value, 100.0)?;
+
+    let s = color.saturation / 100.0;
+    let v = color.value / 100.0;
+
+    let lightness = v * (1.0 - s / 2.0);
+    let saturation = if lightness == 0.0 || lightness == 1.0 {
+        0.0
+    } else {
+        (v - lightness) / lightness.min(1.0 - lightness)
+    };
+
+    Ok(Hsl {
+        hue: color.hue.rem_euclid(360.0),
+        saturation: saturation * 100.0,
+        lightness: lightness * 100.0,
+        alpha: color.alpha,
+    })
+}
+
+/// Ensures a hue angle is a finite number.
+fn check_hue(hue: f64) -> Result<(), ColorParserError> {
+    if !hue.is_finite() {
+        return Err(component_error(hue, "a finite hue angle"));
+    }
+    Ok(())
+}
+
+/// Ensures a component lies within `[0, max]` (which also rejects NaN).
+fn check_range(value: f64, max: f64) -> Result<(), ColorParserError> {
+    match component_expectation(value, Some(max)) {
+        Some(expected) => Err(component_error(value, &expected)),
+        None => Ok(()),
+    }
+}
+
+/// Returns the accepted form of a component that is not finite, or that lies
+/// outside `[0, max]` when there is a `max`; `None` if the value is valid.
+pub(crate) fn component_expectation(value: f64, max: Option<f64>) -> Option<String> {
+    match max {
+        None if !value.is_finite() => Some("a finite number".to_string()),
+        Some(max) if !(0.0..=max).contains(&value) => Some(format!("a number from 0 to {max}")),
+        _ => None,
+    }
+}
+
+/// Builds an `InvalidComponentValue` conversion error, naming the offending
+/// value in the expected note since there is no input to point at.
+pub(crate) fn component_error(value: f64, expected: &str) -> ColorParserError {
+    ColorParserError::from(ErrorKind::InvalidComponentValue)
+        .expecting(&[&format!("{expected}, not {value}")])
+}
+
+/// Converts HSL components to gamma-encoded sRGB channels in the [0, 1] range.
+///
+/// `hue` is in degrees, `saturation` and `lightness` are fractions in [0, 1].
+/// Uses the algorithm from the CSS Color Level 4 specification.
+pub(crate) fn hsl_to_rgb(hue: f64, saturation: f64, lightness: f64) -> (f64, f64, f64) {
+    let hue = hue.rem_euclid(360.0);
+    let a = saturation * lightness.min(1.0 - lightness);
+
+    let channel = |n: f64| {
+        let k = (n + hue / 30.0) % 12.0;
+        lightness - a * (k - 3.0).min(9.0 - k).clamp(-1.0, 1.0)
+    };
+
+    (channel(0.0), channel(8.0), channel(4.0))
+}
+
+/// Converts HSV components to gamma-encoded sRGB channels in the [0, 1] range.
+///
+/// `hue` is in degrees, `saturation` and `value` are fractions in [0, 1].
+pub(crate) fn hsv_to_rgb(hue: f64, saturation: f64, value: f64) -> (f64, f64, f64) {
+    let hue = hue.rem_euclid(360.0);
+
+    let channel = |n: f64| {
+        let k = (n + hue / 60.0) % 6.0;
+        value - value * saturation * k.min(4.0 - k).clamp(0.0, 1.0)
+    };
+
+    (channel(5.0), channel(3.0), channel(1.0))
+}
+
+/// Converts a [0, 1] channel value to a [0, 255] byte, clamping and rounding.
+pub(crate) fn unit_to_u8(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
@@ -1,20 +1,58 @@
 //! A simple CLI application for parsing and displaying color values in various formats.
 //!
-//! This application accepts a hex color code from the command line (e.g., `#FFAA00` or `ffaa00`),
-//! converts it to RGBA, and then prints its equivalent in HSL, HSV, and CMYK formats.
+//! This application accepts a color from the command line (e.g., `#FFAA00`, `ffaa00`,
+//! `rebeccapurple` or `"rgb(255 136 0)"`), converts it to RGBA, and then prints its
+//! equivalent in HSL, HSV, and CMYK formats.
+//!
+//! Subcommands:
+//! - `--order rgba|argb|bgra|abgr <color>` describes a color whose hex digits
+//!   are in another channel order, such as Android's `#AARRGGBB`
+//! - `diff <color> <color>` prints the perceptual difference between two colors
+//! - `contrast <fg> <bg>` prints the WCAG contrast ratio and APCA Lc, and renders
+//!   sample text
+//! - `--cvd [--severity <0-1>] <color>...` prints swatches of each color as seen
+//!   with protan, deutan and tritan color vision deficiencies
+//! - `scale [--steps <n>] [--mode <space>] [--bezier] [--correct-lightness] <color>...`
+//!   prints a gradient through the colors and swatches sampled from it
+//! - `harmony <color> [--scheme <name>] [--oklch]` prints color harmonies of a
+//!   color, or only the given scheme
+//! - `ramp <color> [--name <name>] [--format css|tailwind]` prints a 50–950
+//!   ramp around a color as CSS custom properties or a Tailwind config entry
+//! - `--apply <operations> [--oklch] <color>...` applies a comma-separated
+//!   pipeline of adjustments such as `lighten:10,spin:30,grayscale` and prints
+//!   each step
 //!
 //! # Usage
 //! ```bash
 //! cargo run -- #ffaa00
 //! cargo run -- ff8800
+//! cargo run -- 0xff8800
+//! cargo run -- --order argb 80ff8800
+//! cargo run -- ffff88000001
+//! cargo run -- cornflowerblue
+//! cargo run -- diff #ff8800 #ff8a00
+//! cargo run -- contrast white rebeccapurple
+//! cargo run -- --cvd red green "#ff8800"
+//! cargo run -- scale --steps 7 --bezier --correct-lightness yellow 008ae5 navy
+//! cargo run -- harmony ff8800 --scheme triadic
+//! cargo run -- ramp ff8800 --name brand --format tailwind
+//! cargo run -- --apply lighten:10,spin:30,fade:0.5 --oklch "#ff8800"
 //! ```
 //!
 //! # Dependencies
 //! - `color_parser` — your local crate/module for color conversions
 //! - `owo-colors` — for terminal color preview output
 
+use color_parser::adjust::{ColorModel, Luma};
+use color_parser::contrast::{Polarity, TextSize, WcagLevel};
+use color_parser::cvd::{CvdMethod, Deficiency};
+use color_parser::delta_e::{Cie94Application, DeltaE};
+use color_parser::harmony::Harmony;
+use color_parser::mix::{HueInterpolation, InterpolationSpace};
+use color_parser::ramp::Ramp;
+use color_parser::scale::Scale;
 use color_parser::{
-    ColorParserError, parse_hex_to_rgba, parse_rgb_to_cmyk, parse_rgb_to_hsl, parse_rgb_to_hsv,
+    ChannelOrder, Cmyk, Color, ColorParserError, ErrorKind, Hsl, Hsv, Oklch, ParseOptions, Rgba,
 };
 use owo_colors::OwoColorize;
 use std::{env, process};
@@ -22,76 +60,91 @@ use std::{env, process};
 /// Entry point for the application.
 ///
 /// If an error occurs during execution, the app prints an error message
-/// and exits with code 1.
+/// and exits with code 1. Parse errors are rendered with the offending part
+/// of the input underlined.
 fn main() {
     if let Err(e) = run() {
-        eprintln!("{} {}", "❌ Error:".red(), e.to_string().red()); // Print error message
+        match e.downcast_ref::<ColorParserError>() {
+            Some(e) => eprint!("{}", e.render().red()),
+            None => eprintln!("{} {}", "❌ Error:".red(), e.to_string().red()), // Print error message
+        }
         process::exit(1); // Exit with error code
     }
 }
 
-/// Orchestrates the CLI tool logic:
-/// 1. Parses the color argument.
-/// 2. Converts it to RGBA.
-/// 3. Converts and prints HSL, HSV, and CMYK representations.
-/// 4. Displays a color swatch preview in the terminal.
+/// Dispatches to a subcommand, or describes a single color by default.
 fn run() -> Result<(), Box<dyn std::error::Error>> {
-    // Parse the command-line arguments
-    let color_hex = get_color_argument()?;
-
-    // Parse the hex color to RGBA
-    let rgba_color = parse_hex_to_rgba(&color_hex)?;
-
-    // Parse RGB to HSL
-    let hsl_color = parse_rgb_to_hsl(&rgba_color)?;
+    // Collect command-line arguments into a vector
+    let args: Vec<String> = env::args().collect();
 
-    // Parse RGB to HSV
-    let hsv_color = parse_rgb_to_hsv(&rgba_color)?;
+    match args.get(1).map(String::as_str) {
+        Some("diff") if args.len() == 4 => diff(&args[2], &args[3]),
+        Some("contrast") if args.len() == 4 => contrast(&args[2], &args[3]),
+        Some("--cvd") if args.len() > 2 => simulate_cvd(&args[2..]),
+        Some("scale") if args.len() > 3 => scale(&args[2..]),
+        Some("harmony") if args.len() > 2 => harmony(&args[2..]),
+        Some("ramp") if args.len() > 2 => ramp(&args[2..]),
+        Some("--apply") if args.len() > 3 => apply(&args[2], &args[3..]),
+        Some("--order") if args.len() == 4 => {
+            let order = ChannelOrder::from_name(&args[2])
+                .ok_or("--order must be rgba, argb, bgra or abgr")?;
+            describe(&args[3], order)
+        }
+        _ if args.len() == 2 => describe(&args[1], ChannelOrder::Rgba),
+        _ => print_usage(&args[0]),
+    }
+}
 
-    // Parse RGB to CMYK
-    let cmyk_color = parse_rgb_to_cmyk(&rgba_color)?;
+/// Describes a single color:
+/// 1. Parses the color argument, reading hex digits in `order`.
+/// 2. Converts it to RGBA.
+/// 3. Converts and prints HSL, HSV, and CMYK representations.
+/// 4. Displays a color swatch preview in the terminal.
+fn describe(color_input: &str, order: ChannelOrder) -> Result<(), Box<dyn std::error::Error>> {
+    // Parse the color to RGBA
+    let color = parse_ordered(color_input, order)?;
+    let rgba_color = color.to_rgba();
 
-    // Create swatch using the actual RGB color
-    let color_preview = "      ".on_truecolor(rgba_color.red, rgba_color.green, rgba_color.blue);
+    // Convert RGB to HSL, HSV and CMYK
+    let hsl_color: Hsl = rgba_color.into();
+    let hsv_color: Hsv = rgba_color.into();
+    let cmyk_color: Cmyk = rgba_color.into();
 
-    println!("\n Hex Input: #{color}\n", color = color_hex.to_uppercase());
-    println!("🎨  Color: {}", color_preview);
+    println!("\n Input: {color_input}");
+    match color {
+        Color::Rgba16(wide) => {
+            println!(" Hex:   {} (16-bit)", wide.to_hex_in(order).to_uppercase());
+            println!(" 8-bit: {}", rgba_color.to_hex_in(order).to_uppercase());
+        }
+        _ => println!(" Hex:   {}", rgba_color.to_hex_in(order).to_uppercase()),
+    }
     println!(
-        "\n🌈  RGBA: rgba({}, {}, {})",
-        rgba_color.red, rgba_color.green, rgba_color.blue
+        " u32:   0x{:08X} ({})",
+        rgba_color.to_u32(order),
+        order.name()
     );
+    if let Some(name) = rgba_color.name() {
+        println!(" Name:  {name}");
+    }
+    println!();
+    println!("🎨  Color: {}", swatch(&rgba_color));
+    println!("\n🌈  RGBA: {rgba_color:#}");
     println!("    → Red:   {}", rgba_color.red);
     println!("    → Green: {}", rgba_color.green);
     println!("    → Blue:  {}", rgba_color.blue);
     println!("    → Alpha: {}", rgba_color.alpha);
 
-    println!(
-        "\n🌈  HSL: hsl({}°, {}%, {}%)",
-        hsl_color.hue.round(),
-        hsl_color.saturation.round(),
-        hsl_color.lightness.round()
-    );
+    println!("\n🌈  HSL: {hsl_color:.0}");
     println!("    → Hue:        {}°", hsl_color.hue.round());
     println!("    → Saturation: {}%", hsl_color.saturation.round());
     println!("    → Lightness:  {}%", hsl_color.lightness.round());
 
-    println!(
-        "\n🌈  HSV: hsv({}°, {}%, {}%)",
-        hsv_color.hue.round(),
-        hsv_color.saturation.round(),
-        hsv_color.value.round()
-    );
+    println!("\n🌈  HSV: {hsv_color:.0}");
     println!("    → Hue:        {}°", hsv_color.hue.round());
     println!("    → Saturation: {}%", hsv_color.saturation.round());
     println!("    → value:      {}%", hsv_color.value.round());
 
-    println!(
-        "\n🌈  CMYK: CMYK({}%, {}%, {}%, {}%)",
-        cmyk_color.cyan.round(),
-        cmyk_color.magenta.round(),
-        cmyk_color.yellow.round(),
-        cmyk_color.black.round(),
-    );
+    println!("\n🌈  CMYK: {cmyk_color:.0}");
     println!("    → Cyan:    {}%", cmyk_color.cyan.round());
     println!("    → Magenta: {}%", cmyk_color.magenta.round());
     println!("    → Yellow:  {}%", cmyk_color.yellow.round());
@@ -100,30 +153,368 @@ fn run() -> Result<(), Box<dyn std::error::Error>> {
     Ok(())
 }
 
-/// Retrieves and validates the hex color argument from the command line.
+/// Prints the perceptual difference between two colors with every ΔE formula.
+fn diff(reference_input: &str, sample_input: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let reference = parse_color(reference_input)?;
+    let sample = parse_color(sample_input)?;
+
+    let formulas = [
+        ("ΔE76", DeltaE::Cie76),
+        ("ΔE94", DeltaE::Cie94(Cie94Application::GraphicArts)),
+        ("ΔE2000", DeltaE::Cie2000),
+        (
+            "CMC 2:1",
+            DeltaE::Cmc {
+                lightness: 2.0,
+                chroma: 1.0,
+            },
+        ),
+        (
+            "CMC 1:1",
+            DeltaE::Cmc {
+                lightness: 1.0,
+                chroma: 1.0,
+            },
+        ),
+        ("ΔEOK", DeltaE::Ok),
+    ];
+
+    println!("\n Reference: {} {reference:X}", swatch(&reference));
+    println!(" Sample:    {} {sample:X}", swatch(&sample));
+    println!();
+    for (label, formula) in formulas {
+        println!(
+            "    → {:<8} {:.4}",
+            format!("{label}:"),
+            formula.difference(&reference, &sample)
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Prints the WCAG and APCA contrast between a text and a background color,
+/// with sample text rendered in both.
+fn contrast(
+    foreground_input: &str,
+    background_input: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let foreground = parse_color(foreground_input)?;
+    let background = parse_color(background_input)?;
+    let contrast = foreground.wcag_contrast(&background);
+
+    // The terminal cannot blend, so render the composited colors
+    let (fg, bg) = (contrast.foreground, contrast.background);
+    let sample = |text: &str| {
+        format!("  {text}  ")
+            .truecolor(fg.red, fg.green, fg.blue)
+            .on_truecolor(bg.red, bg.green, bg.blue)
+            .to_string()
+    };
+
+    println!("\n Text:       {} {foreground:X}", swatch(&foreground));
+    println!(" Background: {} {background:X}", swatch(&background));
+    println!();
+    println!(
+        "    {}",
+        sample("The quick brown fox jumps over the lazy dog")
+    );
+    println!("    {}", sample("Large text sample").bold());
+
+    println!("\n🔍  Contrast: {:.2}:1", contrast.ratio);
+    let checks = [
+        ("AA normal text: ", WcagLevel::Aa, TextSize::Normal),
+        ("AA large text:  ", WcagLevel::Aa, TextSize::Large),
+        ("AAA normal text:", WcagLevel::Aaa, TextSize::Normal),
+        ("AAA large text: ", WcagLevel::Aaa, TextSize::Large),
+    ];
+    for (label, level, size) in checks {
+        let verdict = if contrast.passes(level, size) {
+            "✅ pass".green().to_string()
+        } else {
+            "❌ fail".red().to_string()
+        };
+        println!(
+            "    → {label} {verdict} (needs {}:1)",
+            level.required_ratio(size)
+        );
+    }
+
+    let apca = foreground.apca_contrast(&background);
+    let polarity = match apca.polarity() {
+        Polarity::DarkOnLight => "dark on light",
+        Polarity::LightOnDark => "light on dark",
+    };
+    println!("\n🔍  APCA: Lc {:.1} ({polarity})", apca.lc);
+    for weight in [300, 400, 700] {
+        match apca.min_font_size(weight) {
+            Some(size) => println!("    → Weight {weight}: {size}px or larger"),
+            None => println!("    → Weight {weight}: not readable as text"),
+        }
+    }
+    println!();
+    Ok(())
+}
+
+/// Prints each color next to its protan, deutan and tritan simulations.
+///
+/// Accepts an optional `--severity <0-1>` before the colors, defaulting to 1
+/// (dichromacy).
+fn simulate_cvd(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let (severity, inputs) = match args {
+        [flag, value, rest @ ..] if flag == "--severity" => (value.parse::<f64>()?, rest),
+        _ => (1.0, args),
+    };
+
+    let deficiencies = [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+
+    println!("\n Severity: {severity}");
+    println!("\n {:<10} Original Protan   Deutan   Tritan", "");
+    for input in inputs {
+        let color = parse_color(input)?;
+        let simulated = deficiencies.map(|deficiency| {
+            swatch(&color.simulate_cvd(deficiency, severity, CvdMethod::Machado))
+        });
+        println!(
+            " {color:X}   {}   {}   {}   {}",
+            swatch(&color),
+            simulated[0],
+            simulated[1],
+            simulated[2]
+        );
+    }
+    println!();
+    Ok(())
+}
+
+/// Prints a gradient through the given colors and evenly spaced samples of it.
 ///
-/// # Returns
-/// A `Result` containing the hex string if valid, or an error if missing.
+/// Options may appear anywhere among the colors: `--steps <n>` (default 9),
+/// `--mode <space>` (a CSS interpolation space such as `lab` or `oklch`),
+/// `--bezier` and `--correct-lightness`.
+fn scale(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut steps = 9;
+    let mut space = InterpolationSpace::default();
+    let (mut bezier, mut correct_lightness) = (false, false);
+    let mut colors = Vec::new();
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--steps" => steps = args.next().ok_or("--steps needs a value")?.parse()?,
+            "--mode" => {
+                let name = args.next().ok_or("--mode needs a value")?;
+                space = InterpolationSpace::from_css_name(name, HueInterpolation::Shorter)
+                    .ok_or_else(|| format!("unknown interpolation space: {name}"))?;
+            }
+            "--bezier" => bezier = true,
+            "--correct-lightness" => correct_lightness = true,
+            input => colors.push(parse_color(input)?),
+        }
+    }
+    if colors.len() < 2 {
+        return Err("a scale needs at least two colors".into());
+    }
+
+    let scale = Scale::new(&colors)
+        .mode(space)
+        .bezier(bezier)
+        .correct_lightness(correct_lightness);
+
+    let gradient: String = scale
+        .colors(48)
+        .iter()
+        .map(|color| {
+            " ".on_truecolor(color.red, color.green, color.blue)
+                .to_string()
+        })
+        .collect();
+    println!("\n {gradient}\n");
+    for color in scale.colors(steps) {
+        println!(" {} {color:X}", swatch(&color));
+    }
+    println!();
+    Ok(())
+}
+
+/// Prints color harmonies of a color as swatches with hex codes.
 ///
-/// # Errors
-/// - Exits the program if no argument is passed.
-/// - Returns `InvalidLength` if the argument is not a valid hex code length.
+/// Prints every scheme unless `--scheme <name>` selects one. Hues are rotated
+/// in HSL, or in Oklch with `--oklch`.
+fn harmony(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut schemes = Harmony::ALL.to_vec();
+    let mut oklch = false;
+    let mut color = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--scheme" => {
+                let name = args.next().ok_or("--scheme needs a value")?;
+                let scheme =
+                    Harmony::from_name(name).ok_or_else(|| format!("unknown scheme: {name}"))?;
+                schemes = vec![scheme];
+            }
+            "--oklch" => oklch = true,
+            input => color = Some(parse_color(input)?),
+        }
+    }
+    let color = color.ok_or("harmony needs a color")?;
+
+    println!("\n Base: {} {color:X}\n", swatch(&color));
+    for scheme in schemes {
+        let palette = if oklch {
+            Oklch::from(color).harmony(scheme)
+        } else {
+            Hsl::from(color).harmony(scheme)
+        };
+        let swatches: Vec<String> = palette.iter().map(swatch).collect();
+        let codes: Vec<String> = palette.iter().map(|color| format!("{color:X}")).collect();
+        println!(" {:<20} {}", scheme.name(), swatches.join(" "));
+        println!(" {:<20} {}", "", codes.join("  "));
+    }
+    println!();
+    Ok(())
+}
+
+/// Prints a 50–950 ramp around a color, followed by the ramp as CSS custom
+/// properties (`--format css`, the default) or a Tailwind config entry
+/// (`--format tailwind`). `--name <name>` sets the property or entry name.
+fn ramp(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let mut name = String::from("color");
+    let mut tailwind = false;
+    let mut color = None;
+
+    let mut args = args.iter();
+    while let Some(arg) = args.next() {
+        match arg.as_str() {
+            "--name" => name = args.next().ok_or("--name needs a value")?.clone(),
+            "--format" => match args.next().map(String::as_str) {
+                Some("css") => tailwind = false,
+                Some("tailwind") => tailwind = true,
+                _ => return Err("--format must be css or tailwind".into()),
+            },
+            input => color = Some(parse_color(input)?),
+        }
+    }
+    let ramp = Ramp::new(&color.ok_or("ramp needs a color")?);
+
+    println!();
+    for (step, color) in &ramp.colors {
+        let marker = if *step == ramp.anchor { "◀" } else { "" };
+        println!(" {step:>4} {} {color:X} {marker}", swatch(color));
+    }
+    println!();
+    if tailwind {
+        print!("{}", ramp.to_tailwind(&name));
+    } else {
+        print!("{}", ramp.to_css_properties(&name));
+    }
+    println!();
+    Ok(())
+}
+
+/// Applies a pipeline of adjustments to each color, printing every step.
 ///
-/// # Examples
-/// ```bash
-/// cargo run -- #ffaa00
-/// cargo run -- ffcc00
-/// ```
-fn get_color_argument() -> Result<String, ColorParserError> {
-    // Collect command-line arguments into a vector
-    let args: Vec<String> = env::args().collect();
+/// `operations` is a comma-separated list of `name[:value]` steps: `lighten`,
+/// `darken`, `saturate` and `desaturate` take percentage points, `spin` takes
+/// degrees, `fade` takes an opacity in [0, 1], `grayscale` optionally takes
+/// `601` or `709`, and `complement` and `invert` take no value. `--oklch`
+/// among the colors makes the adjustments work in Oklch instead of HSL.
+fn apply(operations: &str, args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let operations = operations
+        .split(',')
+        .map(|operation| match operation.trim().split_once(':') {
+            Some((name, value)) => Ok((name, Some(value.parse::<f64>()?))),
+            None => Ok((operation.trim(), None)),
+        })
+        .collect::<Result<Vec<_>, std::num::ParseFloatError>>()?;
+
+    let model = if args.iter().any(|arg| arg == "--oklch") {
+        ColorModel::Oklch
+    } else {
+        ColorModel::Hsl
+    };
+
+    println!();
+    for input in args.iter().filter(|arg| *arg != "--oklch") {
+        let mut color = parse_color(input)?;
+        println!(" {} {color:X}", swatch(&color));
+
+        for &(name, value) in &operations {
+            color = match (name, value) {
+                ("lighten", Some(amount)) => color.lighten(amount, model),
+                ("darken", Some(amount)) => color.darken(amount, model),
+                ("saturate", Some(amount)) => color.saturate(amount, model),
+                ("desaturate", Some(amount)) => color.desaturate(amount, model),
+                ("spin", Some(degrees)) => color.spin(degrees, model),
+                ("complement", None) => color.complement(model),
+                ("invert", None) => color.invert(),
+                ("grayscale", None) => color.grayscale(Luma::Rec709),
+                ("grayscale", Some(601.0)) => color.grayscale(Luma::Rec601),
+                ("grayscale", Some(709.0)) => color.grayscale(Luma::Rec709),
+                ("fade", Some(opacity)) => color.fade(opacity),
+                _ => return Err(format!("invalid operation: {name}").into()),
+            };
+            let label = match value {
+                Some(value) => format!("{name} {value}"),
+                None => name.to_string(),
+            };
+            println!(" {} {color:X} ← {label}", swatch(&color));
+        }
+        println!();
+    }
+    Ok(())
+}
+
+/// Creates a terminal swatch using the actual RGB color.
+fn swatch(color: &Rgba) -> String {
+    "      "
+        .on_truecolor(color.red, color.green, color.blue)
+        .to_string()
+}
+
+/// Parses a color argument into `Rgba`.
+///
+/// The CLI is forgiving: hex colors may omit the `#`, use `0x` instead
+/// (`0xff8800`) and have surrounding whitespace, and 12- or 16-digit hex is
+/// rounded to 8 bits per channel; anything else goes through the CSS color
+/// parser, which also resolves named colors such as `rebeccapurple`.
+fn parse_color(input: &str) -> Result<Rgba, ColorParserError> {
+    parse_ordered_color(input, ChannelOrder::Rgba)
+}
+
+/// Parses a color argument into `Rgba` like [`parse_color`], reading hex
+/// digits in `order`.
+fn parse_ordered_color(input: &str, order: ChannelOrder) -> Result<Rgba, ColorParserError> {
+    Ok(parse_ordered(input, order)?.to_rgba())
+}
 
-    // Ensure that the user passed exactly one argument (besides the program name)
-    if args.len() != 2 {
-        eprintln!("Usage: {} <hex-color>", args[0]); // Print usage error
-        eprintln!("Example: {} fff or {} #ffcc00", args[0], args[0]);
-        std::process::exit(1); // Exit with error code 1
+/// Parses a color argument, reading hex digits in `order`. 12- and 16-digit
+/// hex colors are kept at 16 bits per channel, as `Color::Rgba16`.
+fn parse_ordered(input: &str, order: ChannelOrder) -> Result<Color, ColorParserError> {
+    let options = ParseOptions::lenient().order(order);
+    match options.parse(input) {
+        Err(error) if error.kind == ErrorKind::InvalidLength => {
+            options.parse_hex16(input).map(Color::Rgba16)
+        }
+        result => result,
     }
+}
 
-    Ok(args[1].clone())
+/// Prints usage information and exits with code 1.
+fn print_usage(program: &str) -> ! {
+    eprintln!("Usage: {program} <color>");
+    eprintln!("       {program} --order rgba|argb|bgra|abgr <color>");
+    eprintln!("       {program} diff <color> <color>");
+    eprintln!("       {program} contrast <fg> <bg>");
+    eprintln!("       {program} --cvd [--severity <0-1>] <color>...");
+    eprintln!(
+        "       {program} scale [--steps <n>] [--mode <space>] [--bezier] [--correct-lightness] <color>..."
+    );
+    eprintln!("       {program} harmony <color> [--scheme <name>] [--oklch]");
+    eprintln!("       {program} ramp <color> [--name <name>] [--format css|tailwind]");
+    eprintln!("       {program} --apply <operations> [--oklch] <color>...");
+    eprintln!("Example: {program} fff or {program} #ffcc00 or {program} rebeccapurple");
+    process::exit(1); // Exit with error code 1
 }
@@ -0,0 +1,60 @@
+//! Small 3×3 matrix helpers used by the color space conversions.
+
+/// A row-major 3×3 matrix.
+pub(crate) type Matrix3 = [[f64; 3]; 3];
+
+/// Multiplies a matrix by a column vector.
+pub(crate) fn multiply(m: &Matrix3, v: [f64; 3]) -> [f64; 3] {
+    [
+        m[0][0] * v[0] + m[0][1] * v[1] + m[0][2] * v[2],
+        m[1][0] * v[0] + m[1][1] * v[1] + m[1][2] * v[2],
+        m[2][0] * v[0] + m[2][1] * v[1] + m[2][2] * v[2],
+    ]
+}
+
+/// Multiplies two matrices.
+pub(crate) fn multiply_matrices(a: &Matrix3, b: &Matrix3) -> Matrix3 {
+    let mut out = [[0.0; 3]; 3];
+    for (i, row) in out.iter_mut().enumerate() {
+        for (j, cell) in row.iter_mut().enumerate() {
+            *cell = a[i][0] * b[0][j] + a[i][1] * b[1][j] + a[i][2] * b[2][j];
+        }
+    }
+    out
+}
+
+/// Builds a diagonal matrix from its three diagonal entries.
+pub(crate) fn diagonal(d: [f64; 3]) -> Matrix3 {
+    [[d[0], 0.0, 0.0], [0.0, d[1], 0.0], [0.0, 0.0, d[2]]]
+}
+
+/// Inverts a matrix using its adjugate.
+///
+/// Every matrix used by this crate is well conditioned, so the determinant is
+/// never zero; custom white points are checked by `WhitePoint::custom`.
+pub(crate) fn invert(m: &Matrix3) -> Matrix3 {
+    let cofactor =
+        |r0: usize, r1: usize, c0: usize, c1: usize| m[r0][c0] * m[r1][c1] - m[r0][c1] * m[r1][c0];
+
+    let adjugate = [
+        [
+            cofactor(1, 2, 1, 2),
+            -cofactor(0, 2, 1, 2),
+            cofactor(0, 1, 1, 2),
+        ],
+        [
+            -cofactor(1, 2, 0, 2),
+            cofactor(0, 2, 0, 2),
+            -cofactor(0, 1, 0, 2),
+        ],
+        [
+            cofactor(1, 2, 0, 1),
+            -cofactor(0, 2, 0, 1),
+            cofactor(0, 1, 0, 1),
+        ],
+    ];
+    let determinant =
+        m[0][0] * adjugate[0][0] + m[0][1] * adjugate[1][0] + m[0][2] * adjugate[2][0];
+
+    adjugate.map(|row| row.map(|value| value / determinant))
+}
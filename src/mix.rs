@@ -0,0 +1,450 @@
+//! Interpolation between two colors, and the CSS `color-mix()` function.
+//!
+//! Mixing follows CSS Color Level 4 interpolation: both colors are converted to
+//! the interpolation space, their non-hue components are premultiplied by alpha
+//! and interpolated linearly, and the result is divided by the interpolated
+//! alpha again. Premultiplying keeps a transparent color from dragging the mix
+//! toward its (invisible) channels.
+//!
+//! In polar spaces the hue takes the path selected by [`HueInterpolation`], and
+//! a color without a meaningful hue (a gray) takes the hue of the other color.
+
+use std::ops::Range;
+
+use crate::error::offset_of;
+use crate::{
+    Color, ColorParserError, ColorSpace, ErrorKind, Hsl, Hsv, Lab, Lch, LinearRgb, Oklab, Oklch,
+    Srgb, WhitePoint, hsv_to_rgb, parse_css_color,
+};
+
+/// Chroma below which an Lch hue is considered powerless
+const LCH_ACHROMATIC: f64 = 1e-4;
+/// Chroma below which an Oklch hue is considered powerless
+const OKLCH_ACHROMATIC: f64 = 1e-6;
+/// Saturation (as a percentage) below which an HSL hue is considered powerless
+const HSL_ACHROMATIC: f64 = 1e-4;
+
+/// The path taken around the hue circle when interpolating a polar space.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum HueInterpolation {
+    /// Takes the shorter arc, at most 180°
+    #[default]
+    Shorter,
+    /// Takes the longer arc, at least 180°
+    Longer,
+    /// Moves toward increasing hue angles
+    Increasing,
+    /// Moves toward decreasing hue angles
+    Decreasing,
+}
+
+/// The color space two colors are interpolated in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum InterpolationSpace {
+    /// Gamma-encoded sRGB
+    Srgb,
+    /// Linear-light sRGB
+    SrgbLinear,
+    /// CIELAB relative to D50
+    Lab,
+    /// Oklab, the CSS default
+    #[default]
+    Oklab,
+    /// CIELCh relative to D50
+    Lch(HueInterpolation),
+    /// Oklch
+    Oklch(HueInterpolation),
+    /// HSL
+    Hsl(HueInterpolation),
+    /// HWB; the result is returned as sRGB
+    Hwb(HueInterpolation),
+}
+
+impl InterpolationSpace {
+    /// Looks up a space by its CSS name, such as `srgb-linear` or `oklch`
+    /// (case-insensitive). `hue` is only used by polar spaces.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::mix::{HueInterpolation, InterpolationSpace};
+    ///
+    /// let space = InterpolationSpace::from_css_name("OkLch", HueInterpolation::Longer);
+    /// assert_eq!(space, Some(InterpolationSpace::Oklch(HueInterpolation::Longer)));
+    /// ```
+    pub fn from_css_name(name: &str, hue: HueInterpolation) -> Option<InterpolationSpace> {
+        let space = match name.to_ascii_lowercase().as_str() {
+            "srgb" => InterpolationSpace::Srgb,
+            "srgb-linear" => InterpolationSpace::SrgbLinear,
+            "lab" => InterpolationSpace::Lab,
+            "oklab" => InterpolationSpace::Oklab,
+            "lch" => InterpolationSpace::Lch(hue),
+            "oklch" => InterpolationSpace::Oklch(hue),
+            "hsl" => InterpolationSpace::Hsl(hue),
+            "hwb" => InterpolationSpace::Hwb(hue),
+            _ => return None,
+        };
+        Some(space)
+    }
+
+    /// Returns the hue interpolation method of a polar space, or `None`.
+    fn hue_interpolation(&self) -> Option<HueInterpolation> {
+        match *self {
+            InterpolationSpace::Lch(method)
+            | InterpolationSpace::Oklch(method)
+            | InterpolationSpace::Hsl(method)
+            | InterpolationSpace::Hwb(method) => Some(method),
+            _ => None,
+        }
+    }
+}
+
+/// Interpolates between two colors, where `t = 0` gives `a` and `t = 1` gives `b`.
+///
+/// `t` is clamped to [0, 1]. The result is expressed in the interpolation space,
+/// except for HWB which has no color type and is returned as [`Color::Srgb`].
+///
+/// # Examples
+/// ```rust
+/// use color_parser::mix::{HueInterpolation, InterpolationSpace, mix};
+/// use color_parser::{Color, Rgba};
+///
+/// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+/// let blue = Rgba { red: 0, green: 0, blue: 255, alpha: 255 };
+///
+/// let purple = mix(&red, &blue, 0.5, InterpolationSpace::Srgb);
+/// assert_eq!(purple.to_rgba(), Rgba { red: 128, green: 0, blue: 128, alpha: 255 });
+///
+/// // Red (0°) to blue (240°) the short way passes through magenta (300°)
+/// let space = InterpolationSpace::Hsl(HueInterpolation::Shorter);
+/// let Color::Hsl(hsl) = mix(&red, &blue, 0.5, space) else { unreachable!() };
+/// assert!((hsl.hue - 300.0).abs() < 1e-9);
+/// ```
+pub fn mix(a: &impl ColorSpace, b: &impl ColorSpace, t: f64, space: InterpolationSpace) -> Color {
+    let (components, alpha) = interpolate(&a.to_srgb(), &b.to_srgb(), t.clamp(0.0, 1.0), space);
+    from_components(components, alpha, space)
+}
+
+/// Interpolates the components of two sRGB colors in `space`, returning the
+/// components and alpha of the result.
+fn interpolate(a: &Srgb, b: &Srgb, t: f64, space: InterpolationSpace) -> ([f64; 3], f64) {
+    let (mut first, mut second) = (to_components(a, space), to_components(b, space));
+    let hue_method = space.hue_interpolation();
+
+    if let Some(method) = hue_method {
+        // A powerless hue takes the other color's hue, so grays don't swing
+        // the mix through unrelated hues
+        match (is_powerless(&first, space), is_powerless(&second, space)) {
+            (true, false) => first[0] = second[0],
+            (false, true) => second[0] = first[0],
+            _ => {}
+        }
+        (first[0], second[0]) = fix_hues(first[0], second[0], method);
+    }
+
+    // Premultiply every component but the hue
+    let premultiplied = |components: &mut [f64; 3], alpha: f64| {
+        let start = usize::from(hue_method.is_some());
+        components[start..].iter_mut().for_each(|c| *c *= alpha);
+    };
+    premultiplied(&mut first, a.alpha);
+    premultiplied(&mut second, b.alpha);
+
+    let alpha = lerp(a.alpha, b.alpha, t);
+    let mut components = [0, 1, 2].map(|i| lerp(first[i], second[i], t));
+    if alpha != 0.0 {
+        premultiplied(&mut components, 1.0 / alpha);
+    }
+    if hue_method.is_some() {
+        components[0] = components[0].rem_euclid(360.0);
+    }
+
+    (components, alpha)
+}
+
+/// Adjusts two hues in degrees so linear interpolation between them follows
+/// the given path.
+fn fix_hues(h1: f64, h2: f64, method: HueInterpolation) -> (f64, f64) {
+    let (h1, h2) = (h1.rem_euclid(360.0), h2.rem_euclid(360.0));
+    let delta = h2 - h1;
+
+    match method {
+        HueInterpolation::Shorter if delta > 180.0 => (h1 + 360.0, h2),
+        HueInterpolation::Shorter if delta < -180.0 => (h1, h2 + 360.0),
+        HueInterpolation::Longer if 0.0 < delta && delta < 180.0 => (h1 + 360.0, h2),
+        HueInterpolation::Longer if -180.0 < delta && delta <= 0.0 => (h1, h2 + 360.0),
+        HueInterpolation::Increasing if h2 < h1 => (h1, h2 + 360.0),
+        HueInterpolation::Decreasing if h1 < h2 => (h1 + 360.0, h2),
+        _ => (h1, h2),
+    }
+}
+
+/// Returns `true` if the hue of polar components carries no information.
+fn is_powerless(components: &[f64; 3], space: InterpolationSpace) -> bool {
+    match space {
+        InterpolationSpace::Lch(_) => components[1] < LCH_ACHROMATIC,
+        InterpolationSpace::Oklch(_) => components[1] < OKLCH_ACHROMATIC,
+        InterpolationSpace::Hsl(_) => components[1] < HSL_ACHROMATIC,
+        InterpolationSpace::Hwb(_) => components[1] + components[2] >= 1.0 - 1e-9,
+        _ => false,
+    }
+}
+
+/// Expresses a color as the three components of `space`, hue first for polar spaces.
+fn to_components(color: &Srgb, space: InterpolationSpace) -> [f64; 3] {
+    match space {
+        InterpolationSpace::Srgb => [color.red, color.green, color.blue],
+        InterpolationSpace::SrgbLinear => {
+            let linear = LinearRgb::from_srgb(*color);
+            [linear.red, linear.green, linear.blue]
+        }
+        InterpolationSpace::Lab => {
+            let lab = Lab::from_srgb(*color);
+            [lab.lightness, lab.a, lab.b]
+        }
+        InterpolationSpace::Oklab => {
+            let oklab = Oklab::from_srgb(*color);
+            [oklab.lightness, oklab.a, oklab.b]
+        }
+        InterpolationSpace::Lch(_) => {
+            let lch = Lch::from_srgb(*color);
+            [lch.hue, lch.chroma, lch.lightness]
+        }
+        InterpolationSpace::Oklch(_) => {
+            let oklch = Oklch::from_srgb(*color);
+            [oklch.hue, oklch.chroma, oklch.lightness]
+        }
+        InterpolationSpace::Hsl(_) => {
+            let hsl = Hsl::from_srgb(*color);
+            [hsl.hue, hsl.saturation, hsl.lightness]
+        }
+        InterpolationSpace::Hwb(_) => {
+            // Whiteness and blackness as fractions, derived from HSV
+            let hsv = Hsv::from_srgb(*color);
+            let (saturation, value) = (hsv.saturation / 100.0, hsv.value / 100.0);
+            [hsv.hue, (1.0 - saturation) * value, 1.0 - value]
+        }
+    }
+}
+
+/// Builds a [`Color`] from the components produced by [`to_components`].
+fn from_components(components: [f64; 3], alpha: f64, space: InterpolationSpace) -> Color {
+    let [x, y, z] = components;
+    match space {
+        InterpolationSpace::Srgb => Color::Srgb(Srgb {
+            red: x,
+            green: y,
+            blue: z,
+            alpha,
+        }),
+        InterpolationSpace::SrgbLinear => Color::LinearRgb(LinearRgb {
+            red: x,
+            green: y,
+            blue: z,
+            alpha,
+        }),
+        InterpolationSpace::Lab => Color::Lab(Lab {
+            lightness: x,
+            a: y,
+            b: z,
+            alpha,
+            white_point: WhitePoint::D50,
+        }),
+        InterpolationSpace::Oklab => Color::Oklab(Oklab {
+            lightness: x,
+            a: y,
+            b: z,
+            alpha,
+        }),
+        InterpolationSpace::Lch(_) => Color::Lch(Lch {
+            lightness: z,
+            chroma: y,
+            hue: x,
+            alpha,
+            white_point: WhitePoint::D50,
+        }),
+        InterpolationSpace::Oklch(_) => Color::Oklch(Oklch {
+            lightness: z,
+            chroma: y,
+            hue: x,
+            alpha,
+        }),
+        InterpolationSpace::Hsl(_) => Color::Hsl(Hsl {
+            hue: x,
+            saturation: y,
+            lightness: z,
+            alpha,
+        }),
+        InterpolationSpace::Hwb(_) => {
+            let (red, green, blue) = hwb_to_rgb(x, y, z);
+            Color::Srgb(Srgb {
+                red,
+                green,
+                blue,
+                alpha,
+            })
+        }
+    }
+}
+
+/// Converts HWB components (whiteness and blackness as fractions) to sRGB.
+fn hwb_to_rgb(hue: f64, whiteness: f64, blackness: f64) -> (f64, f64, f64) {
+    if whiteness + blackness >= 1.0 {
+        let gray = whiteness / (whiteness + blackness);
+        return (gray, gray, gray);
+    }
+
+    let value = 1.0 - blackness;
+    hsv_to_rgb(hue, 1.0 - whiteness / value, value)
+}
+
+/// Linearly interpolates between `a` and `b`.
+fn lerp(a: f64, b: f64, t: f64) -> f64 {
+    a + (b - a) * t
+}
+
+/// Parses the arguments of a CSS `color-mix()` function, e.g.
+/// `in oklch longer hue, red 30%, blue`.
+///
+/// Missing percentages default so the two add up to 100%. Percentages adding
+/// up to more than 100% are scaled down; adding up to less scales the alpha of
+/// the result instead.
+pub(crate) fn parse_color_mix(args: &str) -> Result<Color, ColorParserError> {
+    let parts = split_top_level(args);
+    let [space, first, second] = parts.as_slice() else {
+        let span = match parts.get(3) {
+            Some(extra) => offset_of(args, extra)..args.len(),
+            None => args.len()..args.len(),
+        };
+        return Err(ColorParserError::at(ErrorKind::InvalidSyntax, span)
+            .expecting(&["an interpolation space and two colors"]));
+    };
+
+    let space = parse_interpolation_space(space).map_err(|e| e.shifted(offset_of(args, space)))?;
+    let (first, p1) = parse_mix_component(first).map_err(|e| e.shifted(offset_of(args, first)))?;
+    let (second, p2) =
+        parse_mix_component(second).map_err(|e| e.shifted(offset_of(args, second)))?;
+
+    let (p1, p2) = match (p1, p2) {
+        (None, None) => (50.0, 50.0),
+        (Some(p1), None) => (p1, 100.0 - p1),
+        (None, Some(p2)) => (100.0 - p2, p2),
+        (Some(p1), Some(p2)) => (p1, p2),
+    };
+    let sum = p1 + p2;
+    if sum == 0.0 {
+        let span = offset_of(args, parts[1])..args.len();
+        return Err(ColorParserError::at(ErrorKind::InvalidSyntax, span)
+            .expecting(&["percentages that add up to more than 0%"]));
+    }
+
+    let (components, alpha) = interpolate(&first.to_srgb(), &second.to_srgb(), p2 / sum, space);
+    let alpha = if sum < 100.0 {
+        alpha * sum / 100.0
+    } else {
+        alpha
+    };
+    Ok(from_components(components, alpha, space))
+}
+
+/// Splits a string on commas that are not nested inside parentheses.
+fn split_top_level(input: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let (mut depth, mut start) = (0usize, 0);
+    for (i, c) in input.char_indices() {
+        match c {
+            '(' => depth += 1,
+            ')' => depth = depth.saturating_sub(1),
+            ',' if depth == 0 => {
+                parts.push(&input[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&input[start..]);
+    parts
+}
+
+/// Parses `in <space> [<method> hue]`; error spans are relative to `input`.
+fn parse_interpolation_space(input: &str) -> Result<InterpolationSpace, ColorParserError> {
+    let words: Vec<&str> = input.split_whitespace().collect();
+    let span_of = |word: &str| offset_of(input, word)..offset_of(input, word) + word.len();
+    let syntax_error = |span: Range<usize>, expected: &[&str]| {
+        ColorParserError::at(ErrorKind::InvalidSyntax, span).expecting(expected)
+    };
+
+    let (space, method) = match words.as_slice() {
+        [keyword, ..] if !keyword.eq_ignore_ascii_case("in") => {
+            return Err(syntax_error(span_of(keyword), &["`in`"]));
+        }
+        [_, space] => (*space, None),
+        [_, space, method, hue] if hue.eq_ignore_ascii_case("hue") => {
+            let method = match method.to_ascii_lowercase().as_str() {
+                "shorter" => HueInterpolation::Shorter,
+                "longer" => HueInterpolation::Longer,
+                "increasing" => HueInterpolation::Increasing,
+                "decreasing" => HueInterpolation::Decreasing,
+                _ => {
+                    return Err(syntax_error(
+                        span_of(method),
+                        &["shorter", "longer", "increasing", "decreasing"],
+                    ));
+                }
+            };
+            (*space, Some(method))
+        }
+        _ => {
+            let trimmed = input.trim();
+            let start = offset_of(input, trimmed);
+            return Err(syntax_error(
+                start..start + trimmed.len(),
+                &["in <space> [<method> hue]"],
+            ));
+        }
+    };
+
+    let space =
+        InterpolationSpace::from_css_name(&space.to_ascii_lowercase(), method.unwrap_or_default())
+            .ok_or_else(|| {
+                ColorParserError::at(ErrorKind::UnknownFunction, span_of(space))
+                    .expecting(&["a color space such as srgb, oklab or oklch"])
+            })?;
+
+    // Only polar spaces have a hue to interpolate
+    if method.is_some() && space.hue_interpolation().is_none() {
+        let span = span_of(words[2]).start..span_of(words[3]).end;
+        return Err(syntax_error(span, &["a polar color space"]));
+    }
+    Ok(space)
+}
+
+/// Parses a color with an optional percentage before or after it; error spans
+/// are relative to `input`.
+fn parse_mix_component(input: &str) -> Result<(Color, Option<f64>), ColorParserError> {
+    let trimmed = input.trim();
+
+    let (color, token) = match (
+        trimmed.rsplit_once(char::is_whitespace),
+        trimmed.split_once(char::is_whitespace),
+    ) {
+        (Some((color, last)), _) if percentage(last).is_some() => (color, Some(last)),
+        (_, Some((first, color))) if percentage(first).is_some() => (color, Some(first)),
+        _ => (trimmed, None),
+    };
+
+    let value = token.and_then(percentage);
+    if let Some(token) = token.filter(|_| value.is_some_and(|v| !(0.0..=100.0).contains(&v))) {
+        let start = offset_of(input, token);
+        return Err(
+            ColorParserError::at(ErrorKind::InvalidSyntax, start..start + token.len())
+                .expecting(&["a percentage from 0% to 100%"]),
+        );
+    }
+    let color = parse_css_color(color).map_err(|e| e.shifted(offset_of(input, color)))?;
+    Ok((color, value))
+}
+
+/// Parses a token such as `25%` into its numeric value.
+fn percentage(token: &str) -> Option<f64> {
+    token.strip_suffix('%')?.parse().ok()
+}
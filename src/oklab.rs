@@ -0,0 +1,174 @@
+//! Oklab and Oklch perceptual color spaces.
+//!
+//! Oklab was designed by Björn Ottosson as a perceptually uniform space that
+//! predicts lightness, chroma and hue better than CIELAB. Conversions go through
+//! linear sRGB using the matrices from the original publication.
+
+use crate::css::css_function;
+use crate::matrix::{Matrix3, multiply};
+use crate::{ColorSpace, LinearRgb, Srgb};
+
+/// Linear sRGB to LMS cone response.
+const LINEAR_SRGB_TO_LMS: Matrix3 = [
+    [0.4122214708, 0.5363325363, 0.0514459929],
+    [0.2119034982, 0.6806995451, 0.1073969566],
+    [0.0883024619, 0.2817188376, 0.6299787005],
+];
+
+/// Non-linear LMS to Oklab.
+const LMS_TO_OKLAB: Matrix3 = [
+    [0.2104542553, 0.7936177850, -0.0040720468],
+    [1.9779984951, -2.4285922050, 0.4505937099],
+    [0.0259040371, 0.7827717662, -0.8086757660],
+];
+
+/// Oklab to non-linear LMS.
+const OKLAB_TO_LMS: Matrix3 = [
+    [1.0, 0.3963377774, 0.2158037573],
+    [1.0, -0.1055613458, -0.0638541728],
+    [1.0, -0.0894841775, -1.2914855480],
+];
+
+/// LMS cone response to linear sRGB.
+const LMS_TO_LINEAR_SRGB: Matrix3 = [
+    [4.0767416621, -3.3077115913, 0.2309699292],
+    [-1.2684380046, 2.6097574011, -0.3413193965],
+    [-0.0041960863, -0.7034186147, 1.7076147010],
+];
+
+/// Chroma below which a color is treated as achromatic.
+const ACHROMATIC_CHROMA: f64 = 1e-6;
+
+/// Represents a color in the Oklab color space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklab {
+    /// Perceptual lightness [0–1]
+    pub lightness: f64,
+    /// Green–red axis, roughly [-0.4–0.4]
+    pub a: f64,
+    /// Blue–yellow axis, roughly [-0.4–0.4]
+    pub b: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+}
+
+/// Represents a color in the Oklch color space, the polar form of Oklab.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Oklch {
+    /// Perceptual lightness [0–1]
+    pub lightness: f64,
+    /// Chroma, roughly [0–0.4]
+    pub chroma: f64,
+    /// Hue in degrees [0–360)
+    pub hue: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+}
+
+impl Oklab {
+    /// Converts a linear sRGB color to Oklab.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{LinearRgb, Oklab};
+    ///
+    /// let white = LinearRgb { red: 1.0, green: 1.0, blue: 1.0, alpha: 1.0 };
+    /// let oklab = Oklab::from_linear_rgb(&white);
+    /// assert!((oklab.lightness - 1.0).abs() < 1e-6);
+    /// ```
+    pub fn from_linear_rgb(color: &LinearRgb) -> Oklab {
+        let lms = multiply(&LINEAR_SRGB_TO_LMS, [color.red, color.green, color.blue]);
+        let [lightness, a, b] = multiply(&LMS_TO_OKLAB, lms.map(f64::cbrt));
+
+        Oklab {
+            lightness,
+            a,
+            b,
+            alpha: color.alpha,
+        }
+    }
+
+    /// Converts this color to linear sRGB.
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        let lms = multiply(&OKLAB_TO_LMS, [self.lightness, self.a, self.b]);
+        let [red, green, blue] = multiply(&LMS_TO_LINEAR_SRGB, lms.map(|c| c.powi(3)));
+
+        LinearRgb {
+            red,
+            green,
+            blue,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts this color to its polar form.
+    ///
+    /// Grays carry tiny rounding noise in `a` and `b`, so their meaningless hue
+    /// is reported as `0`.
+    pub fn to_oklch(&self) -> Oklch {
+        let chroma = self.a.hypot(self.b);
+        let hue = if chroma < ACHROMATIC_CHROMA {
+            0.0
+        } else {
+            self.b.atan2(self.a).to_degrees().rem_euclid(360.0)
+        };
+
+        Oklch {
+            lightness: self.lightness,
+            chroma,
+            hue,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Formats this color as a CSS `oklab()` function.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{Oklab, Rgba};
+    ///
+    /// let oklab = Oklab::from(Rgba { red: 255, green: 0, blue: 0, alpha: 255 });
+    /// assert_eq!(oklab.to_css_string(), "oklab(0.628 0.2249 0.1258)");
+    /// ```
+    pub fn to_css_string(&self) -> String {
+        css_function("oklab", [self.lightness, self.a, self.b], self.alpha)
+    }
+}
+
+impl Oklch {
+    /// Converts this color to its rectangular form.
+    pub fn to_oklab(&self) -> Oklab {
+        let hue = self.hue.to_radians();
+        Oklab {
+            lightness: self.lightness,
+            a: self.chroma * hue.cos(),
+            b: self.chroma * hue.sin(),
+            alpha: self.alpha,
+        }
+    }
+
+    /// Formats this color as a CSS `oklch()` function.
+    pub fn to_css_string(&self) -> String {
+        css_function("oklch", [self.lightness, self.chroma, self.hue], self.alpha)
+    }
+}
+
+impl ColorSpace for Oklab {
+    fn to_srgb(&self) -> Srgb {
+        self.to_linear_rgb().to_srgb()
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        Oklab::from_linear_rgb(&LinearRgb::from_srgb(color))
+    }
+}
+
+impl ColorSpace for Oklch {
+    fn to_srgb(&self) -> Srgb {
+        self.to_oklab().to_srgb()
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        Oklab::from_srgb(color).to_oklch()
+    }
+}
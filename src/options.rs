@@ -0,0 +1,376 @@
+//! Configurable strictness for parsing hex and CSS colors.
+//!
+//! [`ParseOptions`] controls which spellings of a hex color are accepted:
+//! whether the `#` is required, whether `0x` may stand in for it, whether
+//! surrounding whitespace is ignored, whether uppercase digits are allowed,
+//! whether the alpha digits and the 3/4-digit shorthand are optional, and the
+//! [`ChannelOrder`] of the digits. [`ParseOptions::parse_hex16`] also accepts
+//! 12 and 16 digits for 16-bit channels.
+//! [`ParseOptions::strict`] accepts exactly what [`Rgba::to_hex`] produces,
+//! which suits configuration files, while [`ParseOptions::lenient`] accepts
+//! anything a user is likely to type.
+//!
+//! # Examples
+//! ```rust
+//! use color_parser::{ParseOptions, Rgba};
+//!
+//! let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+//! assert_eq!(ParseOptions::lenient().parse_hex(" 0xFF8800 ").unwrap(), orange);
+//! assert_eq!(ParseOptions::strict().parse_hex("#ff8800").unwrap(), orange);
+//! assert!(ParseOptions::strict().parse_hex("ff8800").is_err());
+//!
+//! let options = ParseOptions::new().require_hash(true).reject_shorthand(true);
+//! assert!(options.parse_hex("#f80").is_err());
+//! ```
+
+use crate::error::offset_of;
+use crate::{
+    ChannelOrder, Color, ColorParserError, ErrorKind, Rgba, Rgba16, expand_hex, parse_css_color,
+};
+
+/// Which spellings of a color the parser accepts.
+///
+/// The default options accept an optional `#`, upper- or lowercase digits,
+/// and 3, 4, 6 or 8 digits in RGBA order, as
+/// [`parse_hex_to_rgba`](crate::parse_hex_to_rgba) does; `0x` and surrounding
+/// whitespace are rejected.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ParseOptions {
+    require_hash: bool,
+    allow_0x: bool,
+    allow_whitespace: bool,
+    allow_case_variants: bool,
+    allow_missing_alpha: bool,
+    reject_shorthand: bool,
+    order: ChannelOrder,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            require_hash: false,
+            allow_0x: false,
+            allow_whitespace: false,
+            allow_case_variants: true,
+            allow_missing_alpha: true,
+            reject_shorthand: false,
+            order: ChannelOrder::Rgba,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Creates the default options (see [`ParseOptions`]).
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Accepts only `#rrggbb` and `#rrggbbaa` in lowercase, as produced by
+    /// [`Rgba::to_hex`].
+    pub fn strict() -> Self {
+        Self::new()
+            .require_hash(true)
+            .allow_case_variants(false)
+            .reject_shorthand(true)
+    }
+
+    /// Accepts an optional `#` or `0x`, surrounding whitespace and any case.
+    pub fn lenient() -> Self {
+        Self::new().allow_0x(true).allow_whitespace(true)
+    }
+
+    /// Requires hex colors to start with `#` (or `0x`, if allowed).
+    pub fn require_hash(mut self, enabled: bool) -> Self {
+        self.require_hash = enabled;
+        self
+    }
+
+    /// Accepts `0x` in place of `#`, as in `0xff8800`.
+    pub fn allow_0x(mut self, enabled: bool) -> Self {
+        self.allow_0x = enabled;
+        self
+    }
+
+    /// Ignores leading and trailing whitespace.
+    pub fn allow_whitespace(mut self, enabled: bool) -> Self {
+        self.allow_whitespace = enabled;
+        self
+    }
+
+    /// Accepts uppercase hex digits and `0X`; when disabled, hex colors must
+    /// be lowercase.
+    pub fn allow_case_variants(mut self, enabled: bool) -> Self {
+        self.allow_case_variants = enabled;
+        self
+    }
+
+    /// Accepts hex colors without alpha digits, which are fully opaque; when
+    /// disabled, only `#rrggbbaa` and `#rgba` are accepted.
+    pub fn allow_missing_alpha(mut self, enabled: bool) -> Self {
+        self.allow_missing_alpha = enabled;
+        self
+    }
+
+    /// Rejects the 3- and 4-digit shorthand forms `#rgb` and `#rgba`.
+    pub fn reject_shorthand(mut self, enabled: bool) -> Self {
+        self.reject_shorthand = enabled;
+        self
+    }
+
+    /// Reads hex digits in `order`, such as `#AARRGGBB` for
+    /// [`ChannelOrder::Argb`]. Without alpha digits, the color channels keep
+    /// their relative order.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ChannelOrder, ParseOptions, Rgba};
+    ///
+    /// let options = ParseOptions::new().order(ChannelOrder::Argb);
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 128 };
+    /// assert_eq!(options.parse_hex("#80ff8800").unwrap(), orange);
+    /// assert_eq!(options.parse_hex("#ff8800").unwrap(), Rgba { alpha: 255, ..orange });
+    /// ```
+    pub fn order(mut self, order: ChannelOrder) -> Self {
+        self.order = order;
+        self
+    }
+
+    /// Parses a hexadecimal color string into an `Rgba` struct.
+    ///
+    /// # Errors
+    /// - `InvalidSyntax` for disallowed whitespace, pointing at it, or a
+    ///   missing `#`.
+    /// - `InvalidLength`, spanning the digits, if their number is not allowed.
+    /// - `InvalidCharacter`, pointing at the first character that is not an
+    ///   allowed hex digit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ErrorKind, ParseOptions};
+    ///
+    /// // A single `#` is stripped, so a doubled one is a bad character
+    /// let error = ParseOptions::new().parse_hex("##fff").unwrap_err();
+    /// assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+    /// assert_eq!(error.span, 1..2);
+    ///
+    /// let error = ParseOptions::new().allow_missing_alpha(false).parse_hex("#ff8800").unwrap_err();
+    /// assert_eq!(error.expected, ["4 or 8 hex digits"]);
+    /// ```
+    pub fn parse_hex(&self, input: &str) -> Result<Rgba, ColorParserError> {
+        let hex = self.trim(input)?;
+        self.parse_trimmed_hex(hex)
+            .map_err(|e| e.shifted(offset_of(input, hex)).within(input))
+    }
+
+    /// Parses a hexadecimal color string into an `Rgba16` struct.
+    ///
+    /// Besides the forms accepted by [`parse_hex`](Self::parse_hex), which are
+    /// widened losslessly, this accepts 12 and 16 digits with four per
+    /// channel (`#RRRRGGGGBBBB` and `#RRRRGGGGBBBBAAAA`).
+    ///
+    /// # Errors
+    /// The same as [`parse_hex`](Self::parse_hex).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ParseOptions, Rgba16};
+    ///
+    /// let color = ParseOptions::new().parse_hex16("#ffff88000001").unwrap();
+    /// assert_eq!(color, Rgba16 { red: 0xffff, green: 0x8800, blue: 0x0001, alpha: 0xffff });
+    ///
+    /// let color = ParseOptions::new().parse_hex16("#f80").unwrap();
+    /// assert_eq!(color, Rgba16 { red: 0xffff, green: 0x8888, blue: 0x0000, alpha: 0xffff });
+    /// ```
+    pub fn parse_hex16(&self, input: &str) -> Result<Rgba16, ColorParserError> {
+        let hex = self.trim(input)?;
+        self.parse_trimmed_hex16(hex)
+            .map_err(|e| e.shifted(offset_of(input, hex)).within(input))
+    }
+
+    /// Parses a hex color with these options, or any other CSS color with
+    /// [`parse_css_color`].
+    ///
+    /// Input is treated as hex if it starts with `#` (or an allowed `0x`), or
+    /// consists only of hex digits.
+    ///
+    /// # Errors
+    /// Any error of [`parse_hex`](Self::parse_hex) or [`parse_css_color`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ParseOptions, Rgba};
+    ///
+    /// let options = ParseOptions::lenient();
+    /// let orange = Rgba { red: 255, green: 165, blue: 0, alpha: 255 };
+    /// assert_eq!(options.parse("0xffa500").unwrap().to_rgba(), orange);
+    /// assert_eq!(options.parse(" orange ").unwrap().to_rgba(), orange);
+    /// ```
+    pub fn parse(&self, input: &str) -> Result<Color, ColorParserError> {
+        let trimmed = self.trim(input)?;
+        let result = if self.is_hex(trimmed) {
+            self.parse_trimmed_hex(trimmed).map(Color::Rgba)
+        } else {
+            parse_css_color(trimmed)
+        };
+        result.map_err(|e| e.shifted(offset_of(input, trimmed)).within(input))
+    }
+
+    /// Strips surrounding whitespace, or reports it if it is not allowed.
+    fn trim<'a>(&self, input: &'a str) -> Result<&'a str, ColorParserError> {
+        let trimmed = input.trim();
+        if self.allow_whitespace || trimmed.len() == input.len() {
+            return Ok(trimmed);
+        }
+
+        let leading = input.len() - input.trim_start().len();
+        let span = if leading > 0 {
+            0..leading
+        } else {
+            input.trim_end().len()..input.len()
+        };
+        Err(ColorParserError::new(ErrorKind::InvalidSyntax, input, span)
+            .expecting(&["a color without surrounding whitespace"]))
+    }
+
+    /// Returns `true` if the input should be parsed as a hex color.
+    fn is_hex(&self, input: &str) -> bool {
+        input.starts_with('#')
+            || self.strip_0x(input).is_some()
+            || (!input.is_empty() && input.chars().all(|c| c.is_ascii_hexdigit()))
+    }
+
+    /// Strips an allowed `0x` (or `0X`) prefix.
+    fn strip_0x<'a>(&self, input: &'a str) -> Option<&'a str> {
+        if !self.allow_0x {
+            return None;
+        }
+        input.strip_prefix("0x").or_else(|| {
+            input
+                .strip_prefix("0X")
+                .filter(|_| self.allow_case_variants)
+        })
+    }
+
+    /// Parses a hex color without surrounding whitespace; error spans are
+    /// relative to `hex`.
+    fn parse_trimmed_hex(&self, hex: &str) -> Result<Rgba, ColorParserError> {
+        let digits = self.hex_digits(hex, false)?;
+        let color = expand_hex(digits);
+        Ok(self.digit_order(digits.len()).from_bytes([
+            color.red,
+            color.green,
+            color.blue,
+            color.alpha,
+        ]))
+    }
+
+    /// Parses a hex color with up to 16 digits without surrounding
+    /// whitespace; error spans are relative to `hex`.
+    fn parse_trimmed_hex16(&self, hex: &str) -> Result<Rgba16, ColorParserError> {
+        let digits = self.hex_digits(hex, true)?;
+        if digits.len() <= 8 {
+            return self.parse_trimmed_hex(hex).map(Rgba16::from);
+        }
+
+        // Every character is a hex digit, so each group of four fits in a u16
+        let channel =
+            |i: usize| u16::from_str_radix(&digits[i * 4..i * 4 + 4], 16).unwrap_or_default();
+        let alpha = if digits.len() == 16 {
+            channel(3)
+        } else {
+            u16::MAX
+        };
+        Ok(self
+            .digit_order(digits.len())
+            .from_bytes([channel(0), channel(1), channel(2), alpha]))
+    }
+
+    /// Strips the prefix of a hex color and validates its digits, allowing 12
+    /// and 16 of them if `wide` is set.
+    fn hex_digits<'a>(&self, hex: &'a str, wide: bool) -> Result<&'a str, ColorParserError> {
+        let digits = match hex.strip_prefix('#').or_else(|| self.strip_0x(hex)) {
+            Some(digits) => digits,
+            None if self.require_hash => {
+                let prefixes: &[&str] = if self.allow_0x {
+                    &["`#`", "`0x`"]
+                } else {
+                    &["`#`"]
+                };
+                return Err(
+                    ColorParserError::new(ErrorKind::InvalidSyntax, hex, 0..0).expecting(prefixes)
+                );
+            }
+            None => hex,
+        };
+        let start = hex.len() - digits.len();
+
+        let lengths = self.lengths(wide);
+        if !lengths.contains(&digits.len()) {
+            return Err(
+                ColorParserError::new(ErrorKind::InvalidLength, hex, start..hex.len())
+                    .expecting(&[&digit_counts(&lengths)]),
+            );
+        }
+        // Report the first character that is not an allowed hex digit
+        let allowed = |c: char| {
+            c.is_ascii_digit()
+                || ('a'..='f').contains(&c)
+                || (self.allow_case_variants && ('A'..='F').contains(&c))
+        };
+        let expected = if self.allow_case_variants {
+            "a hex digit (0-9, a-f)"
+        } else {
+            "a lowercase hex digit (0-9, a-f)"
+        };
+        if let Some((i, c)) = digits.char_indices().find(|&(_, c)| !allowed(c)) {
+            let span = start + i..start + i + c.len_utf8();
+            return Err(
+                ColorParserError::new(ErrorKind::InvalidCharacter, hex, span)
+                    .expecting(&[expected]),
+            );
+        }
+        Ok(digits)
+    }
+
+    /// Returns the order of the channels for a number of hex digits; without
+    /// alpha digits, the default alpha comes last.
+    fn digit_order(&self, length: usize) -> ChannelOrder {
+        if has_alpha(length) {
+            self.order
+        } else {
+            self.order.alpha_last()
+        }
+    }
+
+    /// Returns the allowed numbers of hex digits, including 12 and 16 if
+    /// `wide` is set.
+    fn lengths(&self, wide: bool) -> Vec<usize> {
+        let lengths: &[usize] = if wide {
+            &[3, 4, 6, 8, 12, 16]
+        } else {
+            &[3, 4, 6, 8]
+        };
+        lengths
+            .iter()
+            .copied()
+            .filter(|&length| !self.reject_shorthand || length > 4)
+            .filter(|&length| self.allow_missing_alpha || has_alpha(length))
+            .collect()
+    }
+}
+
+/// Returns `true` if a hex color with this many digits has alpha digits.
+fn has_alpha(length: usize) -> bool {
+    matches!(length, 4 | 8 | 16)
+}
+
+/// Describes the allowed numbers of hex digits, such as `6 or 8 hex digits`.
+fn digit_counts(lengths: &[usize]) -> String {
+    let counts: Vec<String> = lengths.iter().map(usize::to_string).collect();
+    match counts.split_last() {
+        Some((last, rest)) if !rest.is_empty() => {
+            format!("{} or {last} hex digits", rest.join(", "))
+        }
+        _ => format!("{} hex digits", counts.concat()),
+    }
+}
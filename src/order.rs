@@ -0,0 +1,226 @@
+//! Channel orderings for hex strings and packed 32-bit colors.
+//!
+//! CSS and [`parse_hex_to_rgba`](crate::parse_hex_to_rgba) write colors as
+//! `#RRGGBBAA`, but other platforms store the same four bytes in a different
+//! order: Android writes `#AARRGGBB`, Direct3D and many game engines pack
+//! `0xAARRGGBB` or `0xBBGGRRAA`, and Windows `COLORREF` values are
+//! `0x00BBGGRR`. [`ChannelOrder`] names each layout, and is used by
+//! [`Rgba::from_u32`], [`Rgba::to_u32`], [`Rgba::to_hex_in`] and
+//! [`ParseOptions::order`](crate::ParseOptions::order). `COLORREF` has no
+//! alpha, so it has its own [`Rgba::from_colorref`] and [`Rgba::to_colorref`].
+//!
+//! When a hex string has no alpha digits, the remaining channels keep their
+//! relative order, so `#RRGGBB` is read the same under `Rgba` and `Argb`, and
+//! `#BBGGRR` under `Bgra` and `Abgr`.
+
+use std::fmt;
+
+use crate::{Channel, GenericRgba, Rgba, Rgba16};
+
+/// The order of the red, green, blue and alpha bytes, from first (most
+/// significant) to last.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ChannelOrder {
+    /// `#RRGGBBAA`, as in CSS
+    #[default]
+    Rgba,
+    /// `#AARRGGBB`, as on Android and in .NET
+    Argb,
+    /// `#BBGGRRAA`
+    Bgra,
+    /// `#AABBGGRR`; Windows `COLORREF` uses this order with a zero alpha byte
+    /// (see [`Rgba::from_colorref`])
+    Abgr,
+}
+
+impl ChannelOrder {
+    /// Every ordering, in the order listed above.
+    pub const ALL: [ChannelOrder; 4] = [
+        ChannelOrder::Rgba,
+        ChannelOrder::Argb,
+        ChannelOrder::Bgra,
+        ChannelOrder::Abgr,
+    ];
+
+    /// Returns the lowercase name of the ordering, such as `argb`.
+    pub fn name(&self) -> &'static str {
+        match self {
+            ChannelOrder::Rgba => "rgba",
+            ChannelOrder::Argb => "argb",
+            ChannelOrder::Bgra => "bgra",
+            ChannelOrder::Abgr => "abgr",
+        }
+    }
+
+    /// Looks up an ordering by name (case-insensitive).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::ChannelOrder;
+    ///
+    /// assert_eq!(ChannelOrder::from_name("ARGB"), Some(ChannelOrder::Argb));
+    /// assert_eq!(ChannelOrder::from_name("grba"), None);
+    /// ```
+    pub fn from_name(name: &str) -> Option<ChannelOrder> {
+        let name = name.to_ascii_lowercase();
+        ChannelOrder::ALL
+            .into_iter()
+            .find(|order| order.name() == name)
+    }
+
+    /// Returns the bytes (or wider channels) of a color in this order.
+    pub fn to_bytes<T: Copy>(&self, color: &GenericRgba<T>) -> [T; 4] {
+        let GenericRgba {
+            red,
+            green,
+            blue,
+            alpha,
+        } = *color;
+        match self {
+            ChannelOrder::Rgba => [red, green, blue, alpha],
+            ChannelOrder::Argb => [alpha, red, green, blue],
+            ChannelOrder::Bgra => [blue, green, red, alpha],
+            ChannelOrder::Abgr => [alpha, blue, green, red],
+        }
+    }
+
+    /// Builds a color from bytes (or wider channels) in this order.
+    pub fn from_bytes<T: Copy>(&self, bytes: [T; 4]) -> GenericRgba<T> {
+        let [red, green, blue, alpha] = match self {
+            ChannelOrder::Rgba => bytes,
+            ChannelOrder::Argb => [bytes[1], bytes[2], bytes[3], bytes[0]],
+            ChannelOrder::Bgra => [bytes[2], bytes[1], bytes[0], bytes[3]],
+            ChannelOrder::Abgr => [bytes[3], bytes[2], bytes[1], bytes[0]],
+        };
+        GenericRgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    /// Returns the ordering with the alpha byte moved last, which keeps the
+    /// order of the color channels when there are no alpha digits.
+    pub(crate) fn alpha_last(&self) -> ChannelOrder {
+        match self {
+            ChannelOrder::Rgba | ChannelOrder::Argb => ChannelOrder::Rgba,
+            ChannelOrder::Bgra | ChannelOrder::Abgr => ChannelOrder::Bgra,
+        }
+    }
+}
+
+impl Rgba {
+    /// Unpacks a color from a 32-bit integer whose bytes, from most to least
+    /// significant, are in `order`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ChannelOrder, Rgba};
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 128 };
+    /// assert_eq!(Rgba::from_u32(0x80ff8800, ChannelOrder::Argb), orange);
+    /// assert_eq!(Rgba::from_u32(0x0088ff80, ChannelOrder::Bgra), orange);
+    /// ```
+    pub fn from_u32(value: u32, order: ChannelOrder) -> Rgba {
+        order.from_bytes(value.to_be_bytes())
+    }
+
+    /// Packs the color into a 32-bit integer whose bytes, from most to least
+    /// significant, are in `order`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ChannelOrder, Rgba};
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+    /// assert_eq!(orange.to_u32(ChannelOrder::Rgba), 0xff8800ff);
+    /// assert_eq!(orange.to_u32(ChannelOrder::Abgr), 0xff0088ff);
+    /// ```
+    pub fn to_u32(&self, order: ChannelOrder) -> u32 {
+        u32::from_be_bytes(order.to_bytes(self))
+    }
+
+    /// Unpacks a Windows `COLORREF` (`0x00BBGGRR`) as an opaque color.
+    ///
+    /// The high byte is not alpha, so it is ignored.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// // RGB(255, 136, 0)
+    /// let orange = Rgba::from_colorref(0x000088ff);
+    /// assert_eq!(orange, Rgba { red: 255, green: 136, blue: 0, alpha: 255 });
+    /// ```
+    pub fn from_colorref(value: u32) -> Rgba {
+        Rgba {
+            alpha: u8::MAX,
+            ..Rgba::from_u32(value, ChannelOrder::Abgr)
+        }
+    }
+
+    /// Packs the color into a Windows `COLORREF` (`0x00BBGGRR`), dropping
+    /// the alpha.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 128 };
+    /// assert_eq!(orange.to_colorref(), 0x000088ff);
+    /// ```
+    pub fn to_colorref(&self) -> u32 {
+        self.to_u32(ChannelOrder::Abgr) & 0x00ff_ffff
+    }
+
+    /// Formats the color as lowercase hex in `order`, omitting the alpha
+    /// digits if it is fully opaque, like [`Rgba::to_hex`].
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ChannelOrder, Rgba};
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 128 };
+    /// assert_eq!(orange.to_hex_in(ChannelOrder::Argb), "#80ff8800");
+    /// assert_eq!(Rgba { alpha: 255, ..orange }.to_hex_in(ChannelOrder::Bgra), "#0088ff");
+    /// ```
+    pub fn to_hex_in(&self, order: ChannelOrder) -> String {
+        hex_in(self, order, 2)
+    }
+}
+
+impl Rgba16 {
+    /// Formats the color as lowercase hex in `order` with four digits per
+    /// channel, omitting the alpha digits if it is fully opaque.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{ChannelOrder, Rgba16};
+    ///
+    /// let color = Rgba16 { red: 0xffff, green: 0x8800, blue: 0x0001, alpha: 0x8000 };
+    /// assert_eq!(color.to_hex_in(ChannelOrder::Argb), "#8000ffff88000001");
+    /// ```
+    pub fn to_hex_in(&self, order: ChannelOrder) -> String {
+        hex_in(self, order, 4)
+    }
+}
+
+/// Formats a color as `#` and `width` lowercase hex digits per channel in
+/// `order`, omitting the alpha digits if it is fully opaque.
+fn hex_in<T: Channel + fmt::LowerHex>(
+    color: &GenericRgba<T>,
+    order: ChannelOrder,
+    width: usize,
+) -> String {
+    let (order, count) = if color.alpha == T::MAX {
+        (order.alpha_last(), 3)
+    } else {
+        (order, 4)
+    };
+    let digits: String = order.to_bytes(color)[..count]
+        .iter()
+        .map(|channel| format!("{channel:0width$x}"))
+        .collect();
+    format!("#{digits}")
+}
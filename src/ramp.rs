@@ -0,0 +1,242 @@
+//! Tints, shades, tones and Tailwind-style lightness ramps.
+//!
+//! Tints, shades and tones mix a color with white, black or gray in sRGB, as
+//! design tools and Sass do. A [`Ramp`] is a perceptually even 50–950 scale in
+//! Oklch built around a color: the color itself lands on the step whose
+//! lightness is closest to its own, and the other steps keep its hue,
+//! reducing chroma where a step would fall outside sRGB.
+
+use crate::mix::{InterpolationSpace, mix};
+use crate::{ColorSpace, Oklch, RgbSpace, Rgba, Srgb};
+
+/// Ramp step names, from lightest to darkest
+pub const RAMP_STEPS: [u16; 11] = [50, 100, 200, 300, 400, 500, 600, 700, 800, 900, 950];
+
+/// Number of bisection steps used to fit a step's chroma into sRGB
+const CHROMA_STEPS: usize = 24;
+
+/// Oklch lightness of each step for a typical ramp
+const RAMP_LIGHTNESS: [f64; 11] = [
+    0.97, 0.93, 0.87, 0.79, 0.70, 0.62, 0.54, 0.46, 0.39, 0.32, 0.25,
+];
+
+/// Chroma of each step relative to the most saturated one; the ends of the
+/// ramp are close to white and black, so they carry less color
+const RAMP_CHROMA: [f64; 11] = [0.15, 0.3, 0.5, 0.75, 0.92, 1.0, 1.0, 0.9, 0.78, 0.65, 0.5];
+
+impl Rgba {
+    /// Mixes the color with white, where `amount` 0 keeps the color and 1
+    /// gives white.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// assert_eq!(red.tint(0.5), Rgba { red: 255, green: 128, blue: 128, alpha: 255 });
+    /// ```
+    pub fn tint(&self, amount: f64) -> Rgba {
+        self.mix_with(1.0, amount)
+    }
+
+    /// Mixes the color with black, where `amount` 0 keeps the color and 1
+    /// gives black.
+    pub fn shade(&self, amount: f64) -> Rgba {
+        self.mix_with(0.0, amount)
+    }
+
+    /// Mixes the color with middle gray (`#808080`), where `amount` 0 keeps
+    /// the color and 1 gives gray.
+    pub fn tone(&self, amount: f64) -> Rgba {
+        self.mix_with(128.0 / 255.0, amount)
+    }
+
+    /// Returns `count` tints evenly spaced between the color and white,
+    /// excluding both.
+    pub fn tints(&self, count: usize) -> Vec<Rgba> {
+        series(count, |amount| self.tint(amount))
+    }
+
+    /// Returns `count` shades evenly spaced between the color and black,
+    /// excluding both.
+    pub fn shades(&self, count: usize) -> Vec<Rgba> {
+        series(count, |amount| self.shade(amount))
+    }
+
+    /// Returns `count` tones evenly spaced between the color and middle gray,
+    /// excluding both.
+    pub fn tones(&self, count: usize) -> Vec<Rgba> {
+        series(count, |amount| self.tone(amount))
+    }
+
+    /// Builds a 50–950 ramp around the color, see [`Ramp::new`].
+    pub fn ramp(&self) -> Ramp {
+        Ramp::new(self)
+    }
+
+    /// Mixes the color with an opaque gray whose channels are all `gray`.
+    fn mix_with(&self, gray: f64, amount: f64) -> Rgba {
+        let gray = Srgb {
+            red: gray,
+            green: gray,
+            blue: gray,
+            alpha: self.alpha as f64 / 255.0,
+        };
+        mix(self, &gray, amount, InterpolationSpace::Srgb).to_rgba()
+    }
+}
+
+/// Calls `color` with `count` amounts evenly spaced in (0, 1).
+fn series(count: usize, color: impl Fn(f64) -> Rgba) -> Vec<Rgba> {
+    (1..=count)
+        .map(|i| color(i as f64 / (count + 1) as f64))
+        .collect()
+}
+
+/// Reduces the chroma of a color until it fits in sRGB.
+///
+/// Unlike [`GamutMapping::ChromaReduction`](crate::gamut::GamutMapping), no
+/// clipping is accepted along the way, so the hue is kept exactly and the
+/// steps of a ramp share it.
+fn fit_chroma(color: Oklch) -> Srgb {
+    if color.convert::<Srgb>().in_gamut() {
+        return color.to_srgb();
+    }
+
+    let (mut low, mut high) = (0.0, color.chroma);
+    for _ in 0..CHROMA_STEPS {
+        let chroma = (low + high) / 2.0;
+        let candidate: Srgb = Oklch { chroma, ..color }.convert();
+        if candidate.in_gamut() {
+            low = chroma;
+        } else {
+            high = chroma;
+        }
+    }
+
+    Oklch {
+        chroma: low,
+        ..color
+    }
+    .convert::<Srgb>()
+    .clip()
+}
+
+/// A Tailwind-style ramp of eleven steps from 50 (lightest) to 950 (darkest).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Ramp {
+    /// The step the source color was placed on
+    pub anchor: u16,
+    /// Each step name with its color, lightest first
+    pub colors: [(u16, Rgba); 11],
+}
+
+impl Ramp {
+    /// Builds a ramp around `color` in Oklch.
+    ///
+    /// The color becomes the step with the closest typical lightness. The
+    /// lightness of the other steps is stretched so they stay evenly spaced
+    /// between the color and the ends of the ramp, their chroma follows the
+    /// color's relative to a typical ramp, and the hue is kept. Steps outside
+    /// the sRGB gamut have their chroma reduced until they fit.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    /// use color_parser::ramp::Ramp;
+    ///
+    /// let orange = Rgba { red: 255, green: 136, blue: 0, alpha: 255 };
+    /// let ramp = Ramp::new(&orange);
+    /// assert_eq!(ramp.anchor, 400);
+    /// assert_eq!(ramp.get(400), Some(orange));
+    /// ```
+    pub fn new(color: &impl ColorSpace) -> Ramp {
+        let source: Oklch = color.convert();
+        let anchor = (0..RAMP_STEPS.len())
+            .min_by(|&a, &b| {
+                let distance = |i: usize| (RAMP_LIGHTNESS[i] - source.lightness).abs();
+                distance(a).total_cmp(&distance(b))
+            })
+            .unwrap();
+        let (first, last) = (RAMP_LIGHTNESS[0], RAMP_LIGHTNESS[RAMP_LIGHTNESS.len() - 1]);
+
+        let colors = std::array::from_fn(|i| {
+            if i == anchor {
+                return (RAMP_STEPS[i], Rgba::from_srgb(color.to_srgb()));
+            }
+
+            // Remap the typical lightness so the anchor lands on the source
+            // color while the ends stay where they are
+            let typical = RAMP_LIGHTNESS[i];
+            let reference = RAMP_LIGHTNESS[anchor];
+            let lightness = if i < anchor {
+                let end = first.max(source.lightness);
+                source.lightness
+                    + (typical - reference) / (first - reference) * (end - source.lightness)
+            } else {
+                let end = last.min(source.lightness);
+                source.lightness
+                    + (typical - reference) / (last - reference) * (end - source.lightness)
+            };
+
+            let step = Oklch {
+                lightness,
+                chroma: source.chroma * RAMP_CHROMA[i] / RAMP_CHROMA[anchor],
+                ..source
+            };
+            (RAMP_STEPS[i], Rgba::from_srgb(fit_chroma(step)))
+        });
+
+        Ramp {
+            anchor: RAMP_STEPS[anchor],
+            colors,
+        }
+    }
+
+    /// Returns the color of a step such as `500`, or `None` for an unknown step.
+    pub fn get(&self, step: u16) -> Option<Rgba> {
+        self.colors
+            .iter()
+            .find(|(name, _)| *name == step)
+            .map(|(_, color)| *color)
+    }
+
+    /// Formats the ramp as CSS custom properties named `--<name>-<step>`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let css = Rgba { red: 255, green: 136, blue: 0, alpha: 255 }.ramp().to_css_properties("brand");
+    /// assert!(css.starts_with(":root {\n  --brand-50: #"));
+    /// assert!(css.contains("  --brand-400: #ff8800;\n"));
+    /// ```
+    pub fn to_css_properties(&self, name: &str) -> String {
+        let mut css = String::from(":root {\n");
+        for (step, color) in &self.colors {
+            css.push_str(&format!("  --{name}-{step}: {};\n", color.to_hex()));
+        }
+        css.push_str("}\n");
+        css
+    }
+
+    /// Formats the ramp as an entry for the `colors` object of a Tailwind
+    /// config (`theme.extend.colors`).
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::Rgba;
+    ///
+    /// let config = Rgba { red: 255, green: 136, blue: 0, alpha: 255 }.ramp().to_tailwind("brand");
+    /// assert!(config.starts_with("'brand': {\n  50: '#"));
+    /// assert!(config.contains("  400: '#ff8800',\n"));
+    /// ```
+    pub fn to_tailwind(&self, name: &str) -> String {
+        let mut config = format!("'{name}': {{\n");
+        for (step, color) in &self.colors {
+            config.push_str(&format!("  {step}: '{}',\n", color.to_hex()));
+        }
+        config.push_str("},\n");
+        config
+    }
+}
@@ -0,0 +1,297 @@
+//! Continuous color scales for data visualization.
+//!
+//! A [`Scale`] maps numbers onto colors through a list of color stops, in the
+//! spirit of chroma.js. Values are mapped from the scale's domain onto [0, 1],
+//! optionally quantized into classes, and then interpolated between the
+//! surrounding stops:
+//!
+//! ```rust
+//! use color_parser::mix::InterpolationSpace;
+//! use color_parser::scale::Scale;
+//! use color_parser::Rgba;
+//!
+//! let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+//! let black = Rgba { red: 0, green: 0, blue: 0, alpha: 255 };
+//! let scale = Scale::new(&[white, black])
+//!     .mode(InterpolationSpace::Srgb)
+//!     .domain(0.0, 100.0);
+//!
+//! assert_eq!(scale.at(50.0), Rgba { red: 128, green: 128, blue: 128, alpha: 255 });
+//! assert_eq!(scale.hex_colors(3), ["#ffffff", "#808080", "#000000"]);
+//! ```
+
+use crate::mix::{InterpolationSpace, mix};
+use crate::{ColorSpace, Lab, Rgba, Srgb, WhitePoint};
+
+/// Number of bisection steps used by lightness correction
+const CORRECTION_STEPS: usize = 32;
+
+/// A color scale built from color stops.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Scale {
+    /// Stop positions in [0, 1] and their colors, sorted by position
+    stops: Vec<(f64, Srgb)>,
+    domain: (f64, f64),
+    space: InterpolationSpace,
+    bezier: bool,
+    correct_lightness: bool,
+    classes: Option<Classes>,
+}
+
+/// How a scale is quantized into classes.
+#[derive(Debug, Clone, PartialEq)]
+enum Classes {
+    /// Equal-width classes across the domain
+    Count(usize),
+    /// Class boundaries in domain units
+    Breaks(Vec<f64>),
+}
+
+impl Scale {
+    /// Creates a scale with the colors spread evenly over [0, 1].
+    ///
+    /// The scale interpolates in [`InterpolationSpace::default`] (Oklab) over
+    /// the domain [0, 1].
+    ///
+    /// # Panics
+    /// Panics if `colors` is empty.
+    pub fn new<C: ColorSpace>(colors: &[C]) -> Scale {
+        let last = colors.len().saturating_sub(1).max(1) as f64;
+        let stops: Vec<_> = colors
+            .iter()
+            .enumerate()
+            .map(|(i, color)| (i as f64 / last, color.to_srgb()))
+            .collect();
+
+        Scale::from_stops(&stops)
+    }
+
+    /// Creates a scale from `(position, color)` stops, where positions are
+    /// fractions of the scale in [0, 1].
+    ///
+    /// Stops may be given in any order, and NaN positions are placed at 0.
+    /// Values before the first stop or after the last one take the color of
+    /// that stop.
+    ///
+    /// # Panics
+    /// Panics if `stops` is empty.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::scale::Scale;
+    /// use color_parser::Rgba;
+    ///
+    /// let red = Rgba { red: 255, green: 0, blue: 0, alpha: 255 };
+    /// let white = Rgba { red: 255, green: 255, blue: 255, alpha: 255 };
+    /// let blue = Rgba { red: 0, green: 0, blue: 255, alpha: 255 };
+    ///
+    /// let scale = Scale::from_stops(&[(0.0, red), (0.2, white), (1.0, blue)]);
+    /// assert_eq!(scale.at(0.2), white);
+    /// ```
+    pub fn from_stops<C: ColorSpace>(stops: &[(f64, C)]) -> Scale {
+        assert!(!stops.is_empty(), "a scale needs at least one color");
+
+        let mut stops: Vec<_> = stops
+            .iter()
+            .map(|(position, color)| {
+                let position = if position.is_nan() {
+                    0.0
+                } else {
+                    position.clamp(0.0, 1.0)
+                };
+                (position, color.to_srgb())
+            })
+            .collect();
+        stops.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+        Scale {
+            stops,
+            domain: (0.0, 1.0),
+            space: InterpolationSpace::default(),
+            bezier: false,
+            correct_lightness: false,
+            classes: None,
+        }
+    }
+
+    /// Sets the space colors are interpolated in between stops.
+    pub fn mode(mut self, space: InterpolationSpace) -> Scale {
+        self.space = space;
+        self
+    }
+
+    /// Sets the input range mapped onto the scale. `start` may be greater than
+    /// `end` to reverse the scale.
+    pub fn domain(mut self, start: f64, end: f64) -> Scale {
+        self.domain = (start, end);
+        self
+    }
+
+    /// Interpolates along a Bezier curve in CIELAB that uses the stop colors as
+    /// control points, which gives smoother transitions than piecewise
+    /// interpolation. Stop positions and the interpolation space are ignored.
+    pub fn bezier(mut self, enabled: bool) -> Scale {
+        self.bezier = enabled;
+        self
+    }
+
+    /// Adjusts positions so lightness changes linearly from the first to the
+    /// last color, which keeps sequential scales perceptually even.
+    pub fn correct_lightness(mut self, enabled: bool) -> Scale {
+        self.correct_lightness = enabled;
+        self
+    }
+
+    /// Quantizes the scale into `count` equal-width classes over the domain.
+    /// Every value in a class maps to the same color.
+    pub fn classes(mut self, count: usize) -> Scale {
+        self.classes = Some(Classes::Count(count.max(1)));
+        self
+    }
+
+    /// Quantizes the scale into classes bounded by `breaks`, given in domain
+    /// units. `n` breaks make `n - 1` classes.
+    pub fn class_breaks(mut self, breaks: &[f64]) -> Scale {
+        let mut breaks = breaks.to_vec();
+        breaks.sort_by(f64::total_cmp);
+        self.classes = Some(Classes::Breaks(breaks));
+        self
+    }
+
+    /// Returns the color at `value` in the domain.
+    pub fn at(&self, value: f64) -> Rgba {
+        Rgba::from_srgb(self.color_at(self.position(value)))
+    }
+
+    /// Samples `count` colors evenly across the domain, from start to end.
+    pub fn colors(&self, count: usize) -> Vec<Rgba> {
+        let (start, end) = self.domain;
+        let last = count.saturating_sub(1).max(1) as f64;
+
+        (0..count)
+            .map(|i| self.at(start + (end - start) * i as f64 / last))
+            .collect()
+    }
+
+    /// Samples `count` colors like [`Scale::colors`], formatted as hex strings.
+    pub fn hex_colors(&self, count: usize) -> Vec<String> {
+        self.colors(count).iter().map(Rgba::to_hex).collect()
+    }
+
+    /// Maps a domain value to a position in [0, 1], applying classes.
+    ///
+    /// NaN values, or any value in a NaN domain, map to the domain start.
+    fn position(&self, value: f64) -> f64 {
+        let (start, end) = self.domain;
+        let normalize = |value: f64| {
+            let t = (value - start) / (end - start);
+            if start == end || t.is_nan() {
+                0.0
+            } else {
+                t.clamp(0.0, 1.0)
+            }
+        };
+        let t = normalize(value);
+
+        let breaks: Vec<f64> = match &self.classes {
+            None => return t,
+            Some(Classes::Count(count)) => (0..=*count).map(|i| i as f64 / *count as f64).collect(),
+            Some(Classes::Breaks(breaks)) => breaks.iter().map(|&b| normalize(b)).collect(),
+        };
+        match breaks.len() {
+            0 | 1 => return t,
+            2 => return 0.5,
+            _ => {}
+        }
+
+        // Every class maps to an evenly spaced position, first to last
+        let classes = breaks.len() - 1;
+        let class = breaks[1..classes].iter().filter(|&&b| t >= b).count();
+        class as f64 / (classes - 1) as f64
+    }
+
+    /// Returns the color at position `t`, correcting lightness if enabled.
+    fn color_at(&self, t: f64) -> Srgb {
+        if !self.correct_lightness {
+            return self.interpolate(t);
+        }
+
+        let lightness = |t: f64| Lab::from_srgb(self.interpolate(t)).lightness;
+        let (first, last) = (lightness(0.0), lightness(1.0));
+        if first == last {
+            return self.interpolate(t);
+        }
+
+        // Bisect for the position whose lightness is on the straight line,
+        // assuming lightness changes monotonically along the scale
+        let target = first + (last - first) * t;
+        let direction = (last - first).signum();
+        let (mut low, mut high) = (0.0, 1.0);
+        for _ in 0..CORRECTION_STEPS {
+            let middle = (low + high) / 2.0;
+            if (lightness(middle) - target) * direction < 0.0 {
+                low = middle;
+            } else {
+                high = middle;
+            }
+        }
+
+        self.interpolate((low + high) / 2.0)
+    }
+
+    /// Interpolates the stops at position `t`.
+    fn interpolate(&self, t: f64) -> Srgb {
+        if self.bezier {
+            return self.bezier_at(t);
+        }
+
+        let (first, last) = (self.stops[0], self.stops[self.stops.len() - 1]);
+        if t <= first.0 {
+            return first.1;
+        }
+        if t >= last.0 {
+            return last.1;
+        }
+
+        // Find the pair of stops surrounding t
+        let next = self
+            .stops
+            .iter()
+            .position(|stop| stop.0 > t)
+            .unwrap_or(self.stops.len() - 1);
+        let (start, end) = (self.stops[next - 1], self.stops[next]);
+        let local = (t - start.0) / (end.0 - start.0);
+
+        mix(&start.1, &end.1, local, self.space).to_srgb()
+    }
+
+    /// Evaluates the Bezier curve through the stop colors in CIELAB with
+    /// de Casteljau's algorithm.
+    fn bezier_at(&self, t: f64) -> Srgb {
+        let mut points: Vec<[f64; 4]> = self
+            .stops
+            .iter()
+            .map(|(_, color)| {
+                let lab = Lab::from_srgb(*color);
+                [lab.lightness, lab.a, lab.b, lab.alpha]
+            })
+            .collect();
+
+        while points.len() > 1 {
+            points = points
+                .windows(2)
+                .map(|pair| [0, 1, 2, 3].map(|i| pair[0][i] + (pair[1][i] - pair[0][i]) * t))
+                .collect();
+        }
+
+        let [lightness, a, b, alpha] = points[0];
+        Lab {
+            lightness,
+            a,
+            b,
+            alpha,
+            white_point: WhitePoint::D50,
+        }
+        .to_srgb()
+    }
+}
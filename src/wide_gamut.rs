@@ -0,0 +1,372 @@
+//! Wide-gamut RGB color spaces.
+//!
+//! Each space is described by its primaries, white point and transfer curve
+//! through the [`RgbSpace`] trait. Conversions go through CIE XYZ; spaces with a
+//! white point other than D65 (ProPhoto RGB) are chromatically adapted with the
+//! Bradford transform.
+//!
+//! Supported spaces, with their CSS `color()` identifiers:
+//! - [`Srgb`] (`srgb`) and [`LinearRgb`] (`srgb-linear`), which also implement
+//!   [`RgbSpace`] so they can be used wherever an RGB space is expected
+//! - [`DisplayP3`] (`display-p3`)
+//! - [`Rec2020`] (`rec2020`)
+//! - [`AdobeRgb`] (`a98-rgb`)
+//! - [`ProPhotoRgb`] (`prophoto-rgb`)
+
+use crate::adaptation::{AdaptationMethod, adapt};
+use crate::css::css_channels;
+use crate::matrix::{Matrix3, diagonal, invert, multiply, multiply_matrices};
+use crate::xyz::{WhitePoint, linear_to_srgb, srgb_to_linear};
+use crate::{ColorSpace, LinearRgb, Srgb, Xyz};
+
+/// An RGB color space defined by its primaries, white point and transfer curve.
+pub trait RgbSpace: ColorSpace {
+    /// Identifier used by the CSS `color()` function
+    const CSS_NAME: &'static str;
+    /// CIE 1931 xy chromaticities of the red, green and blue primaries
+    const PRIMARIES: [[f64; 2]; 3];
+    /// Reference white of the space
+    const WHITE_POINT: WhitePoint;
+
+    /// Converts a gamma-encoded channel to linear light.
+    fn decode(value: f64) -> f64;
+
+    /// Converts a linear-light channel to its gamma-encoded form.
+    fn encode(value: f64) -> f64;
+
+    /// Creates a color from gamma-encoded channels and alpha.
+    fn from_channels(channels: [f64; 3], alpha: f64) -> Self;
+
+    /// Returns the gamma-encoded channels and alpha.
+    fn channels(&self) -> ([f64; 3], f64);
+
+    /// Converts this color to D65-relative `Xyz`.
+    fn to_xyz(&self) -> Xyz {
+        let (channels, alpha) = self.channels();
+        let linear = channels.map(Self::decode);
+        let native = multiply(&to_xyz_matrix(Self::PRIMARIES, Self::WHITE_POINT), linear);
+        let [x, y, z] = adapt(
+            native,
+            Self::WHITE_POINT,
+            WhitePoint::D65,
+            AdaptationMethod::Bradford,
+        );
+        Xyz { x, y, z, alpha }
+    }
+
+    /// Creates a color in this space from D65-relative `Xyz`.
+    ///
+    /// Colors outside the gamut produce channels outside [0, 1].
+    fn from_xyz(xyz: &Xyz) -> Self {
+        let native = adapt(
+            [xyz.x, xyz.y, xyz.z],
+            WhitePoint::D65,
+            Self::WHITE_POINT,
+            AdaptationMethod::Bradford,
+        );
+        let matrix = invert(&to_xyz_matrix(Self::PRIMARIES, Self::WHITE_POINT));
+        let linear = multiply(&matrix, native);
+        Self::from_channels(linear.map(Self::encode), xyz.alpha)
+    }
+
+    /// Returns whether the color lies within this space's gamut, allowing for
+    /// floating-point error.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::{DisplayP3, RgbSpace, Srgb};
+    ///
+    /// let p3_red = DisplayP3 { red: 1.0, green: 0.0, blue: 0.0, alpha: 1.0 };
+    /// assert!(p3_red.in_gamut());
+    /// assert!(!Srgb::from(p3_red).in_gamut());
+    /// ```
+    fn in_gamut(&self) -> bool {
+        let (channels, _) = self.channels();
+        channels
+            .iter()
+            .all(|&c| (-GAMUT_EPSILON..=1.0 + GAMUT_EPSILON).contains(&c))
+    }
+
+    /// Clamps each channel to [0, 1].
+    fn clip(&self) -> Self {
+        let (channels, alpha) = self.channels();
+        Self::from_channels(channels.map(|c| c.clamp(0.0, 1.0)), alpha)
+    }
+
+    /// Formats this color as a CSS `color()` function.
+    fn to_css_string(&self) -> String {
+        let (channels, alpha) = self.channels();
+        format!(
+            "color({} {})",
+            Self::CSS_NAME,
+            css_channels(channels, alpha)
+        )
+    }
+}
+
+/// Channel tolerance for gamut checks, well below a 16-bit step
+const GAMUT_EPSILON: f64 = 1e-6;
+
+/// Builds the linear RGB to XYZ matrix for the given primaries and white point.
+fn to_xyz_matrix(primaries: [[f64; 2]; 3], white_point: WhitePoint) -> Matrix3 {
+    // Each column is the XYZ of a primary with Y = 1
+    let columns = primaries.map(|[x, y]| [x / y, 1.0, (1.0 - x - y) / y]);
+    let unscaled = [
+        [columns[0][0], columns[1][0], columns[2][0]],
+        [columns[0][1], columns[1][1], columns[2][1]],
+        [columns[0][2], columns[1][2], columns[2][2]],
+    ];
+
+    // Scale the primaries so that RGB (1, 1, 1) maps to the white point
+    let scale = multiply(&invert(&unscaled), white_point.xyz());
+    multiply_matrices(&unscaled, &diagonal(scale))
+}
+
+/// Applies a transfer function to the magnitude of `value`, keeping its sign.
+fn mirrored(value: f64, curve: impl Fn(f64) -> f64) -> f64 {
+    value.signum() * curve(value.abs())
+}
+
+/// Defines an RGB space struct with floating-point channels and its
+/// `ColorSpace` implementation.
+macro_rules! rgb_space_struct {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Debug, Clone, Copy, PartialEq)]
+        pub struct $name {
+            /// Red channel, nominally [0–1]
+            pub red: f64,
+            /// Green channel, nominally [0–1]
+            pub green: f64,
+            /// Blue channel, nominally [0–1]
+            pub blue: f64,
+            /// Alpha as a fraction [0–1], where 1 is fully opaque
+            pub alpha: f64,
+        }
+
+        impl ColorSpace for $name {
+            fn to_srgb(&self) -> Srgb {
+                RgbSpace::to_xyz(self).to_srgb()
+            }
+
+            fn from_srgb(color: Srgb) -> Self {
+                <$name as RgbSpace>::from_xyz(&Xyz::from_srgb(color))
+            }
+        }
+    };
+}
+
+rgb_space_struct!(
+    /// Represents a color in the Display P3 color space.
+    ///
+    /// Uses the DCI-P3 primaries with a D65 white point and the sRGB transfer curve.
+    DisplayP3
+);
+
+rgb_space_struct!(
+    /// Represents a color in the ITU-R BT.2020 (Rec. 2020) color space.
+    Rec2020
+);
+
+rgb_space_struct!(
+    /// Represents a color in the Adobe RGB (1998) color space.
+    AdobeRgb
+);
+
+rgb_space_struct!(
+    /// Represents a color in the ProPhoto RGB (ROMM RGB) color space.
+    ///
+    /// Uses a D50 white point, so conversions include Bradford adaptation.
+    ProPhotoRgb
+);
+
+impl RgbSpace for Srgb {
+    const CSS_NAME: &'static str = "srgb";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.640, 0.330], [0.300, 0.600], [0.150, 0.060]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn decode(value: f64) -> f64 {
+        srgb_to_linear(value)
+    }
+
+    fn encode(value: f64) -> f64 {
+        linear_to_srgb(value)
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        Srgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
+
+impl RgbSpace for LinearRgb {
+    const CSS_NAME: &'static str = "srgb-linear";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.640, 0.330], [0.300, 0.600], [0.150, 0.060]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn decode(value: f64) -> f64 {
+        value
+    }
+
+    fn encode(value: f64) -> f64 {
+        value
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        LinearRgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
+
+impl RgbSpace for DisplayP3 {
+    const CSS_NAME: &'static str = "display-p3";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.680, 0.320], [0.265, 0.690], [0.150, 0.060]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn decode(value: f64) -> f64 {
+        srgb_to_linear(value)
+    }
+
+    fn encode(value: f64) -> f64 {
+        linear_to_srgb(value)
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        DisplayP3 {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
+
+/// Rec. 2020 transfer curve α constant (ITU-R BT.2020)
+const REC2020_ALPHA: f64 = 1.09929682680944;
+/// Rec. 2020 transfer curve β constant: the end of the linear segment
+const REC2020_BETA: f64 = 0.018053968510807;
+
+impl RgbSpace for Rec2020 {
+    const CSS_NAME: &'static str = "rec2020";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.708, 0.292], [0.170, 0.797], [0.131, 0.046]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn decode(value: f64) -> f64 {
+        mirrored(value, |v| {
+            if v < REC2020_BETA * 4.5 {
+                v / 4.5
+            } else {
+                ((v + REC2020_ALPHA - 1.0) / REC2020_ALPHA).powf(1.0 / 0.45)
+            }
+        })
+    }
+
+    fn encode(value: f64) -> f64 {
+        mirrored(value, |v| {
+            if v < REC2020_BETA {
+                v * 4.5
+            } else {
+                REC2020_ALPHA * v.powf(0.45) - (REC2020_ALPHA - 1.0)
+            }
+        })
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        Rec2020 {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
+
+impl RgbSpace for AdobeRgb {
+    const CSS_NAME: &'static str = "a98-rgb";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.640, 0.330], [0.210, 0.710], [0.150, 0.060]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D65;
+
+    fn decode(value: f64) -> f64 {
+        mirrored(value, |v| v.powf(563.0 / 256.0))
+    }
+
+    fn encode(value: f64) -> f64 {
+        mirrored(value, |v| v.powf(256.0 / 563.0))
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        AdobeRgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
+
+impl RgbSpace for ProPhotoRgb {
+    const CSS_NAME: &'static str = "prophoto-rgb";
+    const PRIMARIES: [[f64; 2]; 3] = [[0.7347, 0.2653], [0.1596, 0.8404], [0.0366, 0.0001]];
+    const WHITE_POINT: WhitePoint = WhitePoint::D50;
+
+    fn decode(value: f64) -> f64 {
+        mirrored(value, |v| {
+            if v <= 16.0 / 512.0 {
+                v / 16.0
+            } else {
+                v.powf(1.8)
+            }
+        })
+    }
+
+    fn encode(value: f64) -> f64 {
+        mirrored(value, |v| {
+            if v >= 1.0 / 512.0 {
+                v.powf(1.0 / 1.8)
+            } else {
+                v * 16.0
+            }
+        })
+    }
+
+    fn from_channels([red, green, blue]: [f64; 3], alpha: f64) -> Self {
+        ProPhotoRgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn channels(&self) -> ([f64; 3], f64) {
+        ([self.red, self.green, self.blue], self.alpha)
+    }
+}
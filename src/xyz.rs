@@ -0,0 +1,325 @@
+//! Linear-light sRGB and CIE XYZ color spaces.
+//!
+//! Gamma-encoded sRGB values (as stored in `Rgba` and `Srgb`) are not
+//! proportional to light intensity, so blending and luminance computations must
+//! happen in linear light. `LinearRgb` uses the exact piecewise sRGB transfer
+//! function from IEC 61966-2-1, and `Xyz` is the CIE 1931 XYZ space relative to
+//! the D65 white point, using the sRGB matrices from CSS Color Level 4.
+
+use crate::adaptation::{AdaptationMethod, adapt};
+use crate::matrix::{Matrix3, multiply};
+use crate::{ColorParserError, ColorSpace, Srgb, component_error};
+
+/// Linear sRGB to CIE XYZ (D65).
+pub(crate) const LINEAR_SRGB_TO_XYZ: Matrix3 = [
+    [506752.0 / 1228815.0, 87881.0 / 245763.0, 12673.0 / 70218.0],
+    [87098.0 / 409605.0, 175762.0 / 245763.0, 12673.0 / 175545.0],
+    [7918.0 / 409605.0, 87881.0 / 737289.0, 1001167.0 / 1053270.0],
+];
+
+/// CIE XYZ (D65) to linear sRGB.
+pub(crate) const XYZ_TO_LINEAR_SRGB: Matrix3 = [
+    [12831.0 / 3959.0, -329.0 / 214.0, -1974.0 / 3959.0],
+    [
+        -851781.0 / 878810.0,
+        1648619.0 / 878810.0,
+        36519.0 / 878810.0,
+    ],
+    [705.0 / 12673.0, -2585.0 / 12673.0, 705.0 / 667.0],
+];
+
+/// Represents a color in the linear-light sRGB color space.
+///
+/// Channels share the sRGB primaries and white point but are proportional to
+/// light intensity. Values outside [0, 1] are out of the sRGB gamut.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct LinearRgb {
+    /// Linear red channel, nominally [0–1]
+    pub red: f64,
+    /// Linear green channel, nominally [0–1]
+    pub green: f64,
+    /// Linear blue channel, nominally [0–1]
+    pub blue: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+}
+
+/// Represents a color in the CIE 1931 XYZ color space, relative to D65.
+///
+/// `y` is the relative luminance, with the D65 reference white at `y = 1`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Xyz {
+    /// X tristimulus value
+    pub x: f64,
+    /// Y tristimulus value (relative luminance)
+    pub y: f64,
+    /// Z tristimulus value
+    pub z: f64,
+    /// Alpha as a fraction [0–1], where 1 is fully opaque
+    pub alpha: f64,
+}
+
+/// The reference white of a color space: a standard illuminant or an
+/// arbitrary chromaticity.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum WhitePoint {
+    /// CIE illuminant A (incandescent light, 2856 K)
+    A,
+    /// CIE illuminant C (average daylight, superseded by D65)
+    C,
+    /// CIE D50 (horizon light), used by ICC profiles and CSS `lab()`/`lch()`
+    D50,
+    /// CIE D55 (mid-morning daylight)
+    D55,
+    /// CIE D65 (noon daylight), used by sRGB and most displays
+    D65,
+    /// CIE D75 (north sky daylight)
+    D75,
+    /// CIE illuminant E (equal energy)
+    E,
+    /// CIE F2 (cool white fluorescent)
+    F2,
+    /// CIE F7 (broadband daylight fluorescent)
+    F7,
+    /// CIE F11 (narrow band white fluorescent)
+    F11,
+    /// A white point given by its CIE 1931 xy chromaticity, created with
+    /// [`WhitePoint::custom`] or [`WhitePoint::daylight`]
+    Custom(Chromaticity),
+}
+
+/// A CIE 1931 xy chromaticity checked by [`WhitePoint::custom`], so that
+/// conversions relative to it never divide by zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Chromaticity {
+    /// x chromaticity coordinate
+    x: f64,
+    /// y chromaticity coordinate, greater than 0
+    y: f64,
+}
+
+impl Chromaticity {
+    /// Returns the x chromaticity coordinate.
+    pub fn x(&self) -> f64 {
+        self.x
+    }
+
+    /// Returns the y chromaticity coordinate.
+    pub fn y(&self) -> f64 {
+        self.y
+    }
+}
+
+impl WhitePoint {
+    /// Returns the CIE 1931 xy chromaticity of the white point.
+    ///
+    /// D50 and D65 use the four-digit values from CSS Color Level 4; the other
+    /// illuminants use the CIE 1931 2° observer values.
+    pub fn chromaticity(&self) -> [f64; 2] {
+        match self {
+            WhitePoint::A => [0.44757, 0.40745],
+            WhitePoint::C => [0.31006, 0.31616],
+            WhitePoint::D50 => [0.3457, 0.3585],
+            WhitePoint::D55 => [0.33242, 0.34743],
+            WhitePoint::D65 => [0.3127, 0.3290],
+            WhitePoint::D75 => [0.29902, 0.31485],
+            WhitePoint::E => [1.0 / 3.0, 1.0 / 3.0],
+            WhitePoint::F2 => [0.37208, 0.37529],
+            WhitePoint::F7 => [0.31292, 0.32933],
+            WhitePoint::F11 => [0.38052, 0.37713],
+            WhitePoint::Custom(chromaticity) => [chromaticity.x, chromaticity.y],
+        }
+    }
+
+    /// Creates a custom white point from a CIE 1931 xy chromaticity.
+    ///
+    /// # Errors
+    /// Returns `InvalidComponentValue` unless both coordinates are finite,
+    /// `x` is at least 0, `y` is greater than 0 and `x + y` is at most 1.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::WhitePoint;
+    ///
+    /// let white = WhitePoint::custom(0.3127, 0.3290).unwrap();
+    /// assert_eq!(white.xyz(), WhitePoint::D65.xyz());
+    /// assert!(WhitePoint::custom(0.3, 0.0).is_err());
+    /// assert!(WhitePoint::custom(f64::NAN, 0.3).is_err());
+    /// ```
+    pub fn custom(x: f64, y: f64) -> Result<WhitePoint, ColorParserError> {
+        if !(0.0..=1.0).contains(&x) {
+            return Err(component_error(x, "an x chromaticity from 0 to 1"));
+        }
+        if !(y > 0.0 && x + y <= 1.0) {
+            return Err(component_error(
+                y,
+                "a y chromaticity above 0 with x + y at most 1",
+            ));
+        }
+        Ok(WhitePoint::Custom(Chromaticity { x, y }))
+    }
+
+    /// Returns the XYZ tristimulus values of the white point, normalized to `Y = 1`.
+    pub fn xyz(&self) -> [f64; 3] {
+        let [x, y] = self.chromaticity();
+        [x / y, 1.0, (1.0 - x - y) / y]
+    }
+
+    /// Returns the CIE daylight white point for a correlated color temperature
+    /// in kelvin.
+    ///
+    /// The CIE daylight locus is defined between 4000 K and 25000 K; values
+    /// outside that range are clamped to it.
+    ///
+    /// # Errors
+    /// Returns `InvalidComponentValue` if the temperature is NaN.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::WhitePoint;
+    ///
+    /// let [x, y] = WhitePoint::daylight(6504.0).unwrap().chromaticity();
+    /// assert!((x - 0.3127).abs() < 1e-3);
+    /// assert!((y - 0.3291).abs() < 1e-3);
+    /// ```
+    pub fn daylight(temperature: f64) -> Result<WhitePoint, ColorParserError> {
+        if temperature.is_nan() {
+            return Err(component_error(temperature, "a temperature in kelvin"));
+        }
+        let t = temperature.clamp(4000.0, 25000.0);
+
+        // Cubic fits of x in 1/T, split at 7000 K
+        let x = if t <= 7000.0 {
+            -4.6070e9 / t.powi(3) + 2.9678e6 / t.powi(2) + 0.09911e3 / t + 0.244063
+        } else {
+            -2.0064e9 / t.powi(3) + 1.9018e6 / t.powi(2) + 0.24748e3 / t + 0.237040
+        };
+        let y = -3.0 * x * x + 2.87 * x - 0.275;
+
+        Ok(WhitePoint::Custom(Chromaticity { x, y }))
+    }
+}
+
+/// Converts a gamma-encoded sRGB channel to linear light.
+///
+/// Implements the piecewise IEC 61966-2-1 transfer function. Negative values
+/// are mirrored so out-of-gamut colors survive the round trip.
+///
+/// # Examples
+/// ```rust
+/// use color_parser::xyz::srgb_to_linear;
+///
+/// assert_eq!(srgb_to_linear(1.0), 1.0);
+/// assert!((srgb_to_linear(0.5) - 0.2140).abs() < 1e-4);
+/// ```
+pub fn srgb_to_linear(value: f64) -> f64 {
+    let abs = value.abs();
+    if abs <= 0.04045 {
+        value / 12.92
+    } else {
+        value.signum() * ((abs + 0.055) / 1.055).powf(2.4)
+    }
+}
+
+/// Converts a linear-light channel to gamma-encoded sRGB.
+///
+/// The inverse of [`srgb_to_linear`].
+///
+/// # Examples
+/// ```rust
+/// use color_parser::xyz::linear_to_srgb;
+///
+/// assert_eq!(linear_to_srgb(0.0), 0.0);
+/// assert!((linear_to_srgb(0.2140) - 0.5).abs() < 1e-4);
+/// ```
+pub fn linear_to_srgb(value: f64) -> f64 {
+    let abs = value.abs();
+    if abs <= 0.0031308 {
+        value * 12.92
+    } else {
+        value.signum() * (1.055 * abs.powf(1.0 / 2.4) - 0.055)
+    }
+}
+
+impl LinearRgb {
+    /// Converts this color to CIE XYZ (D65).
+    pub fn to_xyz(&self) -> Xyz {
+        let [x, y, z] = multiply(&LINEAR_SRGB_TO_XYZ, [self.red, self.green, self.blue]);
+        Xyz {
+            x,
+            y,
+            z,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl Xyz {
+    /// Adapts this color from one white point to another.
+    ///
+    /// Every other conversion in this crate expects D65-relative values, so use
+    /// this to bring in XYZ data measured under another illuminant, or to
+    /// express a color relative to another white.
+    ///
+    /// # Examples
+    /// ```rust
+    /// use color_parser::adaptation::AdaptationMethod;
+    /// use color_parser::{Rgba, WhitePoint, Xyz};
+    ///
+    /// // D50 white from a print profile is D65 white on screen
+    /// let [x, y, z] = WhitePoint::D50.xyz();
+    /// let print = Xyz { x, y, z, alpha: 1.0 };
+    /// let screen = print.adapt(WhitePoint::D50, WhitePoint::D65, AdaptationMethod::Bradford);
+    /// assert_eq!(Rgba::from(screen), Rgba { red: 255, green: 255, blue: 255, alpha: 255 });
+    /// ```
+    pub fn adapt(&self, from: WhitePoint, to: WhitePoint, method: AdaptationMethod) -> Xyz {
+        let [x, y, z] = adapt([self.x, self.y, self.z], from, to, method);
+        Xyz {
+            x,
+            y,
+            z,
+            alpha: self.alpha,
+        }
+    }
+
+    /// Converts this color to linear sRGB.
+    pub fn to_linear_rgb(&self) -> LinearRgb {
+        let [red, green, blue] = multiply(&XYZ_TO_LINEAR_SRGB, [self.x, self.y, self.z]);
+        LinearRgb {
+            red,
+            green,
+            blue,
+            alpha: self.alpha,
+        }
+    }
+}
+
+impl ColorSpace for LinearRgb {
+    fn to_srgb(&self) -> Srgb {
+        Srgb {
+            red: linear_to_srgb(self.red),
+            green: linear_to_srgb(self.green),
+            blue: linear_to_srgb(self.blue),
+            alpha: self.alpha,
+        }
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        LinearRgb {
+            red: srgb_to_linear(color.red),
+            green: srgb_to_linear(color.green),
+            blue: srgb_to_linear(color.blue),
+            alpha: color.alpha,
+        }
+    }
+}
+
+impl ColorSpace for Xyz {
+    fn to_srgb(&self) -> Srgb {
+        self.to_linear_rgb().to_srgb()
+    }
+
+    fn from_srgb(color: Srgb) -> Self {
+        LinearRgb::from_srgb(color).to_xyz()
+    }
+}
@@ -0,0 +1,165 @@
+use color_parser::adaptation::{AdaptationMethod, adapt, adaptation_matrix};
+use color_parser::{ErrorKind, Lab, Rgba, WhitePoint, Xyz};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const METHODS: [AdaptationMethod; 5] = [
+        AdaptationMethod::Bradford,
+        AdaptationMethod::VonKries,
+        AdaptationMethod::Cat02,
+        AdaptationMethod::Cat16,
+        AdaptationMethod::XyzScaling,
+    ];
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_bradford_d65_to_d50_matrix() {
+        // Matrix published in CSS Color Level 4
+        let expected = [
+            [1.0479298, 0.0229468, -0.0501922],
+            [0.0296278, 0.9904345, -0.0170738],
+            [-0.0092430, 0.0150552, 0.7518743],
+        ];
+        let matrix =
+            adaptation_matrix(WhitePoint::D65, WhitePoint::D50, AdaptationMethod::Bradford);
+        for (row, expected_row) in matrix.iter().zip(expected) {
+            for (value, expected_value) in row.iter().zip(expected_row) {
+                assert_close(*value, expected_value, 1e-6);
+            }
+        }
+    }
+
+    #[test]
+    fn test_white_maps_to_white() {
+        let whites = [
+            WhitePoint::A,
+            WhitePoint::C,
+            WhitePoint::D50,
+            WhitePoint::D55,
+            WhitePoint::D65,
+            WhitePoint::D75,
+            WhitePoint::E,
+            WhitePoint::F2,
+            WhitePoint::F7,
+            WhitePoint::F11,
+        ];
+        for method in METHODS {
+            for from in whites {
+                let adapted = adapt(from.xyz(), from, WhitePoint::D65, method);
+                for (value, expected) in adapted.into_iter().zip(WhitePoint::D65.xyz()) {
+                    assert_close(value, expected, 1e-12);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let xyz = [0.2, 0.3, 0.4];
+        for method in METHODS {
+            let there = adapt(xyz, WhitePoint::D65, WhitePoint::A, method);
+            let back = adapt(there, WhitePoint::A, WhitePoint::D65, method);
+            for (value, expected) in back.into_iter().zip(xyz) {
+                assert_close(value, expected, 1e-12);
+            }
+        }
+    }
+
+    #[test]
+    fn test_methods_agree_roughly() {
+        let red: Xyz = rgba(255, 0, 0).into();
+        let bradford = red.adapt(WhitePoint::D65, WhitePoint::D50, AdaptationMethod::Bradford);
+        for method in [AdaptationMethod::Cat02, AdaptationMethod::Cat16] {
+            let other = red.adapt(WhitePoint::D65, WhitePoint::D50, method);
+            assert_ne!(other, bradford);
+            assert_close(other.x, bradford.x, 2e-2);
+            assert_close(other.y, bradford.y, 2e-2);
+            assert_close(other.z, bradford.z, 2e-2);
+        }
+    }
+
+    #[test]
+    fn test_custom_and_daylight_white_points() {
+        let custom = WhitePoint::custom(0.3127, 0.3290).unwrap();
+        assert_eq!(custom.xyz(), WhitePoint::D65.xyz());
+
+        let [x, y] = WhitePoint::daylight(5003.0).unwrap().chromaticity();
+        assert_close(x, 0.3457, 1e-3);
+        assert_close(y, 0.3585, 1e-3);
+
+        let [x, y] = WhitePoint::daylight(7504.0).unwrap().chromaticity();
+        assert_close(x, 0.29902, 1e-3);
+        assert_close(y, 0.31485, 1e-3);
+
+        // NaN temperatures are rejected rather than spreading
+        let error = WhitePoint::daylight(f64::NAN).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidComponentValue);
+    }
+
+    #[test]
+    fn test_checked_custom_white_point() {
+        let white = WhitePoint::custom(0.32, 0.34).unwrap();
+        assert_eq!(white.chromaticity(), [0.32, 0.34]);
+
+        // Chromaticities that would divide by zero or spread NaN are rejected
+        for (x, y) in [
+            (0.3, 0.0),
+            (0.3, -0.1),
+            (f64::NAN, 0.3),
+            (0.3, f64::INFINITY),
+            (0.8, 0.5),
+        ] {
+            let error = WhitePoint::custom(x, y).unwrap_err();
+            assert_eq!(error.kind, ErrorKind::InvalidComponentValue);
+        }
+    }
+
+    #[test]
+    fn test_xyz_adapt_preserves_alpha() {
+        let [x, y, z] = WhitePoint::D50.xyz();
+        let print = Xyz {
+            x,
+            y,
+            z,
+            alpha: 0.5,
+        };
+        let screen = print.adapt(WhitePoint::D50, WhitePoint::D65, AdaptationMethod::Bradford);
+        assert_eq!(screen.alpha, 0.5);
+        assert_eq!(Rgba::from(screen).red, 255);
+        assert_eq!(Rgba::from(screen).blue, 255);
+    }
+
+    #[test]
+    fn test_lab_with_custom_white_point() {
+        let white_point = WhitePoint::A;
+        let white = Lab::from_xyz(&rgba(255, 255, 255).into(), white_point);
+        assert_close(white.lightness, 100.0, 1e-9);
+        assert_close(white.a, 0.0, 1e-9);
+
+        // Lab relative to a custom white round trips with every method
+        let custom = WhitePoint::custom(0.32, 0.34).unwrap();
+        let color = rgba(12, 200, 180);
+        for method in METHODS {
+            let lab = Lab::from_xyz_with(&color.into(), custom, method);
+            assert_eq!(lab.white_point, custom);
+            assert_eq!(Rgba::from(lab.to_xyz_with(method)), color);
+        }
+    }
+}
@@ -0,0 +1,112 @@
+use color_parser::adjust::{ColorModel, Luma};
+use color_parser::{Oklch, Rgba, parse_hex_to_rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn hex(input: &str) -> Rgba {
+        parse_hex_to_rgba(input).unwrap()
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    // Expected values below are from the Sass documentation
+
+    #[test]
+    fn test_lighten_darken() {
+        assert_eq!(
+            hex("#880000").lighten(20.0, ColorModel::Hsl),
+            hex("#ee0000")
+        );
+        assert_eq!(hex("#b37399").darken(20.0, ColorModel::Hsl), hex("#7c4465"));
+        assert_eq!(
+            hex("#003366").darken(100.0, ColorModel::Hsl),
+            hex("#000000")
+        );
+        assert_eq!(
+            hex("#003366").lighten(100.0, ColorModel::Hsl),
+            hex("#ffffff")
+        );
+    }
+
+    #[test]
+    fn test_saturate_desaturate() {
+        assert_eq!(
+            hex("#cc6699").saturate(20.0, ColorModel::Hsl),
+            hex("#e05299")
+        );
+        assert_eq!(
+            hex("#d2e1dd").desaturate(30.0, ColorModel::Hsl),
+            hex("#dadada")
+        );
+        assert_eq!(
+            hex("#0e4982").saturate(100.0, ColorModel::Hsl),
+            hex("#004990")
+        );
+    }
+
+    #[test]
+    fn test_spin_and_complement() {
+        assert_eq!(hex("#6b717f").spin(60.0, ColorModel::Hsl), hex("#796b7f"));
+        assert_eq!(hex("#6b717f").spin(-60.0, ColorModel::Hsl), hex("#6b7f79"));
+        assert_eq!(hex("#6b717f").complement(ColorModel::Hsl), hex("#7f796b"));
+        assert_eq!(hex("#ff0000").spin(360.0, ColorModel::Hsl), hex("#ff0000"));
+    }
+
+    #[test]
+    fn test_invert() {
+        assert_eq!(hex("#b37399").invert(), hex("#4c8c66"));
+        assert_eq!(hex("#55000080").invert(), hex("#aaffff80"));
+    }
+
+    #[test]
+    fn test_grayscale() {
+        let orange = hex("#ff8800");
+        assert_eq!(orange.grayscale(Luma::Rec601), hex("#9c9c9c"));
+        assert_eq!(orange.grayscale(Luma::Rec709), hex("#979797"));
+        assert_eq!(hex("#ff880080").grayscale(Luma::Rec709).alpha, 128);
+
+        // Sass grayscale() is a full HSL desaturation
+        assert_eq!(
+            hex("#6b717f").desaturate(100.0, ColorModel::Hsl),
+            hex("#757575")
+        );
+    }
+
+    #[test]
+    fn test_fade() {
+        // LESS: fade(hsl(90, 90%, 50%), 10%)
+        assert_eq!(hex("#80f20d").fade(0.1), hex("#80f20d1a"));
+        assert_eq!(hex("#80f20d1a").fade(1.5).alpha, 255);
+    }
+
+    #[test]
+    fn test_oklch_model() {
+        // Lightness moves by percentage points of Oklch lightness
+        let pink = hex("#cc6699");
+        let darker = Oklch::from(pink.darken(20.0, ColorModel::Oklch));
+        assert_close(darker.lightness, Oklch::from(pink).lightness - 0.2, 5e-3);
+        assert_close(darker.hue, Oklch::from(pink).hue, 2.0);
+
+        let orange = hex("#ff8800");
+        let source = Oklch::from(orange);
+
+        // Rotating the hue keeps the perceived lightness, unlike HSL
+        let oklch_spun = Oklch::from(orange.spin(180.0, ColorModel::Oklch));
+        let hsl_spun = Oklch::from(orange.spin(180.0, ColorModel::Hsl));
+        assert_close(oklch_spun.lightness, source.lightness, 1e-2);
+        assert!((hsl_spun.lightness - source.lightness).abs() > 0.1);
+
+        // Desaturating fully leaves a gray of the same lightness
+        let gray = orange.desaturate(100.0, ColorModel::Oklch);
+        assert_eq!(gray.red, gray.green);
+        assert_eq!(gray.green, gray.blue);
+        assert_close(Oklch::from(gray).lightness, source.lightness, 5e-3);
+    }
+}
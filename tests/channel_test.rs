@@ -0,0 +1,258 @@
+use color_parser::{
+    Channel, ChannelOrder, Color, ColorSpace, DisplayP3, ErrorKind, GenericRgba, Hsl, ParseOptions,
+    Rgba, Rgba16, RgbaF32, RgbaF64, parse_hex_to_rgba, parse_hex_to_rgba16,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn gray<T: Channel>(value: T) -> GenericRgba<T> {
+        GenericRgba {
+            red: value,
+            green: value,
+            blue: value,
+            alpha: T::MAX,
+        }
+    }
+
+    #[test]
+    fn test_lossless_widening() {
+        for value in 0..=u8::MAX {
+            let color = gray(value);
+            let wide = Rgba16::from(color);
+            assert_eq!(wide.red, value as u16 * 257);
+            assert_eq!(wide.alpha, u16::MAX);
+            assert_eq!(wide.to_depth::<u8>(), color);
+            assert_eq!(RgbaF32::from(color).to_depth::<u8>(), color);
+            assert_eq!(RgbaF64::from(color).to_depth::<u8>(), color);
+        }
+
+        for value in 0..=u16::MAX {
+            let color = gray(value);
+            assert_eq!(RgbaF32::from(color).to_depth::<u16>(), color);
+            assert_eq!(RgbaF64::from(color).to_depth::<u16>(), color);
+        }
+
+        let color = RgbaF32 {
+            red: 0.1,
+            green: 1.5,
+            blue: -0.25,
+            alpha: 1.0,
+        };
+        assert_eq!(RgbaF64::from(color).to_depth::<f32>(), color);
+    }
+
+    #[test]
+    fn test_rounded_narrowing() {
+        // 16 to 8 bits is round(x / 257), which never ties since 257 is odd
+        for value in 0..=u16::MAX {
+            let narrow = gray(value).to_depth::<u8>();
+            assert_eq!(narrow.red as u32, (value as u32 + 128) / 257, "{value}");
+        }
+
+        // f64 to f32 rounds to the nearest f32
+        let color = gray(0.1f64).to_depth::<f32>();
+        assert_eq!(color.red, 0.1f32);
+    }
+
+    #[test]
+    fn test_float_channels() {
+        // Floats keep values outside [0, 1], and integers clamp them
+        let hdr = RgbaF32 {
+            red: 2.0,
+            green: 0.5,
+            blue: -0.5,
+            alpha: 1.0,
+        };
+        assert_eq!(
+            hdr.to_depth::<u8>(),
+            Rgba {
+                red: 255,
+                green: 128,
+                blue: 0,
+                alpha: 255
+            }
+        );
+        assert_eq!(
+            hdr.to_depth::<u16>(),
+            Rgba16 {
+                red: u16::MAX,
+                green: 32768,
+                blue: 0,
+                alpha: u16::MAX
+            }
+        );
+        assert_eq!(<f32 as Channel>::MAX, 1.0);
+        assert_eq!(<u16 as Channel>::MAX, u16::MAX);
+    }
+
+    #[test]
+    fn test_color_space_conversions() {
+        let hsl = Hsl {
+            hue: 120.0,
+            saturation: 100.0,
+            lightness: 25.0,
+            alpha: 1.0,
+        };
+        let wide: Rgba16 = hsl.convert();
+        assert_eq!(
+            wide,
+            Rgba16 {
+                red: 0,
+                green: 32768,
+                blue: 0,
+                alpha: u16::MAX
+            }
+        );
+        // The 8-bit color rounds 127.5 up, like the 16-bit one narrowed
+        assert_eq!(wide.to_depth::<u8>(), hsl.convert::<Rgba>());
+
+        // Float channels are not clamped to the sRGB gamut
+        let p3_red = DisplayP3 {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        let unclamped: RgbaF64 = p3_red.convert();
+        assert!(unclamped.red > 1.0);
+        assert!(unclamped.green < 0.0);
+        let back: DisplayP3 = unclamped.convert();
+        assert!((back.red - 1.0).abs() < 1e-9);
+        assert!(back.green.abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_hub_conversions() {
+        let wide = Rgba16 {
+            red: 0xffff,
+            green: 0x8801,
+            blue: 0x0001,
+            alpha: 0x8000,
+        };
+        let hsl = Hsl::from(wide);
+        assert_eq!(Rgba16::from(hsl), wide);
+        assert_eq!(Rgba::from(wide), wide.to_depth::<u8>());
+
+        // Color keeps the full precision of each type
+        let color = Color::from(wide);
+        assert_eq!(color, Color::Rgba16(wide));
+        assert_eq!(Rgba16::from(color), wide);
+        let hdr = RgbaF32 {
+            red: 1.5,
+            green: 0.25,
+            blue: -0.125,
+            alpha: 1.0,
+        };
+        assert_eq!(RgbaF32::from(Color::from(hdr)), hdr);
+        assert_eq!(RgbaF64::from(hdr), hdr.to_depth::<f64>());
+        assert_eq!(Rgba::from(Color::from(hdr)), hdr.to_depth::<u8>());
+    }
+
+    #[test]
+    fn test_16_bit_and_float_formatting() {
+        let wide = Rgba16 {
+            red: 0xffff,
+            green: 0x8888,
+            blue: 0x0001,
+            alpha: 0xffff,
+        };
+        assert_eq!(format!("{wide}"), "#ffff88880001");
+        assert_eq!(format!("{}", Color::Rgba16(wide)), "#ffff88880001");
+        assert_eq!(format!("{wide:#.2}"), "color(srgb 1.00 0.53 0.00)");
+        assert_eq!(format!("{wide:X}"), "#FF8800");
+        assert_eq!("#ffff88880001".parse::<Rgba16>().unwrap(), wide);
+        assert_eq!("rgb(255 136 0)".parse::<Rgba16>().unwrap().green, 0x8888);
+
+        // Error spans point into the original, untrimmed input
+        let error = "  #ffff8888000g ".parse::<Rgba16>().unwrap_err();
+        assert_eq!(error.input, "  #ffff8888000g ");
+        assert_eq!(error.span, 14..15);
+
+        let hdr = RgbaF64 {
+            red: 1.5,
+            green: 0.25,
+            blue: 0.0,
+            alpha: 0.5,
+        };
+        assert_eq!(format!("{hdr}"), "srgb(1.5, 0.25, 0, 0.5)");
+        assert_eq!(format!("{hdr:#}"), "color(srgb 1.5 0.25 0 / 0.5)");
+        assert_eq!(format!("{hdr:x}"), "#ff400080");
+        assert_eq!(format!("{hdr}").parse::<RgbaF64>().unwrap(), hdr);
+        assert_eq!(
+            format!("{hdr:#}").parse::<RgbaF32>().unwrap(),
+            hdr.to_depth()
+        );
+    }
+
+    #[test]
+    fn test_parse_16_bit_hex() {
+        let color = parse_hex_to_rgba16("#FFFF88000001").unwrap();
+        assert_eq!(
+            color,
+            Rgba16 {
+                red: 0xffff,
+                green: 0x8800,
+                blue: 0x0001,
+                alpha: 0xffff
+            }
+        );
+        let color = parse_hex_to_rgba16("#ffff880000018000").unwrap();
+        assert_eq!(color.alpha, 0x8000);
+
+        // 8-bit forms are widened losslessly
+        for input in ["#f80", "#f808", "#ff8800", "#ff880080"] {
+            let expected = Rgba16::from(parse_hex_to_rgba(input).unwrap());
+            assert_eq!(parse_hex_to_rgba16(input).unwrap(), expected, "{input}");
+        }
+
+        // The 8-bit parser does not accept 12 or 16 digits
+        let error = parse_hex_to_rgba("#ffff88000001").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidLength);
+    }
+
+    #[test]
+    fn test_parse_16_bit_hex_errors() {
+        let error = parse_hex_to_rgba16("#ffff8800000").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidLength);
+        assert_eq!(error.span, 1..12);
+        assert_eq!(error.expected, ["3, 4, 6, 8, 12 or 16 hex digits"]);
+
+        let error = parse_hex_to_rgba16("#ffff8800000g").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+        assert_eq!(error.span, 12..13);
+
+        let options = ParseOptions::strict().allow_missing_alpha(false);
+        let error = options.parse_hex16("#ffff88000001").unwrap_err();
+        assert_eq!(error.expected, ["8 or 16 hex digits"]);
+        assert!(options.parse_hex16("#FFFF880000018000").is_err());
+        assert!(options.parse_hex16("#ffff880000018000").is_ok());
+    }
+
+    #[test]
+    fn test_16_bit_hex_formatting() {
+        let color = Rgba16 {
+            red: 0xffff,
+            green: 0x8800,
+            blue: 0x0001,
+            alpha: 0x8000,
+        };
+        assert_eq!(color.to_hex(), "#ffff880000018000");
+        assert_eq!(
+            Rgba16 {
+                alpha: 0xffff,
+                ..color
+            }
+            .to_hex(),
+            "#ffff88000001"
+        );
+
+        for order in ChannelOrder::ALL {
+            let options = ParseOptions::new().order(order);
+            let hex = color.to_hex_in(order);
+            assert_eq!(options.parse_hex16(&hex).unwrap(), color, "{hex}");
+        }
+        assert_eq!(color.to_hex_in(ChannelOrder::Bgra), "#00018800ffff8000");
+    }
+}
@@ -0,0 +1,89 @@
+use color_parser::{
+    Cmyk, Color, ColorSpace, Hsl, Hsv, Rgba, Srgb, parse_css_color, parse_rgb_to_cmyk,
+    parse_rgb_to_hsl, parse_rgb_to_hsv,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: a,
+        }
+    }
+
+    #[test]
+    fn test_into_matches_free_functions() {
+        let color = rgba(173, 216, 230, 255);
+
+        let hsl: Hsl = color.into();
+        let hsv: Hsv = color.into();
+        let cmyk: Cmyk = color.into();
+
+        assert_eq!(hsl, parse_rgb_to_hsl(&color).unwrap());
+        assert_eq!(hsv, parse_rgb_to_hsv(&color).unwrap());
+        assert_eq!(cmyk, parse_rgb_to_cmyk(&color).unwrap());
+    }
+
+    #[test]
+    fn test_conversions_between_non_rgba_spaces() {
+        let hsl = Hsl {
+            hue: 0.0,
+            saturation: 100.0,
+            lightness: 50.0,
+            alpha: 0.5,
+        };
+
+        let hsv = Hsv::from(hsl);
+        assert_eq!(hsv.saturation, 100.0);
+        assert_eq!(hsv.value, 100.0);
+        assert_eq!(hsv.alpha, 0.5);
+
+        let cmyk: Cmyk = hsv.into();
+        assert_eq!(cmyk.magenta, 100.0);
+        assert_eq!(cmyk.yellow, 100.0);
+
+        assert_eq!(Rgba::from(cmyk), rgba(255, 0, 0, 128));
+    }
+
+    #[test]
+    fn test_srgb_hub() {
+        let srgb = Srgb::from(rgba(255, 0, 51, 255));
+        assert_eq!(srgb.red, 1.0);
+        assert_eq!(srgb.blue, 0.2);
+
+        // Out-of-gamut hub values are clamped when converted to Rgba
+        let wide = Srgb {
+            red: 1.2,
+            green: -0.1,
+            blue: 0.5,
+            alpha: 1.0,
+        };
+        assert_eq!(Rgba::from(wide), rgba(255, 0, 128, 255));
+    }
+
+    #[test]
+    fn test_convert_method() {
+        let color = rgba(0, 0, 255, 255);
+        let hsl: Hsl = color.convert();
+        assert_eq!(hsl.hue, 240.0);
+    }
+
+    #[test]
+    fn test_color_enum() {
+        let color = Color::from(rgba(0, 255, 0, 255));
+        assert_eq!(color, Color::Rgba(rgba(0, 255, 0, 255)));
+
+        let hsv: Hsv = color.into();
+        assert_eq!(hsv.hue, 120.0);
+
+        let parsed = parse_css_color("hsl(240 100% 50%)").unwrap();
+        assert!(matches!(parsed, Color::Hsl(_)));
+        assert_eq!(Rgba::from(parsed), rgba(0, 0, 255, 255));
+        assert_eq!(parsed.to_rgba(), rgba(0, 0, 255, 255));
+    }
+}
@@ -0,0 +1,223 @@
+use color_parser::composite::{BlendMode, CompositeOperator, blend, composite};
+use color_parser::{Rgba, Srgb};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn srgb(red: f64, green: f64, blue: f64, alpha: f64) -> Srgb {
+        Srgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn gray(value: f64, alpha: f64) -> Srgb {
+        srgb(value, value, value, alpha)
+    }
+
+    fn assert_srgb(actual: Srgb, expected: Srgb) {
+        let pairs = [
+            (actual.red, expected.red),
+            (actual.green, expected.green),
+            (actual.blue, expected.blue),
+            (actual.alpha, expected.alpha),
+        ];
+        for (a, e) in pairs {
+            assert!((a - e).abs() < 1e-6, "{actual:?} is not {expected:?}");
+        }
+    }
+
+    #[test]
+    fn test_porter_duff_operators() {
+        // αs = 0.75 and αb = 0.25, so every operator gives a different result
+        let source = srgb(1.0, 0.0, 0.0, 0.75);
+        let backdrop = srgb(0.0, 0.0, 1.0, 0.25);
+        let cases = [
+            (CompositeOperator::Clear, srgb(0.0, 0.0, 0.0, 0.0)),
+            (CompositeOperator::Copy, source),
+            (CompositeOperator::Destination, backdrop),
+            (
+                CompositeOperator::Over,
+                srgb(0.75 / 0.8125, 0.0, 0.0625 / 0.8125, 0.8125),
+            ),
+            (
+                CompositeOperator::DestinationOver,
+                srgb(0.5625 / 0.8125, 0.0, 0.25 / 0.8125, 0.8125),
+            ),
+            (CompositeOperator::In, srgb(1.0, 0.0, 0.0, 0.1875)),
+            (
+                CompositeOperator::DestinationIn,
+                srgb(0.0, 0.0, 1.0, 0.1875),
+            ),
+            (CompositeOperator::Out, srgb(1.0, 0.0, 0.0, 0.5625)),
+            (
+                CompositeOperator::DestinationOut,
+                srgb(0.0, 0.0, 1.0, 0.0625),
+            ),
+            (CompositeOperator::Atop, srgb(0.75, 0.0, 0.25, 0.25)),
+            (
+                CompositeOperator::DestinationAtop,
+                srgb(0.75, 0.0, 0.25, 0.75),
+            ),
+            (CompositeOperator::Xor, srgb(0.9, 0.0, 0.1, 0.625)),
+            (CompositeOperator::Lighter, srgb(0.75, 0.0, 0.25, 1.0)),
+        ];
+
+        for (operator, expected) in cases {
+            let result = composite(&source, &backdrop, operator);
+            assert_srgb(result, expected);
+        }
+    }
+
+    #[test]
+    fn test_opaque_over() {
+        // An opaque source hides the backdrop, a transparent one shows it
+        let backdrop = srgb(0.2, 0.4, 0.6, 1.0);
+        let opaque = srgb(1.0, 0.5, 0.0, 1.0);
+        let transparent = srgb(1.0, 0.5, 0.0, 0.0);
+
+        assert_srgb(
+            composite(&opaque, &backdrop, CompositeOperator::Over),
+            opaque,
+        );
+        assert_srgb(
+            composite(&transparent, &backdrop, CompositeOperator::Over),
+            backdrop,
+        );
+    }
+
+    #[test]
+    fn test_separable_blend_modes() {
+        // Each mode with (Cs, Cb) = (0.75, 0.25) and (0.25, 0.75)
+        let cases = [
+            (BlendMode::Normal, 0.75, 0.25),
+            (BlendMode::Multiply, 0.1875, 0.1875),
+            (BlendMode::Screen, 0.8125, 0.8125),
+            (BlendMode::Overlay, 0.375, 0.625),
+            (BlendMode::Darken, 0.25, 0.25),
+            (BlendMode::Lighten, 0.75, 0.75),
+            (BlendMode::ColorDodge, 1.0, 1.0),
+            (BlendMode::ColorBurn, 0.0, 0.0),
+            (BlendMode::HardLight, 0.625, 0.375),
+            (BlendMode::SoftLight, 0.375, 0.65625),
+            (BlendMode::Difference, 0.5, 0.5),
+            (BlendMode::Exclusion, 0.625, 0.625),
+        ];
+
+        for (mode, light_on_dark, dark_on_light) in cases {
+            let result = blend(&gray(0.75, 1.0), &gray(0.25, 1.0), mode);
+            assert_srgb(result, gray(light_on_dark, 1.0));
+
+            let result = blend(&gray(0.25, 1.0), &gray(0.75, 1.0), mode);
+            assert_srgb(result, gray(dark_on_light, 1.0));
+        }
+    }
+
+    #[test]
+    fn test_dodge_and_burn_edge_cases() {
+        let black = gray(0.0, 1.0);
+        let white = gray(1.0, 1.0);
+
+        // A black backdrop stays black under color-dodge, even with a white source
+        assert_srgb(blend(&white, &black, BlendMode::ColorDodge), black);
+        // A white backdrop stays white under color-burn, even with a black source
+        assert_srgb(blend(&black, &white, BlendMode::ColorBurn), white);
+        // Soft light with Cb > 0.25 uses the square root
+        assert_srgb(
+            blend(&white, &gray(0.5, 1.0), BlendMode::SoftLight),
+            gray(0.5_f64.sqrt(), 1.0),
+        );
+    }
+
+    #[test]
+    fn test_non_separable_blend_modes() {
+        let red = srgb(1.0, 0.0, 0.0, 1.0);
+        let blue = srgb(0.0, 0.0, 1.0, 1.0);
+        let orange = srgb(1.0, 0.5, 0.0, 1.0);
+        let mid_gray = gray(0.5, 1.0);
+
+        // SetLum(red, 0.5) overflows and is clipped toward the luminosity
+        let clipped = 0.5 - 0.3 * 0.5 / 0.7;
+        assert_srgb(
+            blend(&red, &mid_gray, BlendMode::Color),
+            srgb(1.0, clipped, clipped, 1.0),
+        );
+        assert_srgb(
+            blend(&red, &mid_gray, BlendMode::Luminosity),
+            gray(0.3, 1.0),
+        );
+
+        // A gray backdrop has no saturation for the hue to keep
+        assert_srgb(blend(&red, &mid_gray, BlendMode::Hue), mid_gray);
+        assert_srgb(blend(&red, &mid_gray, BlendMode::Saturation), mid_gray);
+
+        // The hue of blue with the saturation and luminosity of orange: SetLum
+        // lifts blue by Lum(orange) - 0.11 and ClipColor pulls blue back to 1
+        let lum = 0.3 + 0.59 * 0.5;
+        let low = lum - 0.11 * (1.0 - lum) / 0.89;
+        assert_srgb(
+            blend(&blue, &orange, BlendMode::Hue),
+            srgb(low, low, 1.0, 1.0),
+        );
+    }
+
+    #[test]
+    fn test_blend_with_alpha() {
+        let source = gray(0.75, 0.5);
+
+        // Opaque backdrop: B(Cb, Cs) composited over it
+        let result = blend(&source, &gray(0.25, 1.0), BlendMode::Multiply);
+        assert_srgb(result, gray(0.21875, 1.0));
+
+        // Translucent backdrop: the source is partly unblended
+        let result = blend(&source, &gray(0.25, 0.5), BlendMode::Multiply);
+        assert_srgb(result, gray(0.296875 / 0.75, 0.75));
+
+        // Transparent backdrop: the source is not blended at all
+        let result = blend(&source, &gray(0.25, 0.0), BlendMode::Multiply);
+        assert_srgb(result, source);
+    }
+
+    #[test]
+    fn test_rgba_methods() {
+        let red = Rgba {
+            red: 255,
+            green: 0,
+            blue: 0,
+            alpha: 128,
+        };
+        let white = Rgba {
+            red: 255,
+            green: 255,
+            blue: 255,
+            alpha: 255,
+        };
+
+        assert_eq!(
+            red.over(&white),
+            Rgba {
+                red: 255,
+                green: 127,
+                blue: 127,
+                alpha: 255
+            }
+        );
+        assert_eq!(
+            red.over(&white),
+            red.composite(&white, CompositeOperator::Over)
+        );
+        assert_eq!(red.blend(&white, BlendMode::Normal), red.over(&white));
+        assert_eq!(
+            red.blend(&white, BlendMode::Difference),
+            Rgba {
+                red: 127,
+                green: 255,
+                blue: 255,
+                alpha: 255
+            }
+        );
+    }
+}
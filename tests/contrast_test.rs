@@ -0,0 +1,159 @@
+use color_parser::Rgba;
+use color_parser::contrast::{Polarity, TextSize, WcagLevel};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: a,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_relative_luminance() {
+        assert_eq!(rgba(0, 0, 0, 255).relative_luminance(), 0.0);
+        assert_eq!(rgba(255, 255, 255, 255).relative_luminance(), 1.0);
+        assert_close(rgba(255, 0, 0, 255).relative_luminance(), 0.2126, 1e-12);
+        assert_close(rgba(0, 255, 0, 255).relative_luminance(), 0.7152, 1e-12);
+        assert_close(rgba(0, 0, 255, 255).relative_luminance(), 0.0722, 1e-12);
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        let black = rgba(0, 0, 0, 255);
+        let white = rgba(255, 255, 255, 255);
+        assert_eq!(black.contrast_ratio(&white), 21.0);
+        assert_eq!(white.contrast_ratio(&white), 1.0);
+
+        // The ratio does not depend on which color is the text
+        let gray = rgba(119, 119, 119, 255);
+        assert_close(gray.contrast_ratio(&white), 4.4781, 1e-4);
+        assert_eq!(gray.contrast_ratio(&white), white.contrast_ratio(&gray));
+    }
+
+    #[test]
+    fn test_thresholds() {
+        let white = rgba(255, 255, 255, 255);
+
+        // #767676 is the lightest gray that passes AA on white, #777777 is not
+        let passing = rgba(118, 118, 118, 255).wcag_contrast(&white);
+        assert!(passing.passes(WcagLevel::Aa, TextSize::Normal));
+        let failing = rgba(119, 119, 119, 255).wcag_contrast(&white);
+        assert!(!failing.passes(WcagLevel::Aa, TextSize::Normal));
+        assert!(failing.passes(WcagLevel::Aa, TextSize::Large));
+        assert!(!failing.passes(WcagLevel::Aaa, TextSize::Large));
+
+        let black = rgba(0, 0, 0, 255).wcag_contrast(&white);
+        assert!(black.passes(WcagLevel::Aaa, TextSize::Normal));
+    }
+
+    #[test]
+    fn test_required_ratios() {
+        assert_eq!(WcagLevel::Aa.required_ratio(TextSize::Normal), 4.5);
+        assert_eq!(WcagLevel::Aa.required_ratio(TextSize::Large), 3.0);
+        assert_eq!(WcagLevel::Aaa.required_ratio(TextSize::Normal), 7.0);
+        assert_eq!(WcagLevel::Aaa.required_ratio(TextSize::Large), 4.5);
+    }
+
+    #[test]
+    fn test_translucent_foreground_is_composited() {
+        let white = rgba(255, 255, 255, 255);
+        let contrast = rgba(0, 0, 0, 128).wcag_contrast(&white);
+        assert_eq!(contrast.foreground, rgba(127, 127, 127, 255));
+        assert_eq!(contrast.background, white);
+        assert!(contrast.ratio < 21.0);
+        assert_close(
+            contrast.ratio,
+            rgba(127, 127, 127, 255).contrast_ratio(&white),
+            0.02,
+        );
+
+        // A fully transparent text color is invisible
+        assert_eq!(rgba(0, 0, 0, 0).contrast_ratio(&white), 1.0);
+    }
+
+    #[test]
+    fn test_translucent_background_is_composited_over_white() {
+        let black = rgba(0, 0, 0, 255);
+        let contrast = black.wcag_contrast(&rgba(0, 0, 0, 0));
+        assert_eq!(contrast.background, rgba(255, 255, 255, 255));
+        assert_eq!(contrast.ratio, 21.0);
+    }
+
+    #[test]
+    fn test_apca_reference_values() {
+        // Published test values from the apca-w3 package
+        let cases = [
+            ((0x88, 0x88, 0x88), (0xff, 0xff, 0xff), 63.056469930209424),
+            ((0xff, 0xff, 0xff), (0x88, 0x88, 0x88), -68.54146436644962),
+            ((0x00, 0x00, 0x00), (0xaa, 0xaa, 0xaa), 58.146262578561334),
+            ((0xaa, 0xaa, 0xaa), (0x00, 0x00, 0x00), -56.24113336839742),
+            ((0x11, 0x22, 0x33), (0xdd, 0xee, 0xff), 91.66830811481631),
+            ((0xdd, 0xee, 0xff), (0x11, 0x22, 0x33), -93.06770049484275),
+            ((0x00, 0x00, 0x00), (0xff, 0xff, 0xff), 106.04067321268862),
+            ((0xff, 0xff, 0xff), (0x00, 0x00, 0x00), -107.88473318309848),
+        ];
+        for ((tr, tg, tb), (br, bg, bb), expected) in cases {
+            let text = rgba(tr, tg, tb, 255);
+            let background = rgba(br, bg, bb, 255);
+            assert_close(text.apca_contrast(&background).lc, expected, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_apca_polarity() {
+        let black = rgba(0, 0, 0, 255);
+        let white = rgba(255, 255, 255, 255);
+        assert_eq!(
+            black.apca_contrast(&white).polarity(),
+            Polarity::DarkOnLight
+        );
+        assert_eq!(
+            white.apca_contrast(&black).polarity(),
+            Polarity::LightOnDark
+        );
+
+        // Near-identical colors have no contrast at all
+        let gray = rgba(128, 128, 128, 255);
+        assert_eq!(gray.apca_contrast(&gray).lc, 0.0);
+        assert_eq!(gray.apca_contrast(&rgba(130, 130, 130, 255)).lc, 0.0);
+    }
+
+    #[test]
+    fn test_apca_min_font_size() {
+        let white = rgba(255, 255, 255, 255);
+
+        // Lc 63 falls in the Lc 60 row
+        let contrast = rgba(0x88, 0x88, 0x88, 255).apca_contrast(&white);
+        assert_eq!(contrast.min_font_size(100), Some(72.0));
+        assert_eq!(contrast.min_font_size(400), Some(24.0));
+        assert_eq!(contrast.min_font_size(449), Some(24.0));
+        assert_eq!(contrast.min_font_size(700), Some(16.0));
+        assert_eq!(contrast.min_font_size(1000), Some(18.0));
+
+        // Lc 106 falls in the Lc 105 row
+        let contrast = rgba(0, 0, 0, 255).apca_contrast(&white);
+        assert_eq!(contrast.min_font_size(400), Some(14.5));
+
+        // Light text on dark uses the magnitude of Lc: -68.5 falls in the Lc 65 row
+        let contrast = white.apca_contrast(&rgba(0x88, 0x88, 0x88, 255));
+        assert_eq!(contrast.min_font_size(400), Some(21.75));
+
+        // Too little contrast for text
+        let contrast = rgba(0xdd, 0xdd, 0xdd, 255).apca_contrast(&white);
+        assert!(contrast.lc < 25.0);
+        assert_eq!(contrast.min_font_size(400), None);
+    }
+}
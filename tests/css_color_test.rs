@@ -0,0 +1,158 @@
+use color_parser::{Color, ColorParserError, ErrorKind, Hsl, Rgba, parse_css_color};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: a,
+        }
+    }
+
+    /// Asserts that every input parses and resolves to the expected `Rgba`.
+    fn assert_parses(cases: &[(&str, Rgba)]) {
+        for (input, expected) in cases {
+            let color = parse_css_color(input)
+                .unwrap_or_else(|e| panic!("{input:?} failed to parse: {e}"))
+                .to_rgba();
+            assert_eq!(color, *expected, "{input:?}");
+        }
+    }
+
+    #[test]
+    fn test_rgb_valid() {
+        assert_parses(&[
+            ("rgb(10, 20, 30)", rgba(10, 20, 30, 255)),
+            ("rgba(10, 20, 30, 0.4)", rgba(10, 20, 30, 102)),
+            ("rgb(10 20 30)", rgba(10, 20, 30, 255)),
+            ("rgb(10 20 30 / 0.4)", rgba(10, 20, 30, 102)),
+            ("rgba(10 20 30 / 40%)", rgba(10, 20, 30, 102)),
+            ("rgb(10 20 30/.4)", rgba(10, 20, 30, 102)),
+            ("rgb(2.5, 3.4, 4.6)", rgba(3, 3, 5, 255)),
+            ("rgb(1e1, 2e1, 3e1)", rgba(10, 20, 30, 255)),
+            ("rgb(10% 20% 30%)", rgba(26, 51, 77, 255)),
+            ("rgb(10% 20 30)", rgba(26, 20, 30, 255)),
+            ("RGB(10, 20, 30)", rgba(10, 20, 30, 255)),
+            ("  rgb( 10 ,20 , 30 )  ", rgba(10, 20, 30, 255)),
+        ]);
+    }
+
+    #[test]
+    fn test_rgb_clamping() {
+        assert_parses(&[
+            ("rgb(-2, 3, 4)", rgba(0, 3, 4, 255)),
+            ("rgb(100, 200, 300)", rgba(100, 200, 255, 255)),
+            ("rgb(100%, 200%, 300%)", rgba(255, 255, 255, 255)),
+            ("rgb(20, 10, 0, -10)", rgba(20, 10, 0, 0)),
+            ("rgb(20 10 0 / 2)", rgba(20, 10, 0, 255)),
+        ]);
+    }
+
+    #[test]
+    fn test_none_keyword() {
+        assert_parses(&[
+            ("rgb(none none none)", rgba(0, 0, 0, 255)),
+            ("rgb(10 20 30 / none)", rgba(10, 20, 30, 0)),
+            ("hsl(none none none)", rgba(0, 0, 0, 255)),
+            ("hsl(none 100% 50%)", rgba(255, 0, 0, 255)),
+            ("hwb(none 0% 0%)", rgba(255, 0, 0, 255)),
+        ]);
+    }
+
+    #[test]
+    fn test_hsl_valid() {
+        assert_parses(&[
+            ("hsl(120 30% 50%)", rgba(89, 166, 89, 255)),
+            ("hsl(120, 30%, 50%, 0.5)", rgba(89, 166, 89, 128)),
+            ("hsla(120, 30%, 50%)", rgba(89, 166, 89, 255)),
+            ("hsl(120 30 50)", rgba(89, 166, 89, 255)),
+            ("hsl(120deg 100% 50%)", rgba(0, 255, 0, 255)),
+            ("HSL(120DEG 100% 50%)", rgba(0, 255, 0, 255)),
+            ("hsl(3.14159rad 100% 50%)", rgba(0, 255, 255, 255)),
+            ("hsl(200grad 100% 50%)", rgba(0, 255, 255, 255)),
+            ("hsl(0.5turn 100% 50%)", rgba(0, 255, 255, 255)),
+            ("hsl(-120 100% 50%)", rgba(0, 0, 255, 255)),
+            ("hsl(480 100% 50%)", rgba(0, 255, 0, 255)),
+        ]);
+    }
+
+    #[test]
+    fn test_hsl_yields_hsl() {
+        let color = parse_css_color("hsl(480deg 30% 150% / 25%)").unwrap();
+        assert_eq!(
+            color,
+            Color::Hsl(Hsl {
+                hue: 120.0,
+                saturation: 30.0,
+                lightness: 100.0,
+                alpha: 0.25,
+            })
+        );
+    }
+
+    #[test]
+    fn test_hwb_valid() {
+        assert_parses(&[
+            ("hwb(120 30% 50%)", rgba(77, 128, 77, 255)),
+            ("hwb(90deg 0% 0% / 0.2)", rgba(128, 255, 0, 51)),
+            ("hwb(0 100% 100%)", rgba(128, 128, 128, 255)),
+            ("hwb(0 0% 100%)", rgba(0, 0, 0, 255)),
+        ]);
+    }
+
+    #[test]
+    fn test_hex_passthrough() {
+        assert_parses(&[
+            ("#ff8800", rgba(255, 136, 0, 255)),
+            ("#f80c", rgba(255, 136, 0, 204)),
+        ]);
+    }
+
+    #[test]
+    fn test_invalid_syntax() {
+        for input in [
+            "rgb(10, 20 30)",
+            "rgb(10%, 20, 30)",
+            "rgb(none, 0, 0)",
+            "rgb(10, 20, 30,)",
+            "rgb(10 20 30 /)",
+            "rgb(10 20 30 / 0.5 / 1)",
+            "rgb(10 20)",
+            "rgb(10 20 30 40)",
+            "rgb(10px 20 30)",
+            "rgb(10 20 30",
+            "rgb(10, 20, 30 / 0.5)",
+            "hsl(120, 100, 50)",
+            "hsl(120px 100% 50%)",
+            "hwb(0, 0%, 0%)",
+            "rgb(1.2.3)",
+            "",
+        ] {
+            assert!(
+                matches!(
+                    parse_css_color(input),
+                    Err(ColorParserError {
+                        kind: ErrorKind::InvalidSyntax,
+                        ..
+                    })
+                ),
+                "{input:?} should be rejected"
+            );
+        }
+    }
+
+    #[test]
+    fn test_unknown_function() {
+        assert!(matches!(
+            parse_css_color("foo(1 2 3)"),
+            Err(ColorParserError {
+                kind: ErrorKind::UnknownFunction,
+                ..
+            })
+        ));
+    }
+}
@@ -0,0 +1,140 @@
+use color_parser::Rgba;
+use color_parser::cvd::{CvdMethod, Deficiency};
+use color_parser::delta_e::DeltaE;
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const DEFICIENCIES: [Deficiency; 3] =
+        [Deficiency::Protan, Deficiency::Deutan, Deficiency::Tritan];
+    const METHODS: [CvdMethod; 3] = [CvdMethod::Brettel, CvdMethod::Vienot, CvdMethod::Machado];
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_near(actual: Rgba, expected: Rgba) {
+        let close = |a: u8, b: u8| a.abs_diff(b) <= 1;
+        assert!(
+            close(actual.red, expected.red)
+                && close(actual.green, expected.green)
+                && close(actual.blue, expected.blue)
+                && actual.alpha == expected.alpha,
+            "{actual:?} is not close to {expected:?}"
+        );
+    }
+
+    #[test]
+    fn test_zero_severity_is_normal_vision() {
+        let color = rgba(255, 136, 0);
+        for deficiency in DEFICIENCIES {
+            for method in METHODS {
+                assert_eq!(color.simulate_cvd(deficiency, 0.0, method), color);
+            }
+        }
+    }
+
+    #[test]
+    fn test_neutral_colors_are_unchanged() {
+        for gray in [rgba(0, 0, 0), rgba(128, 128, 128), rgba(255, 255, 255)] {
+            for deficiency in DEFICIENCIES {
+                for method in METHODS {
+                    assert_near(gray.simulate_cvd(deficiency, 1.0, method), gray);
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_machado_protanopia() {
+        // Red maps to the first column of the severity 1.0 matrix in linear light
+        let red = rgba(255, 0, 0).simulate_cvd(Deficiency::Protan, 1.0, CvdMethod::Machado);
+        assert_eq!(red, rgba(109, 95, 0));
+    }
+
+    #[test]
+    fn test_machado_interpolates_between_steps() {
+        let color = rgba(200, 40, 90);
+        let low = color.simulate_cvd(Deficiency::Deutan, 0.5, CvdMethod::Machado);
+        let mid = color.simulate_cvd(Deficiency::Deutan, 0.55, CvdMethod::Machado);
+        let high = color.simulate_cvd(Deficiency::Deutan, 0.6, CvdMethod::Machado);
+        let between = |a: u8, b: u8, c: u8| a.min(c) <= b && b <= a.max(c);
+        assert!(between(low.red, mid.red, high.red));
+        assert!(between(low.green, mid.green, high.green));
+        assert!(between(low.blue, mid.blue, high.blue));
+
+        // Severity is clamped
+        assert_eq!(
+            color.simulate_cvd(Deficiency::Deutan, 2.0, CvdMethod::Machado),
+            color.simulate_cvd(Deficiency::Deutan, 1.0, CvdMethod::Machado)
+        );
+    }
+
+    #[test]
+    fn test_dichromat_simulation_is_a_projection() {
+        // Simulating a color a dichromat already sees changes nothing
+        let color = rgba(30, 160, 220);
+        for deficiency in DEFICIENCIES {
+            for method in [CvdMethod::Brettel, CvdMethod::Vienot] {
+                let once = color.simulate_cvd(deficiency, 1.0, method);
+                assert_near(once.simulate_cvd(deficiency, 1.0, method), once);
+            }
+        }
+    }
+
+    #[test]
+    fn test_red_green_confusion() {
+        let (red, green) = (rgba(200, 60, 40), rgba(80, 140, 40));
+        let original = DeltaE::Cie2000.difference(&red, &green);
+        for deficiency in [Deficiency::Protan, Deficiency::Deutan] {
+            let simulated = DeltaE::Cie2000.difference(
+                &red.simulate_cvd(deficiency, 1.0, CvdMethod::Brettel),
+                &green.simulate_cvd(deficiency, 1.0, CvdMethod::Brettel),
+            );
+            assert!(simulated < original / 2.0);
+        }
+    }
+
+    #[test]
+    fn test_daltonize() {
+        let gray = rgba(128, 128, 128);
+        assert_near(gray.daltonize(Deficiency::Protan, 1.0), gray);
+
+        // Daltonized colors are easier to tell apart for the simulated viewer
+        let (red, green) = (rgba(200, 60, 40), rgba(80, 140, 40));
+        let seen = |color: Rgba| color.simulate_cvd(Deficiency::Deutan, 1.0, CvdMethod::Machado);
+        let before = DeltaE::Cie2000.difference(&seen(red), &seen(green));
+        let after = DeltaE::Cie2000.difference(
+            &seen(red.daltonize(Deficiency::Deutan, 1.0)),
+            &seen(green.daltonize(Deficiency::Deutan, 1.0)),
+        );
+        assert!(after > before);
+
+        // There is no published correction for tritan deficiencies
+        let blue = rgba(40, 80, 200);
+        assert_eq!(blue.daltonize(Deficiency::Tritan, 1.0), blue);
+    }
+
+    #[test]
+    fn test_alpha_is_preserved() {
+        let color = Rgba {
+            red: 255,
+            green: 0,
+            blue: 0,
+            alpha: 100,
+        };
+        assert_eq!(
+            color
+                .simulate_cvd(Deficiency::Tritan, 1.0, CvdMethod::Brettel)
+                .alpha,
+            100
+        );
+        assert_eq!(color.daltonize(Deficiency::Tritan, 1.0).alpha, 100);
+    }
+}
@@ -0,0 +1,190 @@
+use color_parser::delta_e::{
+    Cie94Application, DeltaE, delta_e_cmc, delta_e_ok, delta_e76, delta_e94, delta_e2000,
+};
+use color_parser::{Lab, Oklab, Rgba, WhitePoint};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    /// CIEDE2000 test data from Sharma, Wu and Dalal (2005):
+    /// L1, a1, b1, L2, a2, b2 and the expected ΔE00.
+    const SHARMA: [[f64; 7]; 34] = [
+        [50.0000, 2.6772, -79.7751, 50.0000, 0.0000, -82.7485, 2.0425],
+        [50.0000, 3.1571, -77.2803, 50.0000, 0.0000, -82.7485, 2.8615],
+        [50.0000, 2.8361, -74.0200, 50.0000, 0.0000, -82.7485, 3.4412],
+        [
+            50.0000, -1.3802, -84.2814, 50.0000, 0.0000, -82.7485, 1.0000,
+        ],
+        [
+            50.0000, -1.1848, -84.8006, 50.0000, 0.0000, -82.7485, 1.0000,
+        ],
+        [
+            50.0000, -0.9009, -85.5211, 50.0000, 0.0000, -82.7485, 1.0000,
+        ],
+        [50.0000, 0.0000, 0.0000, 50.0000, -1.0000, 2.0000, 2.3669],
+        [50.0000, -1.0000, 2.0000, 50.0000, 0.0000, 0.0000, 2.3669],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0009, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0010, 7.1792],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0011, 7.2195],
+        [50.0000, 2.4900, -0.0010, 50.0000, -2.4900, 0.0012, 7.2195],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0009, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0010, -2.4900, 4.8045],
+        [50.0000, -0.0010, 2.4900, 50.0000, 0.0011, -2.4900, 4.7461],
+        [50.0000, 2.5000, 0.0000, 50.0000, 0.0000, -2.5000, 4.3065],
+        [50.0000, 2.5000, 0.0000, 73.0000, 25.0000, -18.0000, 27.1492],
+        [50.0000, 2.5000, 0.0000, 61.0000, -5.0000, 29.0000, 22.8977],
+        [50.0000, 2.5000, 0.0000, 56.0000, -27.0000, -3.0000, 31.9030],
+        [50.0000, 2.5000, 0.0000, 58.0000, 24.0000, 15.0000, 19.4535],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.1736, 0.5854, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2972, 0.0000, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 1.8634, 0.5757, 1.0000],
+        [50.0000, 2.5000, 0.0000, 50.0000, 3.2592, 0.3350, 1.0000],
+        [
+            60.2574, -34.0099, 36.2677, 60.4626, -34.1751, 39.4387, 1.2644,
+        ],
+        [
+            63.0109, -31.0961, -5.8663, 62.8187, -29.7946, -4.0864, 1.2630,
+        ],
+        [61.2901, 3.7196, -5.3901, 61.4292, 2.2480, -4.9620, 1.8731],
+        [35.0831, -44.1164, 3.7933, 35.0232, -40.0716, 1.5901, 1.8645],
+        [
+            22.7233, 20.0904, -46.6940, 23.0331, 14.9730, -42.5619, 2.0373,
+        ],
+        [36.4612, 47.8580, 18.3852, 36.2715, 50.5065, 21.2231, 1.4146],
+        [90.8027, -2.0831, 1.4410, 91.1528, -1.6435, 0.0447, 1.4441],
+        [90.9257, -0.5406, -0.9208, 88.6381, -0.8985, -0.7239, 1.5381],
+        [6.7747, -0.2908, -2.4247, 5.8714, -0.0985, -2.2286, 0.6377],
+        [2.0776, 0.0795, -1.1350, 0.9033, -0.0636, -0.5514, 0.9082],
+    ];
+
+    fn lab(lightness: f64, a: f64, b: f64) -> Lab {
+        Lab {
+            lightness,
+            a,
+            b,
+            alpha: 1.0,
+            white_point: WhitePoint::D50,
+        }
+    }
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_delta_e2000_sharma_dataset() {
+        for [l1, a1, b1, l2, a2, b2, expected] in SHARMA {
+            let (first, second) = (lab(l1, a1, b1), lab(l2, a2, b2));
+            assert_close(delta_e2000(&first, &second), expected, 1e-4);
+            // CIEDE2000 is symmetric
+            assert_close(delta_e2000(&second, &first), expected, 1e-4);
+        }
+    }
+
+    #[test]
+    fn test_delta_e76() {
+        assert_eq!(delta_e76(&lab(50.0, 0.0, 0.0), &lab(53.0, 4.0, 0.0)), 5.0);
+        assert_eq!(
+            delta_e76(&lab(50.0, 10.0, -10.0), &lab(50.0, 10.0, -10.0)),
+            0.0
+        );
+    }
+
+    #[test]
+    fn test_delta_e94() {
+        // A pure lightness difference is divided by kL only
+        let (dark, light) = (lab(50.0, 0.0, 0.0), lab(52.0, 0.0, 0.0));
+        assert_close(
+            delta_e94(&dark, &light, Cie94Application::GraphicArts),
+            2.0,
+            1e-12,
+        );
+        assert_close(
+            delta_e94(&dark, &light, Cie94Application::Textiles),
+            1.0,
+            1e-12,
+        );
+
+        // A pure chroma difference is divided by SC = 1 + K1 * C1
+        let difference = delta_e94(
+            &lab(50.0, 10.0, 0.0),
+            &lab(50.0, 12.0, 0.0),
+            Cie94Application::GraphicArts,
+        );
+        assert_close(difference, 2.0 / 1.45, 1e-12);
+    }
+
+    #[test]
+    fn test_delta_e_cmc() {
+        // SL = 0.040975 * L / (1 + 0.01765 * L) for L >= 16
+        let sl = 0.040975 * 50.0 / (1.0 + 0.01765 * 50.0);
+        let (first, second) = (lab(50.0, 0.0, 0.0), lab(51.0, 0.0, 0.0));
+        assert_close(
+            delta_e_cmc(&first, &second, 2.0, 1.0),
+            1.0 / (2.0 * sl),
+            1e-12,
+        );
+        assert_close(delta_e_cmc(&first, &second, 1.0, 1.0), 1.0 / sl, 1e-12);
+
+        // Below L = 16 SL is constant
+        let (first, second) = (lab(10.0, 0.0, 0.0), lab(11.0, 0.0, 0.0));
+        assert_close(delta_e_cmc(&first, &second, 1.0, 1.0), 1.0 / 0.511, 1e-12);
+    }
+
+    #[test]
+    fn test_delta_e_ok() {
+        let red = Oklab::from(rgba(255, 0, 0));
+        let white = Oklab::from(rgba(255, 255, 255));
+        assert_close(delta_e_ok(&red, &white), 0.452567, 1e-5);
+        assert_eq!(delta_e_ok(&red, &red), 0.0);
+    }
+
+    #[test]
+    fn test_mismatched_white_points_are_aligned() {
+        let d50 = Lab::from(rgba(12, 200, 180));
+        let d65 = d50.with_white_point(WhitePoint::D65);
+        assert_close(delta_e76(&d50, &d65), 0.0, 1e-9);
+        assert_close(delta_e2000(&d50, &d65), 0.0, 1e-9);
+    }
+
+    #[test]
+    fn test_difference_between_color_spaces() {
+        let brand = rgba(255, 136, 0);
+        let methods = [
+            DeltaE::Cie76,
+            DeltaE::Cie94(Cie94Application::GraphicArts),
+            DeltaE::Cie2000,
+            DeltaE::Cmc {
+                lightness: 2.0,
+                chroma: 1.0,
+            },
+            DeltaE::Ok,
+        ];
+        for method in methods {
+            assert_close(method.difference(&brand, &brand), 0.0, 1e-9);
+            assert!(method.difference(&brand, &rgba(0, 136, 255)) > 0.1);
+        }
+
+        // A one-step change in a channel is below the threshold of perception
+        let swatch = rgba(255, 137, 0);
+        assert!(DeltaE::Cie2000.difference(&brand, &swatch) < 1.0);
+        assert_close(
+            DeltaE::Cie2000.difference(&brand, &Lab::from(swatch)),
+            DeltaE::Cie2000.difference(&brand, &swatch),
+            1e-12,
+        );
+    }
+}
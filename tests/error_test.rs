@@ -0,0 +1,177 @@
+use color_parser::{
+    ColorParserError, ErrorKind, Hsl, parse_css_color, parse_hex_to_rgba, parse_hsl_to_rgb,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn css_error(input: &str) -> ColorParserError {
+        parse_css_color(input).unwrap_err()
+    }
+
+    /// Returns the part of the input the error points at.
+    fn snippet(error: &ColorParserError) -> &str {
+        &error.input[error.span.clone()]
+    }
+
+    #[test]
+    fn test_hex_errors() {
+        let error = parse_hex_to_rgba("#fffff").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidLength);
+        assert_eq!(error.span, 1..6);
+        assert_eq!(error.expected, ["3, 4, 6 or 8 hex digits"]);
+        assert_eq!(
+            error.to_string(),
+            "Hex color has the wrong number of digits at byte 1 of `#fffff`; expected 3, 4, 6 or 8 hex digits"
+        );
+
+        // The first bad character is reported, not the whole input
+        let error = parse_hex_to_rgba("#ff8g0z").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+        assert_eq!(error.span, 4..5);
+
+        // Signs are not hex digits, even though `u8::from_str_radix` accepts them
+        let error = parse_hex_to_rgba("#+f+f+f").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+        assert_eq!(error.span, 1..2);
+    }
+
+    #[test]
+    fn test_css_error_spans() {
+        let cases = [
+            ("blurple", ErrorKind::UnknownColorName, "blurple"),
+            ("foo(1 2 3)", ErrorKind::UnknownFunction, "foo"),
+            ("color(cmyk 1 0 0)", ErrorKind::UnknownFunction, "cmyk"),
+            ("rgb(1 2 3 4)", ErrorKind::InvalidSyntax, "4"),
+            ("rgb(1, 2%, 3)", ErrorKind::InvalidSyntax, "2%"),
+            ("hsl(120, 50, 50%)", ErrorKind::InvalidSyntax, "50"),
+            ("lch(50 10 10px)", ErrorKind::InvalidSyntax, "10px"),
+            ("rgb(1 2 3; 4)", ErrorKind::InvalidSyntax, ";"),
+            ("rgb(1, 2, 3,)", ErrorKind::InvalidSyntax, ","),
+            ("rgb(0 0 0 / 1 / 1)", ErrorKind::InvalidSyntax, "/"),
+        ];
+
+        for (input, kind, expected) in cases {
+            let error = css_error(input);
+            assert_eq!(error.kind, kind, "{input}");
+            assert_eq!(error.input, input);
+            assert_eq!(snippet(&error), expected, "{input}");
+        }
+    }
+
+    #[test]
+    fn test_missing_parts_have_empty_spans() {
+        let error = css_error("rgb(1 2)");
+        assert_eq!(error.span, 7..7);
+        assert_eq!(error.expected, ["3 channels"]);
+
+        let error = css_error("rgb(1 2 3");
+        assert_eq!(error.span, 9..9);
+        assert_eq!(error.expected, ["`)`"]);
+
+        let error = css_error("rgb(1 2 3 /)");
+        assert_eq!(error.span, 11..11);
+        assert_eq!(error.expected, ["an alpha value"]);
+    }
+
+    #[test]
+    fn test_spans_include_surrounding_whitespace() {
+        let error = css_error("  #ff8g00 ");
+        assert_eq!(error.input, "  #ff8g00 ");
+        assert_eq!(error.span, 6..7);
+
+        let error = css_error("\thsl(0 0% 0% / x)");
+        assert_eq!(snippet(&error), "x");
+        assert_eq!(error.expected, ["a number, percentage or none"]);
+    }
+
+    #[test]
+    fn test_color_mix_errors_point_into_nested_colors() {
+        let error = css_error("color-mix(in srgb, red, bleu)");
+        assert_eq!(error.kind, ErrorKind::UnknownColorName);
+        assert_eq!(error.span, 24..28);
+
+        let error = css_error("color-mix(in oklch, rgb(1 2 x), blue)");
+        assert_eq!(error.kind, ErrorKind::InvalidSyntax);
+        assert_eq!(snippet(&error), "x");
+
+        let error = css_error("color-mix(in cmyk, red, blue)");
+        assert_eq!(error.kind, ErrorKind::UnknownFunction);
+        assert_eq!(snippet(&error), "cmyk");
+
+        let error = css_error("color-mix(in srgb longer hue, red, blue)");
+        assert_eq!(snippet(&error), "longer hue");
+
+        let error = css_error("color-mix(in srgb, red 120%, blue)");
+        assert_eq!(snippet(&error), "120%");
+    }
+
+    #[test]
+    fn test_render() {
+        let error = css_error("hsl(120 50% 50q)");
+        assert_eq!(
+            error.render(),
+            "error: Invalid CSS color syntax\n  |\n  | hsl(120 50% 50q)\n  |             ^^^ expected a number, percentage or none\n"
+        );
+
+        // Columns count characters, not bytes
+        let error = css_error("ü(1 2 3)");
+        assert!(error.render().ends_with("  | ^ expected a function name\n"));
+
+        // Conversion errors have no input and name the offending value in a note
+        let hsl = Hsl {
+            hue: 0.0,
+            saturation: 120.0,
+            lightness: 50.0,
+            alpha: 1.0,
+        };
+        let error = parse_hsl_to_rgb(&hsl).unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidComponentValue);
+        assert!(error.input.is_empty());
+        assert_eq!(
+            error.render(),
+            "error: Color component is outside its valid range\n  = expected a number from 0 to 100, not 120\n"
+        );
+        assert_eq!(
+            error.to_string(),
+            "Color component is outside its valid range; expected a number from 0 to 100, not 120"
+        );
+
+        // Without an input there is nothing to underline
+        let error = css_error("");
+        assert_eq!(
+            error.render(),
+            "error: Invalid CSS color syntax\n  = expected a hex color, color name or color function\n"
+        );
+    }
+
+    #[test]
+    fn test_kind_codes() {
+        let kinds = [
+            ErrorKind::InvalidLength,
+            ErrorKind::InvalidCharacter,
+            ErrorKind::InvalidRgbValue,
+            ErrorKind::InvalidSyntax,
+            ErrorKind::UnknownFunction,
+            ErrorKind::UnknownColorName,
+            ErrorKind::InvalidComponentValue,
+        ];
+        let codes: Vec<&str> = kinds.iter().map(ErrorKind::code).collect();
+        assert_eq!(
+            codes,
+            [
+                "invalid-length",
+                "invalid-character",
+                "invalid-rgb-value",
+                "invalid-syntax",
+                "unknown-function",
+                "unknown-color-name",
+                "invalid-component-value",
+            ]
+        );
+
+        let error = ColorParserError::from(ErrorKind::InvalidRgbValue);
+        assert_eq!(error.to_string(), "RGB value must be between 0 and 255");
+    }
+}
@@ -0,0 +1,264 @@
+use color_parser::{
+    AdobeRgb, Cmyk, Color, DisplayP3, ErrorKind, Hsl, Hsv, Lab, Lch, LinearRgb, Oklab, Oklch,
+    ProPhotoRgb, Rec2020, Rgba, Srgb, WhitePoint, Xyz,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    #[test]
+    fn test_rgba_formatting() {
+        let orange = rgba(255, 136, 0, 255);
+        assert_eq!(format!("{orange}"), "#ff8800");
+        assert_eq!(format!("{orange:#}"), "rgb(255 136 0)");
+        assert_eq!(format!("{orange:x}"), "#ff8800");
+        assert_eq!(format!("{orange:X}"), "#FF8800");
+        assert_eq!(format!("{orange:#X}"), "#FF8800FF");
+
+        let translucent = rgba(255, 136, 0, 128);
+        assert_eq!(format!("{translucent}"), "#ff880080");
+        assert_eq!(format!("{translucent:#}"), "rgb(255 136 0 / 0.502)");
+        assert_eq!(format!("{translucent:X}"), "#FF880080");
+    }
+
+    #[test]
+    fn test_plain_and_css_forms() {
+        let hsl = Hsl {
+            hue: 32.0,
+            saturation: 100.0,
+            lightness: 50.0,
+            alpha: 0.5,
+        };
+        assert_eq!(format!("{hsl}"), "hsl(32, 100%, 50%, 0.5)");
+        assert_eq!(format!("{hsl:#}"), "hsl(32 100% 50% / 0.5)");
+
+        // CSS has no hsv() or cmyk(), so the alternate form uses hwb() and device-cmyk()
+        let hsv = Hsv {
+            hue: 120.0,
+            saturation: 50.0,
+            value: 80.0,
+            alpha: 1.0,
+        };
+        assert_eq!(format!("{hsv}"), "hsv(120, 50%, 80%)");
+        assert_eq!(format!("{hsv:#}"), "hwb(120 40% 20%)");
+
+        let cmyk = Cmyk {
+            cyan: 0.0,
+            magenta: 47.0,
+            yellow: 100.0,
+            black: 0.0,
+            alpha: 1.0,
+        };
+        assert_eq!(format!("{cmyk}"), "cmyk(0%, 47%, 100%, 0%)");
+        assert_eq!(format!("{cmyk:#}"), "device-cmyk(0% 47% 100% 0%)");
+
+        let p3 = DisplayP3 {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        assert_eq!(format!("{p3}"), "display-p3(1, 0, 0)");
+        assert_eq!(format!("{p3:#}"), "color(display-p3 1 0 0)");
+
+        let xyz = Xyz {
+            x: 0.25,
+            y: 0.5,
+            z: 0.75,
+            alpha: 1.0,
+        };
+        assert_eq!(format!("{xyz:#}"), "color(xyz-d65 0.25 0.5 0.75)");
+    }
+
+    #[test]
+    fn test_precision() {
+        let oklch = Oklch::from(rgba(255, 0, 0, 255));
+        assert_eq!(format!("{oklch}"), "oklch(0.628, 0.2577, 29.2339)");
+        assert_eq!(format!("{oklch:.2}"), "oklch(0.63, 0.26, 29.23)");
+        assert_eq!(format!("{oklch:#.3}"), "oklch(0.628 0.258 29.234)");
+        assert_eq!(format!("{oklch:#.0}"), "oklch(1 0 29)");
+
+        // Negative values that round to zero print as zero
+        let oklab = Oklab {
+            lightness: 0.5,
+            a: -0.0001,
+            b: 0.0,
+            alpha: 1.0,
+        };
+        assert_eq!(format!("{oklab:.2}"), "oklab(0.50, 0.00, 0.00)");
+
+        // Alpha keeps its own precision
+        let hsl = Hsl {
+            hue: 0.0,
+            saturation: 0.0,
+            lightness: 0.0,
+            alpha: 0.25,
+        };
+        assert_eq!(format!("{hsl:.0}"), "hsl(0, 0%, 0%, 0.25)");
+    }
+
+    #[test]
+    fn test_lab_css_form_is_d50() {
+        let white = rgba(255, 255, 255, 255);
+        let lab = Lab::from(white).with_white_point(WhitePoint::D65);
+        assert_eq!(format!("{lab:#.1}"), "lab(100.0 0.0 0.0)");
+
+        // The plain notation is D50 too, so a D65 color round-trips
+        let orange = rgba(255, 136, 0, 255);
+        let d65 = Lab::from(orange).with_white_point(WhitePoint::D65);
+        let parsed: Lab = d65.to_string().parse().unwrap();
+        assert_eq!(parsed.white_point, WhitePoint::D50);
+        let back = parsed.with_white_point(WhitePoint::D65);
+        for (actual, expected) in [
+            (back.lightness, d65.lightness),
+            (back.a, d65.a),
+            (back.b, d65.b),
+        ] {
+            assert!((actual - expected).abs() < 1e-3, "{back:?} is not {d65:?}");
+        }
+        assert_eq!(Rgba::from(parsed), orange);
+
+        let d65 = Lch::from(orange).with_white_point(WhitePoint::D65);
+        assert_eq!(d65.to_string(), Lch::from(orange).to_string());
+        assert_eq!(Rgba::from(d65.to_string().parse::<Lch>().unwrap()), orange);
+
+        let lch: Lch = "lch(50, 30, 120)".parse().unwrap();
+        assert_eq!(lch.white_point, WhitePoint::D50);
+        assert_eq!(format!("{lch:#}"), "lch(50 30 120)");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!("#ff8800".parse::<Rgba>().unwrap(), rgba(255, 136, 0, 255));
+        assert_eq!("orange".parse::<Rgba>().unwrap(), rgba(255, 165, 0, 255));
+
+        // The plain notation is parsed exactly, with optional units and alpha
+        let hsv: Hsv = "hsv(32, 100%, 100%, 0.5)".parse().unwrap();
+        assert_eq!(
+            (hsv.hue, hsv.saturation, hsv.value, hsv.alpha),
+            (32.0, 100.0, 100.0, 0.5)
+        );
+        let cmyk: Cmyk = "CMYK(0, 47, 100, 0)".parse().unwrap();
+        assert_eq!((cmyk.magenta, cmyk.yellow), (47.0, 100.0));
+
+        // A CSS color of the same space is kept as is, others are converted
+        let oklch: Oklch = "oklch(0.5 0.1 30)".parse().unwrap();
+        assert_eq!((oklch.lightness, oklch.chroma, oklch.hue), (0.5, 0.1, 30.0));
+        let hsl: Hsl = "rgb(0 255 0)".parse().unwrap();
+        assert_eq!(hsl.hue, 120.0);
+
+        let cmyk: Cmyk = "device-cmyk(0 0.47 1 0 / 50%)".parse().unwrap();
+        assert_eq!((cmyk.magenta, cmyk.yellow, cmyk.alpha), (47.0, 100.0, 0.5));
+
+        let error = "hsl(30, 100%)".parse::<Hsl>().unwrap_err();
+        assert_eq!(error.input, "hsl(30, 100%)");
+        assert!("blurple".parse::<Color>().is_err());
+    }
+
+    #[test]
+    fn test_from_str_rejects_invalid_components() {
+        // Out-of-range components point at the offending argument
+        let error = "hsl(30, 500%, 50%)".parse::<Hsl>().unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidComponentValue);
+        assert_eq!(&error.input[error.span.clone()], "500%");
+        assert_eq!(error.expected, ["a number from 0 to 100"]);
+
+        let error = " cmyk(200%, 0%, 0%, -50%)".parse::<Cmyk>().unwrap_err();
+        assert_eq!(error.span, 6..10);
+        assert_eq!(&error.input[error.span.clone()], "200%");
+
+        // Non-finite numbers are rejected, including where any value is allowed
+        for input in ["hsl(NaN, 100%, 50%)", "hsl(inf, 100%, 50%)"] {
+            let error = input.parse::<Hsl>().unwrap_err();
+            assert_eq!(error.kind, ErrorKind::InvalidComponentValue);
+            assert_eq!(error.span, 4..7);
+        }
+        let error = "lab(50, -inf, 0)".parse::<Lab>().unwrap_err();
+        assert_eq!(&error.input[error.span.clone()], "-inf");
+        assert_eq!(error.expected, ["a finite number"]);
+
+        // Alpha is limited to [0, 1]
+        let error = "hsv(30, 100%, 50%, 7)".parse::<Hsv>().unwrap_err();
+        assert_eq!(&error.input[error.span.clone()], "7");
+        assert_eq!(error.expected, ["a number from 0 to 1"]);
+        assert!("srgb(1, 0, 0, NaN)".parse::<Srgb>().is_err());
+
+        // Values outside the sRGB gamut are still fine in other spaces
+        assert_eq!("srgb(1.5, -0.2, 0)".parse::<Srgb>().unwrap().red, 1.5);
+    }
+
+    #[test]
+    fn test_round_trips() {
+        let orange = rgba(255, 136, 0, 128);
+        let colors = [
+            Color::Rgba(orange),
+            Color::Srgb(orange.into()),
+            Color::Hsl(orange.into()),
+            Color::Hsv(orange.into()),
+            Color::Cmyk(orange.into()),
+            Color::LinearRgb(orange.into()),
+            Color::Xyz(orange.into()),
+            Color::Lab(orange.into()),
+            Color::Lch(orange.into()),
+            Color::Oklab(orange.into()),
+            Color::Oklch(orange.into()),
+            Color::DisplayP3(orange.into()),
+            Color::Rec2020(orange.into()),
+            Color::AdobeRgb(orange.into()),
+            Color::ProPhotoRgb(orange.into()),
+        ];
+
+        for color in colors {
+            let css: Color = format!("{color:#}").parse().unwrap();
+            assert_eq!(css.to_rgba(), orange, "{color:#}");
+            assert_eq!(format!("{color:x}"), "#ff880080");
+        }
+
+        // The plain notation of each type parses back into that type
+        assert_eq!(
+            Srgb::from(orange)
+                .to_string()
+                .parse::<Srgb>()
+                .unwrap()
+                .to_string(),
+            "srgb(1, 0.5333, 0, 0.502)"
+        );
+        let linear = LinearRgb::from(orange).to_string();
+        assert_eq!(linear.parse::<LinearRgb>().unwrap().to_string(), linear);
+        let a98 = AdobeRgb::from(orange).to_string();
+        assert_eq!(a98.parse::<AdobeRgb>().unwrap().to_string(), a98);
+        let prophoto = ProPhotoRgb::from(orange).to_string();
+        assert_eq!(
+            prophoto.parse::<ProPhotoRgb>().unwrap().to_string(),
+            prophoto
+        );
+        let rec2020 = Rec2020::from(orange).to_string();
+        assert_eq!(rec2020.parse::<Rec2020>().unwrap().to_string(), rec2020);
+    }
+
+    #[test]
+    fn test_color_enum_delegates() {
+        let color = Color::Oklab(Oklab {
+            lightness: 0.5,
+            a: 0.1,
+            b: -0.1,
+            alpha: 1.0,
+        });
+        assert_eq!(format!("{color}"), "oklab(0.5, 0.1, -0.1)");
+        assert_eq!(format!("{color:#.2}"), "oklab(0.50 0.10 -0.10)");
+
+        let parsed: Color = "color(xyz-d50 0.9642 1 0.8252)".parse().unwrap();
+        assert_eq!(parsed.to_rgba(), rgba(255, 255, 255, 255));
+        assert_eq!(format!("{parsed:X}"), "#FFFFFF");
+    }
+}
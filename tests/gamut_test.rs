@@ -0,0 +1,155 @@
+use color_parser::gamut::{GamutMapping, map_to_gamut};
+use color_parser::{
+    DisplayP3, LinearRgb, Oklch, ProPhotoRgb, Rec2020, RgbSpace, Rgba, Srgb, parse_css_color,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const MAPPINGS: [GamutMapping; 3] = [
+        GamutMapping::Clip,
+        GamutMapping::Scale,
+        GamutMapping::ChromaReduction,
+    ];
+
+    fn oklch(lightness: f64, chroma: f64, hue: f64) -> Oklch {
+        Oklch {
+            lightness,
+            chroma,
+            hue,
+            alpha: 1.0,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_in_gamut() {
+        let srgb_red = Srgb {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        assert!(srgb_red.in_gamut());
+        assert!(DisplayP3::from(srgb_red).in_gamut());
+        assert!(Rec2020::from(srgb_red).in_gamut());
+        assert!(LinearRgb::from(srgb_red).in_gamut());
+
+        let p3_green = DisplayP3 {
+            red: 0.0,
+            green: 1.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        assert!(!Srgb::from(p3_green).in_gamut());
+        assert!(Rec2020::from(p3_green).in_gamut());
+        assert!(ProPhotoRgb::from(p3_green).in_gamut());
+
+        // Rec. 2020 green is outside Display P3
+        let rec2020_green = Rec2020::from_channels([0.0, 1.0, 0.0], 1.0);
+        assert!(!DisplayP3::from(rec2020_green).in_gamut());
+    }
+
+    #[test]
+    fn test_in_gamut_colors_are_unchanged() {
+        let color = oklch(0.6, 0.1, 200.0);
+        for mapping in MAPPINGS {
+            let mapped: Srgb = map_to_gamut(&color, mapping);
+            assert_eq!(mapped, Srgb::from(color));
+        }
+    }
+
+    #[test]
+    fn test_every_mapping_lands_in_gamut() {
+        let colors = [
+            oklch(0.7, 0.4, 150.0),
+            oklch(0.3, 0.3, 270.0),
+            oklch(0.9, 0.25, 100.0),
+            oklch(0.5, 0.35, 20.0),
+        ];
+        for color in colors {
+            for mapping in MAPPINGS {
+                let srgb: Srgb = map_to_gamut(&color, mapping);
+                assert!(srgb.in_gamut(), "{mapping:?} left {srgb:?} out of gamut");
+                let p3: DisplayP3 = map_to_gamut(&color, mapping);
+                assert!(p3.in_gamut(), "{mapping:?} left {p3:?} out of gamut");
+            }
+        }
+    }
+
+    #[test]
+    fn test_clip() {
+        let p3_red = DisplayP3::from_channels([1.0, 0.0, 0.0], 0.5);
+        let clipped: Srgb = map_to_gamut(&p3_red, GamutMapping::Clip);
+        assert_eq!(
+            clipped,
+            Srgb {
+                red: 1.0,
+                green: 0.0,
+                blue: 0.0,
+                alpha: 0.5,
+            }
+        );
+    }
+
+    #[test]
+    fn test_scale_keeps_linear_channel_ratios() {
+        let linear = LinearRgb::from_channels([1.5, 0.5, -0.1], 1.0);
+        let scaled: LinearRgb = map_to_gamut(&linear, GamutMapping::Scale);
+        assert!(scaled.in_gamut());
+
+        // The channels keep their order and relative distances from gray
+        let gray = 1.9 / 3.0;
+        let ratio = (scaled.red - gray) / (1.5 - gray);
+        assert_close((scaled.green - gray) / (0.5 - gray), ratio, 1e-9);
+        assert_close(scaled.red, 1.0, 1e-9);
+    }
+
+    #[test]
+    fn test_chroma_reduction_preserves_lightness_and_hue() {
+        let color = oklch(0.7, 0.4, 150.0);
+        let mapped: Srgb = map_to_gamut(&color, GamutMapping::ChromaReduction);
+        let result = Oklch::from(mapped);
+        assert_close(result.lightness, 0.7, 0.02);
+        assert_close(result.hue, 150.0, 3.0);
+        assert!(result.chroma < 0.4);
+
+        // Plain clipping shifts the hue further
+        let clipped = Oklch::from(map_to_gamut::<Srgb>(&color, GamutMapping::Clip));
+        assert!((clipped.hue - 150.0).abs() > (result.hue - 150.0).abs());
+    }
+
+    #[test]
+    fn test_chroma_reduction_extremes() {
+        let white: Srgb = map_to_gamut(&oklch(1.2, 0.2, 40.0), GamutMapping::ChromaReduction);
+        assert_eq!(white, Srgb::from_channels([1.0; 3], 1.0));
+
+        let black: DisplayP3 = map_to_gamut(&oklch(-0.1, 0.2, 40.0), GamutMapping::ChromaReduction);
+        assert_eq!(black, DisplayP3::from_channels([0.0; 3], 1.0));
+    }
+
+    #[test]
+    fn test_to_rgba_mapped() {
+        let color = parse_css_color("color(display-p3 0 1 0 / 0.5)").unwrap();
+        let mapped = color.to_rgba_mapped(GamutMapping::ChromaReduction);
+        assert_eq!(mapped.alpha, 128);
+        assert_ne!(mapped, color.to_rgba());
+        assert_eq!(color.to_rgba_mapped(GamutMapping::Clip), color.to_rgba());
+
+        let red = Rgba {
+            red: 255,
+            green: 0,
+            blue: 0,
+            alpha: 255,
+        };
+        let parsed = parse_css_color("red").unwrap();
+        assert_eq!(parsed.to_rgba_mapped(GamutMapping::ChromaReduction), red);
+    }
+}
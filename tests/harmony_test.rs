@@ -0,0 +1,128 @@
+use color_parser::harmony::Harmony;
+use color_parser::{Hsl, Oklch, Rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_complementary() {
+        let red = Hsl::from(rgba(255, 0, 0, 255));
+        assert_eq!(
+            red.harmony(Harmony::Complementary),
+            [rgba(255, 0, 0, 255), rgba(0, 255, 255, 255)]
+        );
+    }
+
+    #[test]
+    fn test_triadic() {
+        let orange = Hsl::from(rgba(255, 136, 0, 255));
+        assert_eq!(
+            orange.harmony(Harmony::Triadic),
+            [
+                rgba(255, 136, 0, 255),
+                rgba(0, 255, 136, 255),
+                rgba(136, 0, 255, 255)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_tetradic_analogous_and_split() {
+        let red = Hsl::from(rgba(255, 0, 0, 255));
+        assert_eq!(
+            red.harmony(Harmony::Tetradic),
+            [
+                rgba(255, 0, 0, 255),
+                rgba(128, 255, 0, 255),
+                rgba(0, 255, 255, 255),
+                rgba(128, 0, 255, 255)
+            ]
+        );
+        assert_eq!(
+            red.harmony(Harmony::Analogous),
+            [
+                rgba(255, 0, 0, 255),
+                rgba(255, 0, 128, 255),
+                rgba(255, 128, 0, 255)
+            ]
+        );
+        assert_eq!(
+            red.harmony(Harmony::SplitComplementary),
+            [
+                rgba(255, 0, 0, 255),
+                rgba(0, 255, 128, 255),
+                rgba(0, 128, 255, 255)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_alpha_is_kept() {
+        let translucent = Hsl::from(rgba(255, 0, 0, 128));
+        for color in translucent.harmony(Harmony::Tetradic) {
+            assert_eq!(color.alpha, 128);
+        }
+    }
+
+    #[test]
+    fn test_oklch_harmony() {
+        // A muted color stays in gamut, so lightness and chroma are kept exactly
+        let base = Oklch {
+            lightness: 0.6,
+            chroma: 0.08,
+            hue: 40.0,
+            alpha: 1.0,
+        };
+        let palette = base.harmony(Harmony::Triadic);
+        assert_eq!(palette.len(), 3);
+
+        for (color, offset) in palette.iter().zip([0.0, 120.0, 240.0]) {
+            let oklch = Oklch::from(*color);
+            assert_close(oklch.lightness, 0.6, 5e-3);
+            assert_close(oklch.chroma, 0.08, 5e-3);
+            assert_close(oklch.hue, 40.0 + offset, 2.0);
+        }
+    }
+
+    #[test]
+    fn test_oklch_harmony_maps_to_gamut() {
+        // Rotating a vivid orange pushes some hues out of sRGB
+        let orange = Oklch::from(rgba(255, 136, 0, 255));
+        let palette = orange.harmony(Harmony::Complementary);
+        assert_eq!(palette[0], rgba(255, 136, 0, 255));
+
+        let complement = Oklch::from(palette[1]);
+        assert_close(complement.lightness, orange.lightness, 1e-2);
+        assert_close(complement.hue, orange.hue + 180.0, 3.0);
+        assert!(complement.chroma < orange.chroma);
+    }
+
+    #[test]
+    fn test_from_name() {
+        for harmony in Harmony::ALL {
+            assert_eq!(Harmony::from_name(harmony.name()), Some(harmony));
+        }
+        assert_eq!(
+            Harmony::from_name("SPLIT"),
+            Some(Harmony::SplitComplementary)
+        );
+        assert_eq!(Harmony::from_name("square"), None);
+    }
+}
@@ -1,4 +1,4 @@
-use color_parser::{ColorParserError, Rgba, parse_hex_to_rgba};
+use color_parser::{ColorParserError, ErrorKind, Rgba, parse_hex_to_rgba};
 
 #[cfg(test)]
 mod test {
@@ -50,7 +50,10 @@ mod test {
     fn test_invalid_length() {
         assert!(matches!(
             parse_hex_to_rgba("FFFFF"),
-            Err(ColorParserError::InvalidLength)
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidLength,
+                ..
+            })
         ));
     }
 
@@ -58,7 +61,10 @@ mod test {
     fn test_invalid_characters() {
         assert!(matches!(
             parse_hex_to_rgba("#GGHHII"),
-            Err(ColorParserError::InvalidCharacter)
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidCharacter,
+                ..
+            })
         ));
     }
 }
@@ -0,0 +1,187 @@
+use color_parser::{
+    Cmyk, ColorParserError, ErrorKind, Hsl, Hsv, Rgba, parse_cmyk_to_rgb, parse_hsl_to_hsv,
+    parse_hsl_to_rgb, parse_hsv_to_hsl, parse_hsv_to_rgb, parse_rgb_to_cmyk, parse_rgb_to_hsl,
+    parse_rgb_to_hsv,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: a,
+        }
+    }
+
+    /// Iterates over every 24-bit color, fully opaque.
+    fn every_color() -> impl Iterator<Item = Rgba> {
+        (0..=0xFF_FF_FFu32).map(|v| rgba((v >> 16) as u8, (v >> 8) as u8, v as u8, 255))
+    }
+
+    #[test]
+    fn test_hsl_to_rgb_primaries() {
+        let hsl = |hue| Hsl {
+            hue,
+            saturation: 100.0,
+            lightness: 50.0,
+            alpha: 1.0,
+        };
+        assert_eq!(parse_hsl_to_rgb(&hsl(0.0)).unwrap(), rgba(255, 0, 0, 255));
+        assert_eq!(parse_hsl_to_rgb(&hsl(120.0)).unwrap(), rgba(0, 255, 0, 255));
+        assert_eq!(parse_hsl_to_rgb(&hsl(240.0)).unwrap(), rgba(0, 0, 255, 255));
+        assert_eq!(
+            parse_hsl_to_rgb(&hsl(-120.0)).unwrap(),
+            rgba(0, 0, 255, 255)
+        );
+    }
+
+    #[test]
+    fn test_hsv_to_rgb_primaries() {
+        let hsv = |hue| Hsv {
+            hue,
+            saturation: 100.0,
+            value: 100.0,
+            alpha: 1.0,
+        };
+        assert_eq!(parse_hsv_to_rgb(&hsv(0.0)).unwrap(), rgba(255, 0, 0, 255));
+        assert_eq!(
+            parse_hsv_to_rgb(&hsv(60.0)).unwrap(),
+            rgba(255, 255, 0, 255)
+        );
+        assert_eq!(
+            parse_hsv_to_rgb(&hsv(300.0)).unwrap(),
+            rgba(255, 0, 255, 255)
+        );
+        assert_eq!(
+            parse_hsv_to_rgb(&hsv(420.0)).unwrap(),
+            rgba(255, 255, 0, 255)
+        );
+    }
+
+    #[test]
+    fn test_cmyk_to_rgb() {
+        let cmyk = Cmyk {
+            cyan: 0.0,
+            magenta: 0.0,
+            yellow: 0.0,
+            black: 100.0,
+            alpha: 1.0,
+        };
+        assert_eq!(parse_cmyk_to_rgb(&cmyk).unwrap(), rgba(0, 0, 0, 255));
+
+        let cmyk = Cmyk {
+            cyan: 100.0,
+            magenta: 0.0,
+            yellow: 100.0,
+            black: 50.0,
+            alpha: 1.0,
+        };
+        assert_eq!(parse_cmyk_to_rgb(&cmyk).unwrap(), rgba(0, 128, 0, 255));
+    }
+
+    #[test]
+    fn test_alpha_carried_through() {
+        let color = rgba(255, 136, 0, 51);
+        let hsl = parse_rgb_to_hsl(&color).unwrap();
+        let hsv = parse_rgb_to_hsv(&color).unwrap();
+        let cmyk = parse_rgb_to_cmyk(&color).unwrap();
+        assert_eq!(hsl.alpha, 0.2);
+
+        assert_eq!(parse_hsl_to_rgb(&hsl).unwrap(), color);
+        assert_eq!(parse_hsv_to_rgb(&hsv).unwrap(), color);
+        assert_eq!(parse_cmyk_to_rgb(&cmyk).unwrap(), color);
+        assert_eq!(parse_hsl_to_hsv(&hsl).unwrap().alpha, 0.2);
+        assert_eq!(parse_hsv_to_hsl(&hsv).unwrap().alpha, 0.2);
+    }
+
+    #[test]
+    fn test_hsl_hsv_direct() {
+        let hsl = Hsl {
+            hue: 200.0,
+            saturation: 60.0,
+            lightness: 30.0,
+            alpha: 1.0,
+        };
+        let hsv = parse_hsl_to_hsv(&hsl).unwrap();
+        assert!((hsv.saturation - 75.0).abs() < 1e-9);
+        assert!((hsv.value - 48.0).abs() < 1e-9);
+
+        let back = parse_hsv_to_hsl(&hsv).unwrap();
+        assert!((back.saturation - hsl.saturation).abs() < 1e-9);
+        assert!((back.lightness - hsl.lightness).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_invalid_components() {
+        let hsl = Hsl {
+            hue: 0.0,
+            saturation: 120.0,
+            lightness: 50.0,
+            alpha: 1.0,
+        };
+        assert!(matches!(
+            parse_hsl_to_rgb(&hsl),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidComponentValue,
+                ..
+            })
+        ));
+
+        let hsv = Hsv {
+            hue: f64::NAN,
+            saturation: 50.0,
+            value: 50.0,
+            alpha: 1.0,
+        };
+        assert!(matches!(
+            parse_hsv_to_rgb(&hsv),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidComponentValue,
+                ..
+            })
+        ));
+
+        let cmyk = Cmyk {
+            cyan: 0.0,
+            magenta: 0.0,
+            yellow: 0.0,
+            black: 0.0,
+            alpha: 1.5,
+        };
+        assert!(matches!(
+            parse_cmyk_to_rgb(&cmyk),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidComponentValue,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_every_color_round_trips_through_hsl() {
+        for color in every_color() {
+            let hsl = parse_rgb_to_hsl(&color).unwrap();
+            assert_eq!(parse_hsl_to_rgb(&hsl).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn test_every_color_round_trips_through_hsv() {
+        for color in every_color() {
+            let hsv = parse_rgb_to_hsv(&color).unwrap();
+            assert_eq!(parse_hsv_to_rgb(&hsv).unwrap(), color);
+        }
+    }
+
+    #[test]
+    fn test_every_color_round_trips_through_cmyk() {
+        for color in every_color() {
+            let cmyk = parse_rgb_to_cmyk(&color).unwrap();
+            assert_eq!(parse_cmyk_to_rgb(&cmyk).unwrap(), color);
+        }
+    }
+}
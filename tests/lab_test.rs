@@ -0,0 +1,160 @@
+use color_parser::{
+    Color, ColorParserError, ErrorKind, Lab, Lch, Rgba, WhitePoint, Xyz, parse_css_color,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_red_d50() {
+        let lab = Lab::from(rgba(255, 0, 0));
+        assert_eq!(lab.white_point, WhitePoint::D50);
+        assert_close(lab.lightness, 54.2905, 1e-3);
+        assert_close(lab.a, 80.8049, 1e-3);
+        assert_close(lab.b, 69.8910, 1e-3);
+    }
+
+    #[test]
+    fn test_red_d65() {
+        let xyz: Xyz = rgba(255, 0, 0).into();
+        let lab = Lab::from_xyz(&xyz, WhitePoint::D65);
+        assert_close(lab.lightness, 53.2371, 1e-3);
+        assert_close(lab.a, 80.0901, 1e-3);
+        assert_close(lab.b, 67.2033, 1e-3);
+    }
+
+    #[test]
+    fn test_white_and_black() {
+        for white_point in [WhitePoint::D50, WhitePoint::D65] {
+            let white = Lab::from_xyz(&rgba(255, 255, 255).into(), white_point);
+            assert_close(white.lightness, 100.0, 1e-9);
+            assert_close(white.a, 0.0, 1e-9);
+            assert_close(white.b, 0.0, 1e-9);
+
+            let black = Lab::from_xyz(&rgba(0, 0, 0).into(), white_point);
+            assert_close(black.lightness, 0.0, 1e-9);
+        }
+    }
+
+    #[test]
+    fn test_white_point_change_preserves_color() {
+        let lab = Lab::from(rgba(12, 200, 180));
+        let d65 = lab.with_white_point(WhitePoint::D65);
+        assert_eq!(d65.white_point, WhitePoint::D65);
+        assert_eq!(Rgba::from(d65), rgba(12, 200, 180));
+    }
+
+    #[test]
+    fn test_lch_polar_form() {
+        let lch = Lch::from(rgba(255, 0, 0));
+        assert_close(lch.chroma, 106.8372, 1e-3);
+        assert_close(lch.hue, 40.8577, 1e-3);
+
+        let lab = lch.to_lab();
+        assert_close(lab.a, 80.8049, 1e-3);
+        assert_close(lab.b, 69.8910, 1e-3);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for v in (0..=255u8).step_by(5) {
+            let color = rgba(v, 255 - v, v / 3);
+            assert_eq!(Rgba::from(Lab::from(color)), color);
+            assert_eq!(Rgba::from(Lch::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn test_parse_css_lab_lch() {
+        // Examples from the CSS Color Level 4 specification
+        let lab = parse_css_color("lab(29.2345% 39.3825 20.0664)").unwrap();
+        assert!(matches!(lab, Color::Lab(_)));
+        assert_eq!(lab.to_rgba(), rgba(125, 35, 41));
+
+        let lch = parse_css_color("lch(29.2345% 44.2 27)").unwrap();
+        assert!(matches!(lch, Color::Lch(_)));
+        assert_eq!(lch.to_rgba(), rgba(125, 35, 41));
+
+        let lab = parse_css_color("LAB(52.2345 40.1645 59.9971 / 0.5)").unwrap();
+        assert_eq!(
+            lab.to_rgba(),
+            Rgba {
+                red: 198,
+                green: 93,
+                blue: 6,
+                alpha: 128
+            }
+        );
+    }
+
+    #[test]
+    fn test_parse_css_percentages_and_none() {
+        let Color::Lab(lab) = parse_css_color("lab(50% 100% -100%)").unwrap() else {
+            panic!("expected Lab");
+        };
+        assert_eq!((lab.lightness, lab.a, lab.b), (50.0, 125.0, -125.0));
+
+        let Color::Lch(lch) = parse_css_color("lch(120% 50% 1turn / none)").unwrap() else {
+            panic!("expected Lch");
+        };
+        assert_eq!((lch.lightness, lch.chroma, lch.hue), (100.0, 75.0, 0.0));
+        assert_eq!(lch.alpha, 0.0);
+
+        assert!(matches!(
+            parse_css_color("lab(50%, 10, 10)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_css_color("lch(50 10 10px)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_css_string() {
+        assert_eq!(
+            Lab::from(rgba(255, 255, 255)).to_css_string(),
+            "lab(100 0 0)"
+        );
+        assert_eq!(
+            Lab::from(rgba(255, 0, 0)).to_css_string(),
+            "lab(54.2905 80.8049 69.891)"
+        );
+
+        let lch = Lch {
+            lightness: 29.2345,
+            chroma: 44.2,
+            hue: 27.0,
+            alpha: 0.25,
+            white_point: WhitePoint::D50,
+        };
+        assert_eq!(lch.to_css_string(), "lch(29.2345 44.2 27 / 0.25)");
+
+        // Formatting round-trips through the parser
+        let parsed = parse_css_color(&lch.to_css_string()).unwrap();
+        assert_eq!(parsed, Color::Lch(lch));
+    }
+}
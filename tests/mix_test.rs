@@ -0,0 +1,254 @@
+use color_parser::mix::{HueInterpolation, InterpolationSpace, mix};
+use color_parser::{Color, ColorParserError, ErrorKind, Hsl, Oklch, Rgba, Srgb, parse_css_color};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn hsl(hue: f64) -> Hsl {
+        Hsl {
+            hue,
+            saturation: 100.0,
+            lightness: 50.0,
+            alpha: 1.0,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_mix_endpoints() {
+        let red = rgba(255, 0, 0, 255);
+        let blue = rgba(0, 0, 255, 255);
+        let spaces = [
+            InterpolationSpace::Srgb,
+            InterpolationSpace::SrgbLinear,
+            InterpolationSpace::Lab,
+            InterpolationSpace::Oklab,
+            InterpolationSpace::Lch(HueInterpolation::Longer),
+            InterpolationSpace::Oklch(HueInterpolation::Increasing),
+            InterpolationSpace::Hsl(HueInterpolation::Decreasing),
+            InterpolationSpace::Hwb(HueInterpolation::Shorter),
+        ];
+
+        for space in spaces {
+            assert_eq!(mix(&red, &blue, 0.0, space).to_rgba(), red, "{space:?}");
+            assert_eq!(mix(&red, &blue, 1.0, space).to_rgba(), blue, "{space:?}");
+        }
+    }
+
+    #[test]
+    fn test_mix_midpoints() {
+        let red = rgba(255, 0, 0, 255);
+        let lime = rgba(0, 255, 0, 255);
+
+        let srgb = mix(&red, &lime, 0.5, InterpolationSpace::Srgb);
+        assert_eq!(srgb.to_rgba(), rgba(128, 128, 0, 255));
+
+        // Mixing in linear light is brighter than in gamma-encoded sRGB
+        let linear = mix(&red, &lime, 0.5, InterpolationSpace::SrgbLinear);
+        assert_eq!(linear.to_rgba(), rgba(188, 188, 0, 255));
+
+        let hsl = mix(
+            &red,
+            &lime,
+            0.5,
+            InterpolationSpace::Hsl(HueInterpolation::Shorter),
+        );
+        assert_eq!(hsl.to_rgba(), rgba(255, 255, 0, 255));
+
+        let hwb = mix(
+            &red,
+            &lime,
+            0.5,
+            InterpolationSpace::Hwb(HueInterpolation::Shorter),
+        );
+        assert_eq!(hwb.to_rgba(), rgba(255, 255, 0, 255));
+    }
+
+    #[test]
+    fn test_hue_interpolation_methods() {
+        let hue_of = |a: f64, b: f64, method| match mix(
+            &hsl(a),
+            &hsl(b),
+            0.5,
+            InterpolationSpace::Hsl(method),
+        ) {
+            Color::Hsl(hsl) => hsl.hue,
+            color => panic!("expected Hsl, got {color:?}"),
+        };
+
+        assert_close(hue_of(10.0, 100.0, HueInterpolation::Shorter), 55.0, 1e-9);
+        assert_close(hue_of(10.0, 100.0, HueInterpolation::Longer), 235.0, 1e-9);
+        assert_close(
+            hue_of(10.0, 100.0, HueInterpolation::Increasing),
+            55.0,
+            1e-9,
+        );
+        assert_close(
+            hue_of(10.0, 100.0, HueInterpolation::Decreasing),
+            235.0,
+            1e-9,
+        );
+
+        assert_close(
+            hue_of(100.0, 10.0, HueInterpolation::Increasing),
+            235.0,
+            1e-9,
+        );
+        assert_close(
+            hue_of(100.0, 10.0, HueInterpolation::Decreasing),
+            55.0,
+            1e-9,
+        );
+        assert_close(hue_of(350.0, 20.0, HueInterpolation::Shorter), 5.0, 1e-9);
+        assert_close(hue_of(350.0, 20.0, HueInterpolation::Longer), 185.0, 1e-9);
+    }
+
+    #[test]
+    fn test_powerless_hue() {
+        // White has no hue, so the mix keeps the hue of blue
+        let white = rgba(255, 255, 255, 255);
+        let blue = rgba(0, 0, 255, 255);
+        let space = InterpolationSpace::Oklch(HueInterpolation::Shorter);
+
+        let (Color::Oklch(mixed), blue) = (mix(&white, &blue, 0.5, space), Oklch::from(blue))
+        else {
+            panic!("expected Oklch");
+        };
+        assert_close(mixed.hue, blue.hue, 1e-6);
+        assert_close(mixed.chroma, blue.chroma / 2.0, 1e-6);
+    }
+
+    #[test]
+    fn test_premultiplied_alpha() {
+        // A transparent color only contributes its alpha
+        let red = rgba(255, 0, 0, 255);
+        let transparent = rgba(0, 0, 255, 0);
+        let Color::Srgb(mixed) = mix(&red, &transparent, 0.5, InterpolationSpace::Srgb) else {
+            panic!("expected Srgb");
+        };
+        assert_close(mixed.red, 1.0, 1e-12);
+        assert_close(mixed.blue, 0.0, 1e-12);
+        assert_close(mixed.alpha, 0.5, 1e-12);
+
+        // CSS Color 5, example 16; rgb() alphas are stored as bytes
+        let color =
+            parse_css_color("color-mix(in srgb, rgb(100% 0% 0% / 0.7) 25%, rgb(0% 100% 0% / 0.2))")
+                .unwrap();
+        let Color::Srgb(Srgb {
+            red,
+            green,
+            blue,
+            alpha,
+        }) = color
+        else {
+            panic!("expected Srgb, got {color:?}");
+        };
+        assert_close(red, 0.53846, 1e-3);
+        assert_close(green, 0.46154, 1e-3);
+        assert_close(blue, 0.0, 1e-12);
+        assert_close(alpha, 0.325, 1e-3);
+    }
+
+    #[test]
+    fn test_parse_color_mix() {
+        let color = parse_css_color("color-mix(in srgb, red 50%, blue)").unwrap();
+        assert_eq!(color.to_rgba(), rgba(128, 0, 128, 255));
+
+        // CSS Color 5, example 8
+        let color = parse_css_color("color-mix(in lch, peru 40%, palegoldenrod)").unwrap();
+        let Color::Lch(lch) = color else {
+            panic!("expected Lch, got {color:?}");
+        };
+        assert_close(lch.lightness, 79.7256, 1e-2);
+        assert_close(lch.chroma, 40.448, 1e-2);
+        assert_close(lch.hue, 84.771, 1e-2);
+
+        // The percentage may precede the color, and the hue method is optional
+        let a = parse_css_color(
+            "COLOR-MIX(in HSL longer hue, 25% hsl(10 100% 50%), hsl(100 100% 50%))",
+        );
+        let b = parse_css_color(
+            "color-mix(in hsl longer hue, hsl(10 100% 50%) 25%, hsl(100 100% 50%) 75%)",
+        );
+        assert_eq!(a.unwrap(), b.unwrap());
+
+        // Nested color-mix()
+        let color =
+            parse_css_color("color-mix(in srgb, color-mix(in srgb, red, blue), white 0%)").unwrap();
+        assert_eq!(color.to_rgba(), rgba(128, 0, 128, 255));
+    }
+
+    #[test]
+    fn test_parse_color_mix_percentages() {
+        // Percentages above 100% in total are normalized
+        let a = parse_css_color("color-mix(in srgb, red 80%, blue 80%)").unwrap();
+        let b = parse_css_color("color-mix(in srgb, red, blue)").unwrap();
+        assert_eq!(a, b);
+
+        // Percentages below 100% in total reduce the alpha
+        let Color::Srgb(mixed) = parse_css_color("color-mix(in srgb, red 20%, blue 20%)").unwrap()
+        else {
+            panic!("expected Srgb");
+        };
+        assert_close(mixed.red, 0.5, 1e-12);
+        assert_close(mixed.blue, 0.5, 1e-12);
+        assert_close(mixed.alpha, 0.4, 1e-12);
+    }
+
+    #[test]
+    fn test_parse_color_mix_errors() {
+        let invalid = [
+            "color-mix(in srgb, red)",
+            "color-mix(srgb, red, blue)",
+            "color-mix(in srgb, red, blue, green)",
+            "color-mix(in srgb longer hue, red, blue)",
+            "color-mix(in hsl sideways hue, red, blue)",
+            "color-mix(in srgb, red 0%, blue 0%)",
+            "color-mix(in srgb, red 120%, blue)",
+            "color-mix(in srgb, red -10%, blue)",
+        ];
+        for input in invalid {
+            assert!(
+                matches!(
+                    parse_css_color(input),
+                    Err(ColorParserError {
+                        kind: ErrorKind::InvalidSyntax,
+                        ..
+                    })
+                ),
+                "{input}"
+            );
+        }
+
+        assert!(matches!(
+            parse_css_color("color-mix(in cmyk, red, blue)"),
+            Err(ColorParserError {
+                kind: ErrorKind::UnknownFunction,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_css_color("color-mix(in srgb, red, bleu)"),
+            Err(ColorParserError {
+                kind: ErrorKind::UnknownColorName,
+                ..
+            })
+        ));
+    }
+}
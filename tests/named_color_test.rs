@@ -0,0 +1,101 @@
+use color_parser::named::named_colors;
+use color_parser::{ColorParserError, ErrorKind, Rgba, named_color, parse_css_color};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8, a: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: a,
+        }
+    }
+
+    #[test]
+    fn test_named_lookup() {
+        assert_eq!(named_color("rebeccapurple"), Some(rgba(102, 51, 153, 255)));
+        assert_eq!(
+            named_color("cornflowerblue"),
+            Some(rgba(100, 149, 237, 255))
+        );
+        assert_eq!(named_color("aliceblue"), Some(rgba(240, 248, 255, 255)));
+        assert_eq!(named_color("yellowgreen"), Some(rgba(154, 205, 50, 255)));
+        assert_eq!(named_color("transparent"), Some(rgba(0, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_named_case_insensitive() {
+        assert_eq!(named_color("RebeccaPurple"), named_color("rebeccapurple"));
+        assert_eq!(named_color("TRANSPARENT"), Some(rgba(0, 0, 0, 0)));
+    }
+
+    #[test]
+    fn test_named_unknown() {
+        assert_eq!(named_color("notacolor"), None);
+        assert_eq!(named_color(""), None);
+    }
+
+    #[test]
+    fn test_css_parser_resolves_names() {
+        let color = parse_css_color("  Tomato ").unwrap().to_rgba();
+        assert_eq!(color, rgba(255, 99, 71, 255));
+
+        assert!(matches!(
+            parse_css_color("blurple"),
+            Err(ColorParserError {
+                kind: ErrorKind::UnknownColorName,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_reverse_name() {
+        assert_eq!(rgba(102, 51, 153, 255).name(), Some("rebeccapurple"));
+        assert_eq!(rgba(0, 0, 0, 0).name(), Some("transparent"));
+        assert_eq!(rgba(0, 255, 255, 255).name(), Some("aqua"));
+        assert_eq!(rgba(128, 128, 128, 255).name(), Some("gray"));
+        assert_eq!(rgba(102, 51, 153, 128).name(), None);
+        assert_eq!(rgba(1, 2, 3, 255).name(), None);
+    }
+
+    #[test]
+    fn test_every_name_round_trips() {
+        let mut count = 0;
+        for (name, color) in named_colors() {
+            // Every entry is found, so the tables are sorted for binary search
+            assert_eq!(named_color(name), Some(color), "{name}");
+            assert_eq!(named_color(color.name().unwrap()), Some(color), "{name}");
+            count += 1;
+        }
+
+        if cfg!(feature = "x11") {
+            assert_eq!(count, 148 + 529);
+        } else {
+            assert_eq!(count, 148);
+        }
+    }
+
+    #[cfg(feature = "x11")]
+    #[test]
+    fn test_x11_extras() {
+        assert_eq!(named_color("NavyBlue"), Some(rgba(0, 0, 128, 255)));
+        assert_eq!(named_color("x11gray"), Some(rgba(190, 190, 190, 255)));
+        assert_eq!(named_color("red3"), Some(rgba(205, 0, 0, 255)));
+        assert_eq!(named_color("gray0"), Some(rgba(0, 0, 0, 255)));
+        assert_eq!(named_color("Grey100"), Some(rgba(255, 255, 255, 255)));
+        assert_eq!(named_color("antiquewhite1"), Some(rgba(255, 239, 219, 255)));
+        assert_eq!(rgba(190, 190, 190, 255).name(), Some("x11gray"));
+        // CSS keywords keep their CSS values
+        assert_eq!(named_color("gray"), Some(rgba(128, 128, 128, 255)));
+    }
+
+    #[cfg(not(feature = "x11"))]
+    #[test]
+    fn test_x11_extras_disabled() {
+        assert_eq!(named_color("navyblue"), None);
+    }
+}
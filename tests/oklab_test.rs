@@ -0,0 +1,137 @@
+use color_parser::{Color, ColorParserError, ErrorKind, Oklab, Oklch, Rgba, Xyz, parse_css_color};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_reference_values() {
+        // XYZ → Oklab table published by Björn Ottosson (three decimals)
+        let table = [
+            ([0.950, 1.000, 1.089], [1.000, 0.000, 0.000]),
+            ([1.000, 0.000, 0.000], [0.450, 1.236, -0.019]),
+            ([0.000, 1.000, 0.000], [0.922, -0.671, 0.263]),
+            ([0.000, 0.000, 1.000], [0.153, -1.415, -0.449]),
+        ];
+
+        for ([x, y, z], [l, a, b]) in table {
+            let oklab = Oklab::from(Xyz {
+                x,
+                y,
+                z,
+                alpha: 1.0,
+            });
+            assert_close(oklab.lightness, l, 1e-3);
+            assert_close(oklab.a, a, 1e-3);
+            assert_close(oklab.b, b, 1e-3);
+        }
+    }
+
+    #[test]
+    fn test_srgb_primaries() {
+        let red = Oklab::from(rgba(255, 0, 0));
+        assert_close(red.lightness, 0.627_955, 1e-6);
+        assert_close(red.a, 0.224_863, 1e-6);
+        assert_close(red.b, 0.125_846, 1e-6);
+
+        let white = Oklab::from(rgba(255, 255, 255));
+        assert_close(white.lightness, 1.0, 1e-6);
+        assert_close(white.a, 0.0, 1e-6);
+        assert_close(white.b, 0.0, 1e-6);
+    }
+
+    #[test]
+    fn test_oklch_polar_form() {
+        let red = Oklch::from(rgba(255, 0, 0));
+        assert_close(red.chroma, 0.257_683, 1e-6);
+        assert_close(red.hue, 29.233_885, 1e-5);
+
+        // Grays have no meaningful hue
+        let gray = Oklch::from(rgba(128, 128, 128));
+        assert_eq!(gray.hue, 0.0);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for v in (0..=255u8).step_by(3) {
+            let color = rgba(v, v / 2, 255 - v);
+            assert_eq!(Rgba::from(Oklab::from(color)), color);
+            assert_eq!(Rgba::from(Oklch::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn test_parse_css() {
+        let Color::Oklab(oklab) = parse_css_color("oklab(40.1% 0.1143 0.045)").unwrap() else {
+            panic!("expected Oklab");
+        };
+        assert_close(oklab.lightness, 0.401, 1e-12);
+
+        let Color::Oklch(oklch) = parse_css_color("oklch(0.628 64.4% 29.23deg / 50%)").unwrap()
+        else {
+            panic!("expected Oklch");
+        };
+        assert_close(oklch.chroma, 0.2576, 1e-12);
+        assert_eq!(oklch.alpha, 0.5);
+        assert_eq!(
+            Rgba::from(oklch),
+            Rgba {
+                red: 255,
+                green: 0,
+                blue: 0,
+                alpha: 128
+            }
+        );
+
+        let Color::Oklab(oklab) = parse_css_color("oklab(none 100% -50%)").unwrap() else {
+            panic!("expected Oklab");
+        };
+        assert_eq!((oklab.lightness, oklab.a, oklab.b), (0.0, 0.4, -0.2));
+
+        assert!(matches!(
+            parse_css_color("oklch(0.5, 0.1, 20)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_css_string() {
+        let red = Oklab::from(rgba(255, 0, 0));
+        assert_eq!(red.to_css_string(), "oklab(0.628 0.2249 0.1258)");
+        assert_eq!(
+            red.to_oklch().to_css_string(),
+            "oklch(0.628 0.2577 29.2339)"
+        );
+
+        let translucent = Oklch {
+            lightness: 0.5,
+            chroma: 0.1,
+            hue: 200.0,
+            alpha: 0.3,
+        };
+        assert_eq!(translucent.to_css_string(), "oklch(0.5 0.1 200 / 0.3)");
+        assert_eq!(
+            parse_css_color(&translucent.to_css_string()).unwrap(),
+            Color::Oklch(translucent)
+        );
+    }
+}
@@ -0,0 +1,158 @@
+use color_parser::{Color, ErrorKind, ParseOptions, Rgba, parse_hex_to_rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ORANGE: Rgba = Rgba {
+        red: 255,
+        green: 136,
+        blue: 0,
+        alpha: 255,
+    };
+
+    #[test]
+    fn test_default_options() {
+        let options = ParseOptions::default();
+        assert_eq!(options, ParseOptions::new());
+        assert_eq!(options.parse_hex("#ff8800").unwrap(), ORANGE);
+        assert_eq!(options.parse_hex("FF8800").unwrap(), ORANGE);
+        assert_eq!(options.parse_hex("#f80").unwrap(), ORANGE);
+
+        // Only a single `#` is stripped
+        let error = parse_hex_to_rgba("##fff").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+        assert_eq!(error.span, 1..2);
+
+        assert_eq!(
+            options.parse_hex("0xff8800").unwrap_err().kind,
+            ErrorKind::InvalidCharacter
+        );
+    }
+
+    #[test]
+    fn test_require_hash() {
+        let options = ParseOptions::new().require_hash(true);
+        assert_eq!(options.parse_hex("#ff8800").unwrap(), ORANGE);
+
+        let error = options.parse_hex("ff8800").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidSyntax);
+        assert_eq!(error.span, 0..0);
+        assert_eq!(error.expected, ["`#`"]);
+
+        // `0x` satisfies the requirement when it is allowed
+        let options = options.allow_0x(true);
+        assert_eq!(options.parse_hex("0xff8800").unwrap(), ORANGE);
+        assert_eq!(
+            options.parse_hex("ff8800").unwrap_err().expected,
+            ["`#`", "`0x`"]
+        );
+    }
+
+    #[test]
+    fn test_whitespace() {
+        let error = ParseOptions::new().parse_hex("  #ff8800").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidSyntax);
+        assert_eq!(error.span, 0..2);
+        let error = ParseOptions::new().parse_hex("#ff8800\n").unwrap_err();
+        assert_eq!(error.span, 7..8);
+
+        let options = ParseOptions::new().allow_whitespace(true);
+        assert_eq!(options.parse_hex("  #ff8800\n").unwrap(), ORANGE);
+
+        // Spans still refer to the untrimmed input
+        let error = options.parse_hex("  #ff8g00").unwrap_err();
+        assert_eq!(error.span, 6..7);
+        assert_eq!(error.input, "  #ff8g00");
+    }
+
+    #[test]
+    fn test_case_variants() {
+        let options = ParseOptions::new()
+            .allow_0x(true)
+            .allow_case_variants(false);
+        assert_eq!(options.parse_hex("#ff8800").unwrap(), ORANGE);
+        assert_eq!(options.parse_hex("0xff8800").unwrap(), ORANGE);
+
+        let error = options.parse_hex("#ff8A00").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidCharacter);
+        assert_eq!(error.span, 4..5);
+        assert_eq!(error.expected, ["a lowercase hex digit (0-9, a-f)"]);
+        assert!(options.parse_hex("0Xff8800").is_err());
+
+        let options = options.allow_case_variants(true);
+        assert_eq!(options.parse_hex("0XFF8800").unwrap(), ORANGE);
+    }
+
+    #[test]
+    fn test_alpha_and_shorthand() {
+        let options = ParseOptions::new().allow_missing_alpha(false);
+        assert_eq!(options.parse_hex("#ff8800ff").unwrap(), ORANGE);
+        assert_eq!(options.parse_hex("#f80f").unwrap(), ORANGE);
+        let error = options.parse_hex("#ff8800").unwrap_err();
+        assert_eq!(error.kind, ErrorKind::InvalidLength);
+        assert_eq!(error.span, 1..7);
+        assert_eq!(error.expected, ["4 or 8 hex digits"]);
+
+        let options = ParseOptions::new().reject_shorthand(true);
+        assert_eq!(options.parse_hex("#ff8800").unwrap(), ORANGE);
+        assert_eq!(
+            options.parse_hex("#f80").unwrap_err().expected,
+            ["6 or 8 hex digits"]
+        );
+
+        let options = options.allow_missing_alpha(false);
+        assert_eq!(
+            options.parse_hex("#f80f").unwrap_err().expected,
+            ["8 hex digits"]
+        );
+    }
+
+    #[test]
+    fn test_strict_and_lenient() {
+        let strict = ParseOptions::strict();
+        assert_eq!(strict.parse_hex("#ff8800").unwrap(), ORANGE);
+        assert_eq!(strict.parse_hex(&ORANGE.to_hex()).unwrap(), ORANGE);
+        for input in [
+            "ff8800", "#FF8800", "#f80", " #ff8800", "0xff8800", "##ff8800",
+        ] {
+            assert!(strict.parse_hex(input).is_err(), "{input}");
+        }
+
+        let lenient = ParseOptions::lenient();
+        for input in [
+            "ff8800",
+            "#FF8800",
+            "#f80",
+            " #ff8800 ",
+            "0xff8800",
+            "0XF80",
+        ] {
+            assert_eq!(lenient.parse_hex(input).unwrap(), ORANGE, "{input}");
+        }
+        assert!(lenient.parse_hex("##ff8800").is_err());
+    }
+
+    #[test]
+    fn test_parse_any_color() {
+        let strict = ParseOptions::strict();
+        assert_eq!(strict.parse("#ff8800").unwrap(), Color::Rgba(ORANGE));
+        assert_eq!(strict.parse("rgb(255 136 0)").unwrap().to_rgba(), ORANGE);
+        // Bare hex digits are still parsed as hex, and need the `#`
+        assert_eq!(
+            strict.parse("ff8800").unwrap_err().kind,
+            ErrorKind::InvalidSyntax
+        );
+        assert_eq!(
+            strict.parse(" red").unwrap_err().kind,
+            ErrorKind::InvalidSyntax
+        );
+
+        let lenient = ParseOptions::lenient();
+        assert_eq!(lenient.parse(" 0xff8800 ").unwrap(), Color::Rgba(ORANGE));
+        assert_eq!(lenient.parse(" rgb(255 136 0) ").unwrap().to_rgba(), ORANGE);
+        let error = lenient.parse("  hsl(30 100% 50q)").unwrap_err();
+        assert_eq!(error.input, "  hsl(30 100% 50q)");
+        assert_eq!(&error.input[error.span.clone()], "50q");
+    }
+}
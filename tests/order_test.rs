@@ -0,0 +1,148 @@
+use color_parser::{ChannelOrder, ErrorKind, ParseOptions, Rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    const ORANGE: Rgba = Rgba {
+        red: 0xff,
+        green: 0x88,
+        blue: 0x00,
+        alpha: 0x80,
+    };
+
+    #[test]
+    fn test_names() {
+        for order in ChannelOrder::ALL {
+            assert_eq!(ChannelOrder::from_name(order.name()), Some(order));
+        }
+        assert_eq!(ChannelOrder::from_name("BGRA"), Some(ChannelOrder::Bgra));
+        assert_eq!(ChannelOrder::from_name("rgb"), None);
+        assert_eq!(ChannelOrder::default(), ChannelOrder::Rgba);
+    }
+
+    #[test]
+    fn test_bytes() {
+        let cases = [
+            (ChannelOrder::Rgba, [0xff, 0x88, 0x00, 0x80]),
+            (ChannelOrder::Argb, [0x80, 0xff, 0x88, 0x00]),
+            (ChannelOrder::Bgra, [0x00, 0x88, 0xff, 0x80]),
+            (ChannelOrder::Abgr, [0x80, 0x00, 0x88, 0xff]),
+        ];
+
+        for (order, bytes) in cases {
+            assert_eq!(order.to_bytes(&ORANGE), bytes, "{order:?}");
+            assert_eq!(order.from_bytes(bytes), ORANGE, "{order:?}");
+        }
+    }
+
+    #[test]
+    fn test_packed_integers() {
+        let cases = [
+            (ChannelOrder::Rgba, 0xff880080),
+            (ChannelOrder::Argb, 0x80ff8800),
+            (ChannelOrder::Bgra, 0x0088ff80),
+            (ChannelOrder::Abgr, 0x800088ff),
+        ];
+
+        for (order, packed) in cases {
+            assert_eq!(ORANGE.to_u32(order), packed, "{order:?}");
+            assert_eq!(Rgba::from_u32(packed, order), ORANGE, "{order:?}");
+        }
+    }
+
+    #[test]
+    fn test_colorref() {
+        // RGB(0x12, 0x34, 0x56), as returned by the Win32 RGB macro
+        let color = Rgba::from_colorref(0x00563412);
+        assert_eq!(
+            color,
+            Rgba {
+                red: 0x12,
+                green: 0x34,
+                blue: 0x56,
+                alpha: 0xff
+            }
+        );
+        assert_eq!(color.to_colorref(), 0x00563412);
+
+        // The high byte is not alpha: it is ignored on input and zero on output
+        assert_eq!(Rgba::from_colorref(0x01563412), color);
+        assert_eq!(ORANGE.to_colorref(), 0x000088ff);
+        assert_eq!(Rgba::from_colorref(ORANGE.to_colorref()).alpha, 0xff);
+    }
+
+    #[test]
+    fn test_hex_formatting() {
+        assert_eq!(ORANGE.to_hex_in(ChannelOrder::Rgba), ORANGE.to_hex());
+        assert_eq!(ORANGE.to_hex_in(ChannelOrder::Argb), "#80ff8800");
+        assert_eq!(ORANGE.to_hex_in(ChannelOrder::Bgra), "#0088ff80");
+        assert_eq!(ORANGE.to_hex_in(ChannelOrder::Abgr), "#800088ff");
+
+        // Opaque colors omit the alpha digits, keeping the color channel order
+        let opaque = Rgba {
+            alpha: 255,
+            ..ORANGE
+        };
+        assert_eq!(opaque.to_hex_in(ChannelOrder::Argb), "#ff8800");
+        assert_eq!(opaque.to_hex_in(ChannelOrder::Abgr), "#0088ff");
+    }
+
+    #[test]
+    fn test_parse_ordered_hex() {
+        for order in ChannelOrder::ALL {
+            let options = ParseOptions::new().order(order);
+            assert_eq!(options.parse_hex(&ORANGE.to_hex_in(order)).unwrap(), ORANGE);
+
+            let opaque = Rgba {
+                alpha: 255,
+                ..ORANGE
+            };
+            assert_eq!(options.parse_hex(&opaque.to_hex_in(order)).unwrap(), opaque);
+        }
+    }
+
+    #[test]
+    fn test_parse_ordered_shorthand() {
+        let argb = ParseOptions::new().order(ChannelOrder::Argb);
+        assert_eq!(
+            argb.parse_hex("#8f80").unwrap(),
+            Rgba {
+                red: 0xff,
+                green: 0x88,
+                blue: 0x00,
+                alpha: 0x88
+            }
+        );
+
+        let bgra = ParseOptions::new().order(ChannelOrder::Bgra);
+        assert_eq!(
+            bgra.parse_hex("#08f").unwrap(),
+            Rgba {
+                red: 0xff,
+                green: 0x88,
+                blue: 0x00,
+                alpha: 0xff
+            }
+        );
+    }
+
+    #[test]
+    fn test_order_with_other_options() {
+        let options = ParseOptions::lenient().order(ChannelOrder::Argb);
+        assert_eq!(options.parse_hex(" 0x80FF8800 ").unwrap(), ORANGE);
+        assert_eq!(options.parse("80ff8800").unwrap().to_rgba(), ORANGE);
+        // CSS functions and names are not affected by the order
+        assert_eq!(
+            options.parse("rgb(255 136 0 / 0.5)").unwrap().to_rgba(),
+            ORANGE
+        );
+
+        let options = ParseOptions::strict().order(ChannelOrder::Abgr);
+        assert_eq!(options.parse_hex("#800088ff").unwrap(), ORANGE);
+        assert_eq!(
+            options.parse_hex("#800088FF").unwrap_err().kind,
+            ErrorKind::InvalidCharacter
+        );
+    }
+}
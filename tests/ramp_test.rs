@@ -0,0 +1,130 @@
+use color_parser::ramp::{RAMP_STEPS, Ramp};
+use color_parser::{Oklch, Rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_tint_shade_tone() {
+        let red = rgba(255, 0, 0, 255);
+
+        assert_eq!(red.tint(0.0), red);
+        assert_eq!(red.tint(0.5), rgba(255, 128, 128, 255));
+        assert_eq!(red.tint(1.0), rgba(255, 255, 255, 255));
+
+        assert_eq!(red.shade(0.5), rgba(128, 0, 0, 255));
+        assert_eq!(red.shade(1.0), rgba(0, 0, 0, 255));
+
+        assert_eq!(red.tone(0.5), rgba(192, 64, 64, 255));
+        assert_eq!(red.tone(1.0), rgba(128, 128, 128, 255));
+    }
+
+    #[test]
+    fn test_series() {
+        let blue = rgba(0, 0, 255, 255);
+
+        assert_eq!(
+            blue.tints(3),
+            [
+                rgba(64, 64, 255, 255),
+                rgba(128, 128, 255, 255),
+                rgba(191, 191, 255, 255)
+            ]
+        );
+        assert_eq!(
+            blue.shades(3),
+            [
+                rgba(0, 0, 191, 255),
+                rgba(0, 0, 128, 255),
+                rgba(0, 0, 64, 255)
+            ]
+        );
+        assert_eq!(blue.tones(1), [rgba(64, 64, 192, 255)]);
+        assert!(blue.tints(0).is_empty());
+    }
+
+    #[test]
+    fn test_series_keeps_alpha() {
+        let translucent = rgba(255, 0, 0, 128);
+        assert_eq!(translucent.tint(0.5), rgba(255, 128, 128, 128));
+        assert_eq!(translucent.shade(0.5), rgba(128, 0, 0, 128));
+    }
+
+    #[test]
+    fn test_ramp_anchor() {
+        // Tailwind's blue-500 lands on 500 and is kept exactly
+        let blue = rgba(59, 130, 246, 255);
+        let ramp = Ramp::new(&blue);
+        assert_eq!(ramp.anchor, 500);
+        assert_eq!(ramp.get(500), Some(blue));
+        assert_eq!(ramp.get(550), None);
+
+        // A dark color lands near the end of the ramp
+        assert_eq!(rgba(17, 17, 17, 255).ramp().anchor, 950);
+        assert_eq!(rgba(250, 250, 250, 255).ramp().anchor, 50);
+    }
+
+    #[test]
+    fn test_ramp_is_monotonic_with_constant_hue() {
+        for color in [
+            rgba(255, 136, 0, 255),
+            rgba(59, 130, 246, 255),
+            rgba(22, 163, 74, 255),
+        ] {
+            let ramp = color.ramp();
+            let source = Oklch::from(color);
+            let steps: Vec<Oklch> = ramp.colors.iter().map(|(_, c)| Oklch::from(*c)).collect();
+
+            assert_eq!(ramp.colors.map(|(step, _)| step), RAMP_STEPS, "{color:?}");
+            for pair in steps.windows(2) {
+                assert!(pair[0].lightness > pair[1].lightness, "{color:?}");
+            }
+            for step in &steps {
+                assert_close(step.hue, source.hue, 3.0);
+            }
+        }
+    }
+
+    #[test]
+    fn test_gray_ramp_stays_gray() {
+        let ramp = rgba(128, 128, 128, 255).ramp();
+        for (_, color) in ramp.colors {
+            assert_eq!(color.red, color.green);
+            assert_eq!(color.green, color.blue);
+        }
+    }
+
+    #[test]
+    fn test_ramp_output() {
+        let ramp = rgba(59, 130, 246, 255).ramp();
+
+        let css = ramp.to_css_properties("primary");
+        assert!(css.starts_with(":root {\n  --primary-50: #"));
+        assert!(css.contains("\n  --primary-500: #3b82f6;\n"));
+        assert!(css.ends_with("  --primary-950: #021e4d;\n}\n"));
+        assert_eq!(css.lines().count(), 13);
+
+        let config = ramp.to_tailwind("primary");
+        assert!(config.starts_with("'primary': {\n  50: '#"));
+        assert!(config.contains("\n  500: '#3b82f6',\n"));
+        assert!(config.ends_with("  950: '#021e4d',\n},\n"));
+        assert_eq!(config.lines().count(), 13);
+    }
+}
@@ -0,0 +1,176 @@
+use color_parser::mix::InterpolationSpace;
+use color_parser::scale::Scale;
+use color_parser::{Lab, Rgba};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(red: u8, green: u8, blue: u8, alpha: u8) -> Rgba {
+        Rgba {
+            red,
+            green,
+            blue,
+            alpha,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    const WHITE: Rgba = Rgba {
+        red: 255,
+        green: 255,
+        blue: 255,
+        alpha: 255,
+    };
+    const BLACK: Rgba = Rgba {
+        red: 0,
+        green: 0,
+        blue: 0,
+        alpha: 255,
+    };
+
+    #[test]
+    fn test_two_color_scale() {
+        let scale = Scale::new(&[WHITE, BLACK]).mode(InterpolationSpace::Srgb);
+
+        assert_eq!(scale.at(0.0), WHITE);
+        assert_eq!(scale.at(0.5), rgba(128, 128, 128, 255));
+        assert_eq!(scale.at(1.0), BLACK);
+
+        // Values outside the domain are clamped
+        assert_eq!(scale.at(-1.0), WHITE);
+        assert_eq!(scale.at(2.0), BLACK);
+    }
+
+    #[test]
+    fn test_stops() {
+        let red = rgba(255, 0, 0, 255);
+        let blue = rgba(0, 0, 255, 255);
+        let scale = Scale::from_stops(&[(1.0, blue), (0.0, red), (0.25, WHITE)])
+            .mode(InterpolationSpace::Srgb);
+
+        assert_eq!(scale.at(0.25), WHITE);
+        assert_eq!(scale.at(0.125), rgba(255, 128, 128, 255));
+        assert_eq!(scale.at(0.625), rgba(128, 128, 255, 255));
+
+        // A single stop is a constant scale
+        let constant = Scale::new(&[red]);
+        assert_eq!(constant.colors(3), [red, red, red]);
+    }
+
+    #[test]
+    fn test_domain() {
+        let scale = Scale::new(&[WHITE, BLACK]).mode(InterpolationSpace::Srgb);
+        let domain = scale.clone().domain(100.0, 200.0);
+        assert_eq!(domain.at(125.0), scale.at(0.25));
+
+        // A reversed domain reverses the scale
+        let reversed = scale.clone().domain(1.0, 0.0);
+        assert_eq!(reversed.at(0.25), scale.at(0.75));
+    }
+
+    #[test]
+    fn test_nan_values() {
+        // NaN maps to the domain start instead of panicking
+        let scale = Scale::new(&[WHITE, rgba(255, 0, 0, 255), BLACK]);
+        assert_eq!(scale.at(f64::NAN), WHITE);
+        assert_eq!(scale.clone().classes(3).at(f64::NAN), WHITE);
+        assert_eq!(scale.clone().correct_lightness(true).at(f64::NAN), WHITE);
+
+        let nan_domain = scale.domain(f64::NAN, 1.0);
+        assert_eq!(nan_domain.colors(3), [WHITE, WHITE, WHITE]);
+
+        // NaN stop positions are placed at the start rather than sorted last
+        let red = rgba(255, 0, 0, 255);
+        let scale = Scale::from_stops(&[(1.0, red), (f64::NAN, WHITE)]);
+        assert_eq!(scale.at(0.0), WHITE);
+        assert_eq!(scale.at(1.0), red);
+        assert_ne!(scale.at(0.5).alpha, 0);
+    }
+
+    #[test]
+    fn test_classes() {
+        let scale = Scale::new(&[WHITE, BLACK])
+            .mode(InterpolationSpace::Srgb)
+            .domain(0.0, 100.0)
+            .classes(3);
+
+        // Each class maps to one color, from the first stop to the last
+        assert_eq!(scale.at(0.0), WHITE);
+        assert_eq!(scale.at(30.0), WHITE);
+        assert_eq!(scale.at(40.0), rgba(128, 128, 128, 255));
+        assert_eq!(scale.at(60.0), rgba(128, 128, 128, 255));
+        assert_eq!(scale.at(70.0), BLACK);
+        assert_eq!(scale.at(100.0), BLACK);
+
+        let uneven = scale.class_breaks(&[0.0, 10.0, 20.0, 100.0]);
+        assert_eq!(uneven.at(5.0), WHITE);
+        assert_eq!(uneven.at(15.0), rgba(128, 128, 128, 255));
+        assert_eq!(uneven.at(50.0), BLACK);
+    }
+
+    #[test]
+    fn test_bezier() {
+        let yellow = rgba(255, 255, 0, 255);
+        let navy = rgba(0, 0, 128, 255);
+        let colors = [yellow, rgba(0, 138, 229, 255), navy];
+
+        let linear = Scale::new(&colors).mode(InterpolationSpace::Lab);
+        let bezier = Scale::new(&colors).bezier(true);
+
+        // The curve starts and ends on the first and last stops, but does
+        // not pass through the middle one
+        assert_eq!(bezier.at(0.0), yellow);
+        assert_eq!(bezier.at(1.0), navy);
+        assert_ne!(bezier.at(0.5), linear.at(0.5));
+
+        // With two colors, the curve is a straight line in Lab
+        let two = Scale::new(&[yellow, navy]);
+        assert_eq!(
+            two.clone().bezier(true).colors(5),
+            two.mode(InterpolationSpace::Lab).colors(5)
+        );
+    }
+
+    #[test]
+    fn test_correct_lightness() {
+        let colors = [WHITE, rgba(0, 100, 0, 255), BLACK];
+        let scale = Scale::new(&colors)
+            .mode(InterpolationSpace::Lab)
+            .correct_lightness(true);
+
+        let lightness: Vec<f64> = scale
+            .colors(5)
+            .into_iter()
+            .map(|color| Lab::from(color).lightness)
+            .collect();
+        let step = (lightness[4] - lightness[0]) / 4.0;
+        for (i, l) in lightness.iter().enumerate() {
+            assert_close(*l, lightness[0] + step * i as f64, 0.5);
+        }
+
+        // Without correction the steps are uneven
+        let uncorrected = Lab::from(Scale::new(&colors).mode(InterpolationSpace::Lab).at(0.25));
+        assert!((uncorrected.lightness - (lightness[0] + step)).abs() > 2.0);
+    }
+
+    #[test]
+    fn test_hex_colors() {
+        let scale = Scale::new(&[WHITE, BLACK]).mode(InterpolationSpace::Srgb);
+        assert_eq!(scale.hex_colors(3), ["#ffffff", "#808080", "#000000"]);
+        assert_eq!(scale.hex_colors(1), ["#ffffff"]);
+        assert!(scale.hex_colors(0).is_empty());
+
+        let transparent = Scale::new(&[WHITE, rgba(255, 255, 255, 0)]);
+        assert_eq!(
+            transparent.hex_colors(3),
+            ["#ffffff", "#ffffff80", "#ffffff00"]
+        );
+    }
+}
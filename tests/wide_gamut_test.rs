@@ -0,0 +1,153 @@
+use color_parser::{
+    AdobeRgb, Color, ColorParserError, DisplayP3, ErrorKind, ProPhotoRgb, Rec2020, RgbSpace, Rgba,
+    Srgb, parse_css_color,
+};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_channels<S: RgbSpace>(color: S, expected: [f64; 3]) {
+        let (channels, _) = color.channels();
+        for (actual, expected) in channels.into_iter().zip(expected) {
+            assert!(
+                (actual - expected).abs() < 1e-3,
+                "{channels:?} is not close to {expected:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_srgb_red_in_each_space() {
+        let red = rgba(255, 0, 0);
+        assert_channels(DisplayP3::from(red), [0.9175, 0.2003, 0.1386]);
+        assert_channels(Rec2020::from(red), [0.7920, 0.2310, 0.0738]);
+        assert_channels(AdobeRgb::from(red), [0.8586, 0.0, 0.0]);
+        assert_channels(ProPhotoRgb::from(red), [0.7023, 0.2757, 0.1035]);
+    }
+
+    #[test]
+    fn test_white_maps_to_white() {
+        let white = rgba(255, 255, 255);
+        assert_channels(DisplayP3::from(white), [1.0, 1.0, 1.0]);
+        assert_channels(Rec2020::from(white), [1.0, 1.0, 1.0]);
+        assert_channels(AdobeRgb::from(white), [1.0, 1.0, 1.0]);
+        // ProPhoto uses D50, so this also exercises the Bradford adaptation
+        assert_channels(ProPhotoRgb::from(white), [1.0, 1.0, 1.0]);
+    }
+
+    #[test]
+    fn test_wide_gamut_colors_are_outside_srgb() {
+        let p3_red = DisplayP3 {
+            red: 1.0,
+            green: 0.0,
+            blue: 0.0,
+            alpha: 1.0,
+        };
+        let srgb = Srgb::from(p3_red);
+        assert!(srgb.red > 1.0);
+        assert!(srgb.green < 0.0);
+        assert!(srgb.blue < 0.0);
+
+        // Converting back preserves the out-of-gamut values
+        assert_channels(DisplayP3::from(srgb), [1.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for v in (0..=255u8).step_by(5) {
+            let color = rgba(v, 255 - v, v / 4);
+            assert_eq!(Rgba::from(DisplayP3::from(color)), color);
+            assert_eq!(Rgba::from(Rec2020::from(color)), color);
+            assert_eq!(Rgba::from(AdobeRgb::from(color)), color);
+            assert_eq!(Rgba::from(ProPhotoRgb::from(color)), color);
+        }
+    }
+
+    #[test]
+    fn test_parse_css_color_function() {
+        let color = parse_css_color("color(display-p3 0.9175 0.2003 0.1386)").unwrap();
+        assert!(matches!(color, Color::DisplayP3(_)));
+        assert_eq!(color.to_rgba(), rgba(255, 0, 0));
+
+        let color = parse_css_color("color(rec2020 79.2% 23.1% 7.38% / 0.5)").unwrap();
+        let Color::Rec2020(rec2020) = color else {
+            panic!("expected Rec2020");
+        };
+        assert_eq!(rec2020.alpha, 0.5);
+
+        let color = parse_css_color("COLOR(A98-RGB 1 1 1)").unwrap();
+        assert!(matches!(color, Color::AdobeRgb(_)));
+
+        let color = parse_css_color("color(prophoto-rgb none 0 0)").unwrap();
+        assert_eq!(color.to_rgba(), rgba(0, 0, 0));
+
+        let color = parse_css_color("color(srgb 1 0.5 0)").unwrap();
+        assert_eq!(color.to_rgba(), rgba(255, 128, 0));
+
+        let color = parse_css_color("color(srgb-linear 1 0 0)").unwrap();
+        assert_eq!(color.to_rgba(), rgba(255, 0, 0));
+    }
+
+    #[test]
+    fn test_parse_css_color_function_errors() {
+        assert!(matches!(
+            parse_css_color("color(cmyk 1 0 0)"),
+            Err(ColorParserError {
+                kind: ErrorKind::UnknownFunction,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_css_color("color(display-p3 1 0)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_css_color("color(1 0 0)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+        assert!(matches!(
+            parse_css_color("color(display-p3, 1, 0, 0)"),
+            Err(ColorParserError {
+                kind: ErrorKind::InvalidSyntax,
+                ..
+            })
+        ));
+    }
+
+    #[test]
+    fn test_css_string() {
+        let p3 = DisplayP3 {
+            red: 1.0,
+            green: 0.5,
+            blue: 0.0,
+            alpha: 0.8,
+        };
+        assert_eq!(p3.to_css_string(), "color(display-p3 1 0.5 0 / 0.8)");
+        assert_eq!(
+            parse_css_color(&p3.to_css_string()).unwrap(),
+            Color::DisplayP3(p3)
+        );
+
+        let prophoto = ProPhotoRgb::from(rgba(255, 0, 0));
+        assert_eq!(
+            prophoto.to_css_string(),
+            "color(prophoto-rgb 0.7023 0.2757 0.1035)"
+        );
+    }
+}
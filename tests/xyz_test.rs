@@ -0,0 +1,91 @@
+use color_parser::xyz::{linear_to_srgb, srgb_to_linear};
+use color_parser::{LinearRgb, Rgba, Xyz};
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    fn rgba(r: u8, g: u8, b: u8) -> Rgba {
+        Rgba {
+            red: r,
+            green: g,
+            blue: b,
+            alpha: 255,
+        }
+    }
+
+    fn assert_close(actual: f64, expected: f64, tolerance: f64) {
+        assert!(
+            (actual - expected).abs() < tolerance,
+            "{actual} is not within {tolerance} of {expected}"
+        );
+    }
+
+    #[test]
+    fn test_transfer_function() {
+        assert_eq!(srgb_to_linear(0.0), 0.0);
+        assert_eq!(srgb_to_linear(1.0), 1.0);
+        assert_close(srgb_to_linear(0.04045), 0.04045 / 12.92, 1e-12);
+        assert_close(srgb_to_linear(0.5), 0.214_041_140_482_232_5, 1e-12);
+        assert_close(linear_to_srgb(0.0031308), 0.0031308 * 12.92, 1e-12);
+        assert_close(linear_to_srgb(0.18), 0.461_356_129_500_441_9, 1e-12);
+    }
+
+    #[test]
+    fn test_transfer_function_is_continuous() {
+        // Both pieces meet at the breakpoints
+        let below = 0.04045 / 12.92;
+        let above = ((0.04045_f64 + 0.055) / 1.055).powf(2.4);
+        assert_close(below, above, 1e-7);
+    }
+
+    #[test]
+    fn test_negative_values_are_mirrored() {
+        assert_close(srgb_to_linear(-0.5), -srgb_to_linear(0.5), 1e-15);
+        assert_close(linear_to_srgb(-0.2), -linear_to_srgb(0.2), 1e-15);
+    }
+
+    #[test]
+    fn test_white_is_d65() {
+        let xyz: Xyz = rgba(255, 255, 255).into();
+        assert_close(xyz.x, 0.3127 / 0.3290, 1e-12);
+        assert_close(xyz.y, 1.0, 1e-12);
+        assert_close(xyz.z, (1.0 - 0.3127 - 0.3290) / 0.3290, 1e-12);
+    }
+
+    #[test]
+    fn test_primaries() {
+        let red: Xyz = rgba(255, 0, 0).into();
+        assert_close(red.x, 0.412_390_8, 1e-7);
+        assert_close(red.y, 0.212_639_0, 1e-7);
+        assert_close(red.z, 0.019_330_8, 1e-7);
+
+        let green: Xyz = rgba(0, 255, 0).into();
+        assert_close(green.y, 0.715_168_7, 1e-7);
+
+        let blue: Xyz = rgba(0, 0, 255).into();
+        assert_close(blue.z, 0.950_532_2, 1e-7);
+    }
+
+    #[test]
+    fn test_linear_rgb_midpoint() {
+        let linear: LinearRgb = rgba(128, 128, 128).into();
+        assert_close(linear.red, 0.215_860_5, 1e-7);
+        assert_eq!(linear.red, linear.green);
+        assert_eq!(linear.alpha, 1.0);
+    }
+
+    #[test]
+    fn test_every_channel_round_trips() {
+        for v in 0..=255u8 {
+            let color = rgba(v, 255 - v, v / 2);
+
+            let linear: LinearRgb = color.into();
+            assert_eq!(Rgba::from(linear), color);
+
+            let xyz: Xyz = color.into();
+            assert_eq!(Rgba::from(xyz), color);
+            assert_eq!(Rgba::from(LinearRgb::from(xyz)), color);
+        }
+    }
+}